@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{middleware, routing::get, Router};
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::CorsLayer;
+use tower_http::trace::TraceLayer;
+
+use kiep_core::Config;
+
+pub mod routes;
+
+pub struct AppState {
+    pub pool: sqlx::PgPool,
+    pub config: Config,
+    /// None이면 IP별 요청 제한 없음
+    pub throttle: Option<routes::throttle::IpThrottle>,
+}
+
+/// 라우터 구성, 미등록 경로는 JSON 404로 응답
+/// 지역/기업 등 조회 라우트는 키 표기 변환과 IP별 제한, `/openapi.json`은 IP별 제한만
+pub fn build_app(state: Arc<AppState>) -> Router {
+    Router::new()
+        .nest(
+            "/api/v1",
+            routes::api_router(&state)
+                .layer(middleware::from_fn_with_state(state.clone(), routes::case::convert_case))
+                // 명세는 키 표기 변환 밖에 둔다 (경로/스키마 이름이 ?case=camel로 바뀌지 않도록)
+                .route("/openapi.json", get(routes::openapi::openapi_json))
+                .layer(middleware::from_fn_with_state(state.clone(), routes::throttle::limit_per_ip)),
+        )
+        .fallback(routes::fallback::not_found)
+        .layer(CorsLayer::permissive())
+        .layer(TraceLayer::new_for_http())
+        .layer(CompressionLayer::new())
+        .with_state(state)
+}
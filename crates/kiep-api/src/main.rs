@@ -1,29 +1,24 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::Router;
+use axum::{middleware, ServiceExt};
+use tower::Layer;
 use sqlx::postgres::PgPoolOptions;
-use tower_http::cors::CorsLayer;
-use tower_http::trace::TraceLayer;
-use tower_http::compression::CompressionLayer;
+use tracing_subscriber::fmt::format::FmtSpan;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use kiep_api::{build_app, routes, AppState};
 use kiep_core::Config;
 
-mod routes;
-
-pub struct AppState {
-    pub pool: sqlx::PgPool,
-    pub config: Config,
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Init tracing
+    // 핸들러 span(라우트명 + 주요 파라미터)이 끝날 때 소요 시간과 함께 한 줄 기록
     tracing_subscriber::registry()
         .with(tracing_subscriber::EnvFilter::try_from_default_env()
             .unwrap_or_else(|_| "kiep_api=debug,tower_http=debug".into()))
-        .with(tracing_subscriber::fmt::layer())
+        .with(tracing_subscriber::fmt::layer().with_span_events(FmtSpan::CLOSE))
         .init();
 
     // Load config
@@ -32,20 +27,23 @@ async fn main() -> anyhow::Result<()> {
     // Connect to database
     let pool = PgPoolOptions::new()
         .max_connections(20)
-        .connect(&config.database_url)
+        .connect_with(config.pg_connect_options("kiep-api")?)
         .await?;
 
     tracing::info!("Connected to database");
+    routes::geo::check_region_geometry(&pool, &config.region_geometry).await;
+    if config.admin_api_key.is_none() {
+        tracing::warn!("ADMIN_API_KEY not set; /admin routes will reject every request");
+    }
 
-    let state = Arc::new(AppState { pool, config: config.clone() });
+    let throttle = config.api_requests_per_minute.map(routes::throttle::IpThrottle::new);
+    if let Some(throttle) = &throttle {
+        throttle.spawn_cleanup(Duration::from_secs(60));
+    }
+    let state = Arc::new(AppState { pool, config: config.clone(), throttle });
 
-    // Build router
-    let app = Router::new()
-        .nest("/api/v1", routes::api_router())
-        .layer(CorsLayer::permissive())
-        .layer(TraceLayer::new_for_http())
-        .layer(CompressionLayer::new())
-        .with_state(state);
+    // 405는 Router 바깥에서 감싸야 Allow 헤더를 볼 수 있음
+    let app = middleware::from_fn(routes::fallback::method_not_allowed).layer(build_app(state));
 
     // Start server
     let addr = SocketAddr::new(
@@ -55,7 +53,7 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("Starting KIEP API server on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
 
     Ok(())
 }
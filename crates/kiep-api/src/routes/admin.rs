@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::AppState;
+use super::case::Json;
+use super::regions::AppError;
+use super::Paginated;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/companies-missing-employment", get(companies_missing_employment))
+        .route("/jobs", get(list_jobs))
+        .route("/sources", get(list_sources))
+}
+
+#[derive(Deserialize)]
+pub struct PageParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+#[derive(Serialize, FromRow)]
+pub struct MissingEmploymentItem {
+    biz_no: String,
+    name: String,
+    data_source: Option<String>,
+    bjd_code: Option<String>,
+    created_at: String,
+}
+
+/// employment_series가 한 건도 없는 기업 (anti-join)
+#[tracing::instrument(skip_all, fields(limit = ?params.limit, offset = ?params.offset))]
+async fn companies_missing_employment(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<Paginated<MissingEmploymentItem>>, AppError> {
+    let limit = state.config.limits.admin.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM companies c
+        LEFT JOIN employment_series es ON es.biz_no = c.biz_no
+        WHERE es.biz_no IS NULL
+        "#,
+    )
+    .fetch_one(&state.pool)
+    .await?;
+
+    let items = sqlx::query_as::<_, MissingEmploymentItem>(
+        r#"
+        SELECT c.biz_no, c.name, c.data_source, c.bjd_code,
+               to_char(c.created_at, 'YYYY-MM-DD') as created_at
+        FROM companies c
+        LEFT JOIN employment_series es ON es.biz_no = c.biz_no
+        WHERE es.biz_no IS NULL
+        ORDER BY c.biz_no
+        LIMIT $1 OFFSET $2
+        "#,
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(Paginated { total, limit, offset, items }))
+}
+
+#[derive(Serialize, FromRow)]
+pub struct JobItem {
+    id: String,
+    command: String,
+    args: serde_json::Value,
+    status: String,
+    fetched_count: Option<i32>,
+    written_count: Option<i32>,
+    error: Option<String>,
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+/// CLI 수집/재계산 실행 기록 (최근 순)
+#[tracing::instrument(skip_all, fields(limit = ?params.limit, offset = ?params.offset))]
+async fn list_jobs(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<PageParams>,
+) -> Result<Json<Paginated<JobItem>>, AppError> {
+    let limit = state.config.limits.admin.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs")
+        .fetch_one(&state.pool)
+        .await?;
+
+    let items = sqlx::query_as::<_, JobItem>(
+        r#"
+        SELECT id::text as id, command, args, status, fetched_count, written_count, error,
+               started_at, finished_at
+        FROM jobs
+        ORDER BY started_at DESC, id
+        LIMIT $1 OFFSET $2
+        "#,
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(Paginated { total, limit, offset, items }))
+}
+
+#[derive(Serialize, FromRow)]
+pub struct SourceItem {
+    source: String,
+    last_success_at: Option<DateTime<Utc>>,
+    /// 마지막 성공 후 경과 시간, 성공 기록이 없으면 null
+    age_hours: Option<f64>,
+    /// 성공 기록이 없거나 SOURCE_MAX_AGE_HOURS를 넘김
+    stale: bool,
+    /// succeeded/failed/never
+    last_outcome: String,
+    last_counts: Option<serde_json::Value>,
+    last_error: Option<String>,
+    last_error_at: Option<DateTime<Utc>>,
+}
+
+/// $1 = stale 기준 시간
+const SOURCE_STATUS_SQL: &str = r#"
+    SELECT source, last_success_at,
+           EXTRACT(EPOCH FROM NOW() - last_success_at)::float8 / 3600 as age_hours,
+           last_success_at IS NULL
+               OR last_success_at < NOW() - make_interval(hours => $1) as stale,
+           CASE WHEN last_error_at IS NOT NULL
+                     AND (last_success_at IS NULL OR last_error_at > last_success_at)
+                THEN 'failed'
+                WHEN last_success_at IS NOT NULL THEN 'succeeded'
+                ELSE 'never' END as last_outcome,
+           last_counts, last_error, last_error_at
+    FROM source_status
+    ORDER BY source
+"#;
+
+/// 데이터 소스별 마지막 수집 시각과 결과
+#[tracing::instrument(skip_all)]
+async fn list_sources(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<SourceItem>>, AppError> {
+    let items = sqlx::query_as::<_, SourceItem>(SOURCE_STATUS_SQL)
+        .bind(state.config.source_max_age_hours as i32)
+        .fetch_all(&state.pool)
+        .await?;
+    Ok(Json(items))
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::AppState;
+use super::case::Json;
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// `X-API-Key`가 `ADMIN_API_KEY`와 같을 때만 통과, 키가 설정되지 않았으면 모두 거부
+pub async fn require_api_key(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if is_authorized(req.headers(), state.config.admin_api_key.as_deref()) {
+        return next.run(req).await;
+    }
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(serde_json::json!({ "error": "missing or invalid X-API-Key", "code": "unauthorized" })),
+    )
+        .into_response()
+}
+
+fn is_authorized(headers: &HeaderMap, expected: Option<&str>) -> bool {
+    let Some(expected) = expected else { return false };
+    headers
+        .get(API_KEY_HEADER)
+        .is_some_and(|given| constant_time_eq(given.as_bytes(), expected.as_bytes()))
+}
+
+/// 길이가 같으면 내용과 무관하게 모든 바이트를 비교 (일치 위치로 시간이 달라지지 않음)
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_is_authorized() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_authorized(&headers, Some("secret")));
+
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("wrong!"));
+        assert!(!is_authorized(&headers, Some("secret")));
+
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("secret"));
+        assert!(is_authorized(&headers, Some("secret")));
+        // 키 미설정이면 어떤 값도 통과하지 못함
+        assert!(!is_authorized(&headers, None));
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"abcd"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use kiep_core::hash::fnv1a64;
+use sqlx::PgPool;
+
+use crate::AppState;
+
+/// 지역/choropleth 조회 응답 캐시 정책 (데이터는 많아야 월 1회 바뀜)
+const CACHE_CONTROL: &str = "public, max-age=3600";
+
+/// data_version 테이블의 데이터셋 갱신 시각 (초 단위 절사)
+pub async fn data_version(pool: &PgPool, name: &str) -> Result<Option<SystemTime>, sqlx::Error> {
+    let epoch: Option<i64> = sqlx::query_scalar(
+        "SELECT EXTRACT(EPOCH FROM updated_at)::bigint FROM data_version WHERE name = $1",
+    )
+    .bind(name)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(epoch.map(|secs| UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64)))
+}
+
+/// region_health 버전과 최신 기준월 (ETag 키)
+async fn region_health_version(pool: &PgPool) -> Result<Option<(SystemTime, Option<String>)>, sqlx::Error> {
+    let row: Option<(i64, Option<String>)> = sqlx::query_as(
+        r#"
+        SELECT EXTRACT(EPOCH FROM updated_at)::bigint, (SELECT MAX(year_month) FROM region_health)
+        FROM data_version WHERE name = 'region_health'
+        "#,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|(secs, latest)| (UNIX_EPOCH + Duration::from_secs(secs.max(0) as u64), latest)))
+}
+
+/// If-Modified-Since 기준으로 변경이 없으면 true
+pub fn is_not_modified(headers: &HeaderMap, version: SystemTime) -> bool {
+    headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| httpdate::parse_http_date(v).ok())
+        .is_some_and(|since| version <= since)
+}
+
+/// 조건부 요청이면 304 응답 (본문 조회 전에 호출)
+pub fn not_modified_response(headers: &HeaderMap, version: Option<SystemTime>) -> Option<Response> {
+    let version = version?;
+    is_not_modified(headers, version).then(|| {
+        with_last_modified(StatusCode::NOT_MODIFIED.into_response(), Some(version))
+    })
+}
+
+pub fn with_last_modified(mut resp: Response, version: Option<SystemTime>) -> Response {
+    if let Some(version) = version
+        && let Ok(value) = HeaderValue::from_str(&httpdate::fmt_http_date(version))
+    {
+        resp.headers_mut().insert(header::LAST_MODIFIED, value);
+    }
+    resp
+}
+
+/// region_health 버전·최신 기준월과 요청(경로+쿼리, Accept, 기본 키 표기)으로 만든 약한 ETag
+/// region_health만 읽는 라우트는 데이터가 바뀌기 전까지 본문이 같으므로 핸들러를 돌리기 전에 계산할 수 있다
+fn weak_etag(
+    version: SystemTime,
+    latest: Option<&str>,
+    uri: &str,
+    accept: Option<&[u8]>,
+    camel_default: bool,
+) -> String {
+    let secs = version.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs().to_be_bytes();
+    let hash = fnv1a64([
+        secs.as_slice(),
+        latest.unwrap_or_default().as_bytes(),
+        uri.as_bytes(),
+        accept.unwrap_or_default(),
+        &[u8::from(camel_default)],
+    ]);
+    format!("W/\"{:016x}\"", hash)
+}
+
+/// If-None-Match 목록에 etag가 있으면 true (약한 비교, `*`는 항상 일치)
+fn matches_if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let strip = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|tag| tag.trim() == "*" || strip(tag) == strip(etag))
+}
+
+fn set_cache_headers(headers: &mut HeaderMap, tag: HeaderValue) {
+    headers.insert(header::ETAG, tag);
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static(CACHE_CONTROL));
+    // CSV/GeoJSON을 Accept로도 고르므로 공유 캐시가 형식을 섞지 않도록
+    headers.append(header::VARY, HeaderValue::from_static("accept"));
+}
+
+/// GET 200 응답에 ETag/Cache-Control 부여, If-None-Match가 일치하면 핸들러를 실행하지 않고 304
+/// region_health 버전이 없으면(미적재) 캐시 헤더 없이 그대로 통과
+/// region_health 외 테이블을 읽는 라우트에는 걸지 않는다 (그 테이블이 바뀌어도 ETag가 그대로라 오래된 304가 나감)
+pub async fn etag(State(state): State<Arc<AppState>>, req: Request, next: Next) -> Response {
+    if req.method() != Method::GET {
+        return next.run(req).await;
+    }
+    let (version, latest) = match region_health_version(&state.pool).await {
+        Ok(Some(version)) => version,
+        Ok(None) => return next.run(req).await,
+        Err(e) => {
+            tracing::warn!("Skipping ETag, failed to read data version: {}", e);
+            return next.run(req).await;
+        }
+    };
+    let uri = req.uri().path_and_query().map_or(req.uri().path(), |pq| pq.as_str());
+    let accept = req.headers().get(header::ACCEPT).map(HeaderValue::as_bytes);
+    let tag = weak_etag(version, latest.as_deref(), uri, accept, state.config.camel_case_responses);
+    let Ok(tag_value) = HeaderValue::from_str(&tag) else {
+        return next.run(req).await;
+    };
+
+    if matches_if_none_match(req.headers(), &tag) {
+        let mut resp = with_last_modified(StatusCode::NOT_MODIFIED.into_response(), Some(version));
+        set_cache_headers(resp.headers_mut(), tag_value);
+        return resp;
+    }
+
+    let mut resp = next.run(req).await;
+    if resp.status() == StatusCode::OK {
+        set_cache_headers(resp.headers_mut(), tag_value);
+    }
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers_with_ims(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MODIFIED_SINCE, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_is_not_modified() {
+        let version = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let same = httpdate::fmt_http_date(version);
+        let later = httpdate::fmt_http_date(version + Duration::from_secs(60));
+        let earlier = httpdate::fmt_http_date(version - Duration::from_secs(60));
+
+        assert!(is_not_modified(&headers_with_ims(&same), version));
+        assert!(is_not_modified(&headers_with_ims(&later), version));
+        assert!(!is_not_modified(&headers_with_ims(&earlier), version));
+        assert!(!is_not_modified(&headers_with_ims("garbage"), version));
+        assert!(!is_not_modified(&HeaderMap::new(), version));
+    }
+
+    #[test]
+    fn test_weak_etag_tracks_version_and_request() {
+        let version = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let latest = Some("2024-03");
+        let tag = weak_etag(version, latest, "/regions/ranking?limit=10", None, false);
+        assert!(tag.starts_with("W/\"") && tag.ends_with('"'));
+        assert_eq!(tag, weak_etag(version, latest, "/regions/ranking?limit=10", None, false));
+        // 고정 해시: Rust 버전과 무관하게 같은 값
+        assert_eq!(tag, "W/\"d4c8b70075edfe51\"");
+        assert_ne!(tag, weak_etag(version + Duration::from_secs(1), latest, "/regions/ranking?limit=10", None, false));
+        assert_ne!(tag, weak_etag(version, Some("2024-04"), "/regions/ranking?limit=10", None, false));
+        assert_ne!(tag, weak_etag(version, latest, "/regions/ranking?limit=20", None, false));
+        assert_ne!(tag, weak_etag(version, latest, "/regions/ranking?limit=10", Some(b"text/csv"), false));
+        assert_ne!(tag, weak_etag(version, latest, "/regions/ranking?limit=10", None, true));
+    }
+
+    #[test]
+    fn test_matches_if_none_match() {
+        let tag = weak_etag(UNIX_EPOCH, None, "/geo/choropleth", None, false);
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_NONE_MATCH, HeaderValue::from_str(value).unwrap());
+            headers
+        };
+
+        assert!(matches_if_none_match(&headers(&tag), &tag));
+        // 약한 비교: W/ 유무 무시, 목록 중 하나만 맞으면 됨
+        assert!(matches_if_none_match(&headers(&format!("\"x\", {}", tag.trim_start_matches("W/"))), &tag));
+        assert!(matches_if_none_match(&headers("*"), &tag));
+        assert!(!matches_if_none_match(&headers("W/\"other\""), &tag));
+        assert!(!matches_if_none_match(&HeaderMap::new(), &tag));
+    }
+}
@@ -0,0 +1,443 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::de::DeserializeOwned;
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::AppState;
+
+tokio::task_local! {
+    /// 이번 요청의 응답 키를 camelCase로 쓸지 (convert_case가 설정)
+    static CAMEL_CASE: bool;
+}
+
+/// 응답 키 표기 선택 미들웨어
+/// `?case=camel|snake`가 우선하고, 없으면 `API_CAMEL_CASE` 설정을 따름
+/// 변환은 핸들러가 [`Json`]을 직렬화할 때 한 번에 이뤄지므로 본문을 다시 읽지 않는다
+pub async fn convert_case(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let camel = requested_case(req.uri().query()).unwrap_or(state.config.camel_case_responses);
+    CAMEL_CASE.scope(camel, next.run(req)).await
+}
+
+fn requested_case(query: Option<&str>) -> Option<bool> {
+    query?
+        .split('&')
+        .find_map(|kv| kv.strip_prefix("case="))
+        .and_then(|v| match v {
+            "camel" => Some(true),
+            "snake" => Some(false),
+            _ => None,
+        })
+}
+
+/// 요청별 키 표기를 따르는 JSON 응답 (요청 본문 추출은 axum::Json과 같음)
+pub struct Json<T>(pub T);
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        if CAMEL_CASE.try_with(|camel| *camel).unwrap_or(false) {
+            axum::Json(Camel(&self.0)).into_response()
+        } else {
+            axum::Json(self.0).into_response()
+        }
+    }
+}
+
+impl<T: DeserializeOwned, S: Send + Sync> FromRequest<S> for Json<T> {
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        axum::Json::<T>::from_request(req, state).await.map(|axum::Json(value)| Self(value))
+    }
+}
+
+/// "health_score" → "healthScore"
+fn to_camel_case(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper_next = false;
+    for (i, c) in key.chars().enumerate() {
+        if c == '_' && i > 0 {
+            upper_next = true;
+        } else if upper_next {
+            out.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// 구조체 필드 이름은 `&'static str`이어야 하므로 변환 결과를 한 번만 만들어 재사용
+/// (필드 이름은 코드에 있는 만큼이라 크기가 고정된다)
+fn camel_static(name: &'static str) -> &'static str {
+    static NAMES: LazyLock<Mutex<HashMap<&'static str, &'static str>>> = LazyLock::new(Default::default);
+    if !name.chars().skip(1).any(|c| c == '_') {
+        return name;
+    }
+    NAMES
+        .lock()
+        .unwrap()
+        .entry(name)
+        .or_insert_with(|| Box::leak(to_camel_case(name).into_boxed_str()))
+}
+
+/// 직렬화하면서 구조체 필드 이름(flatten된 구조체 필드 포함)과 enum 변형 키를 camelCase로 바꾸는 래퍼
+/// map 키는 상태/업종/지역 코드 같은 데이터라 그대로 둔다
+struct Camel<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> Serialize for Camel<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(CamelSerializer(serializer))
+    }
+}
+
+/// flatten 구조체의 필드 이름 키 (문자열이면 변환, 그 외는 그대로)
+struct CamelKey<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> Serialize for CamelKey<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serde_json::to_value(self.0).map_err(ser::Error::custom)? {
+            serde_json::Value::String(key) => serializer.serialize_str(&to_camel_case(&key)),
+            other => other.serialize(serializer),
+        }
+    }
+}
+
+struct CamelSerializer<S>(S);
+
+/// 중첩 값에도 Camel을 씌우는 복합 타입 래퍼
+struct Compound<C>(C);
+
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(fn $method(self, $($arg: $ty),*) -> Result<Self::Ok, Self::Error> {
+            self.0.$method($($arg),*)
+        })*
+    };
+}
+
+impl<S: Serializer> Serializer for CamelSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeStruct = Compound<S::SerializeStruct>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(v: bool);
+        serialize_i8(v: i8);
+        serialize_i16(v: i16);
+        serialize_i32(v: i32);
+        serialize_i64(v: i64);
+        serialize_i128(v: i128);
+        serialize_u8(v: u8);
+        serialize_u16(v: u16);
+        serialize_u32(v: u32);
+        serialize_u64(v: u64);
+        serialize_u128(v: u128);
+        serialize_f32(v: f32);
+        serialize_f64(v: f64);
+        serialize_char(v: char);
+        serialize_str(v: &str);
+        serialize_bytes(v: &[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(name: &'static str);
+        serialize_unit_variant(name: &'static str, index: u32, variant: &'static str);
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_some(&Camel(value))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_struct(name, &Camel(value))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.0.serialize_newtype_variant(name, index, camel_static(variant), &Camel(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        self.0.serialize_seq(len).map(Compound)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.0.serialize_tuple(len).map(Compound)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.0.serialize_tuple_struct(name, len).map(Compound)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        self.0.serialize_tuple_variant(name, index, camel_static(variant), len).map(Compound)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        self.0.serialize_map(len).map(Compound)
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.0.serialize_struct(name, len).map(Compound)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.0.serialize_struct_variant(name, index, camel_static(variant), len).map(Compound)
+    }
+}
+
+impl<C: ser::SerializeSeq> ser::SerializeSeq for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&Camel(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: ser::SerializeTuple> ser::SerializeTuple for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_element(&Camel(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&Camel(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(&Camel(value))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+/// map 키는 그대로 두되, flatten 필드가 있는 구조체는 serde가 map으로 쓰므로 필드 이름만 골라 변환
+/// serde는 구조체 필드와 flatten된 구조체 필드를 `serialize_entry("이름", ..)`(키 타입 `str`)로 넘기고,
+/// map 항목(flatten된 map 포함)은 `&K` 참조로 넘기므로 키 타입으로 구분한다
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.0.serialize_key(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_value(&Camel(value))
+    }
+
+    fn serialize_entry<K: Serialize + ?Sized, V: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), Self::Error> {
+        if std::any::type_name::<K>() == "str" {
+            self.0.serialize_entry(&CamelKey(key), &Camel(value))
+        } else {
+            self.0.serialize_entry(key, &Camel(value))
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: ser::SerializeStruct> ser::SerializeStruct for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(camel_static(key), &Camel(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.0.skip_field(camel_static(key))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+impl<C: ser::SerializeStructVariant> ser::SerializeStructVariant for Compound<C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        self.0.serialize_field(camel_static(key), &Camel(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), Self::Error> {
+        self.0.skip_field(camel_static(key))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.0.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use super::*;
+
+    #[test]
+    fn test_to_camel_case() {
+        assert_eq!(to_camel_case("health_score"), "healthScore");
+        assert_eq!(to_camel_case("year_month"), "yearMonth");
+        assert_eq!(to_camel_case("code"), "code");
+        assert_eq!(to_camel_case("_private"), "_private");
+        assert_eq!(camel_static("_private"), "_private");
+        assert_eq!(camel_static("biz_no"), "bizNo");
+    }
+
+    #[derive(Serialize)]
+    struct Item {
+        biz_no: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ceo_name: Option<&'static str>,
+    }
+
+    #[derive(Serialize)]
+    struct Source {
+        data_source: &'static str,
+    }
+
+    #[derive(Serialize)]
+    struct Page {
+        company_count: u32,
+        items: Vec<Item>,
+        by_status: BTreeMap<&'static str, u32>,
+        #[serde(flatten)]
+        source: Source,
+        #[serde(flatten)]
+        extra: BTreeMap<String, u32>,
+        latest_item: Option<Item>,
+    }
+
+    #[test]
+    fn test_camelize_field_names_and_keep_map_keys() {
+        let page = Page {
+            company_count: 3,
+            items: vec![Item { biz_no: "1234567890", ceo_name: None }],
+            by_status: BTreeMap::from([("not_active", 1)]),
+            source: Source { data_source: "NPS" },
+            extra: BTreeMap::from([("region_code".to_string(), 43111)]),
+            latest_item: Some(Item { biz_no: "1", ceo_name: Some("김대표") }),
+        };
+        // 필드 이름(flatten 구조체 포함)만 바꾸고 map 키(flatten map 포함)는 데이터라 그대로
+        let expected = serde_json::json!({
+            "companyCount": 3,
+            "items": [{ "bizNo": "1234567890" }],
+            "byStatus": { "not_active": 1 },
+            "dataSource": "NPS",
+            "region_code": 43111,
+            "latestItem": { "bizNo": "1", "ceoName": "김대표" },
+        });
+        assert_eq!(serde_json::to_value(Camel(&page)).unwrap(), expected);
+
+        // json! 객체도 map이라 키를 바꾸지 않고, 안의 구조체만 변환
+        let value = serde_json::json!({ "C26_1": [{ "new_hires": 2 }] });
+        assert_eq!(serde_json::to_value(Camel(&value)).unwrap(), value);
+        let shares = BTreeMap::from([("43111", vec![Item { biz_no: "1", ceo_name: None }])]);
+        assert_eq!(
+            serde_json::to_value(Camel(&shares)).unwrap(),
+            serde_json::json!({ "43111": [{ "bizNo": "1" }] })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_json_follows_request_case() {
+        let body = |resp: Response| async {
+            let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        };
+        let item = || Json(Item { biz_no: "1", ceo_name: None });
+
+        assert_eq!(body(item().into_response()).await, r#"{"biz_no":"1"}"#);
+        let camel = CAMEL_CASE.scope(true, async { item().into_response() }).await;
+        assert_eq!(body(camel).await, r#"{"bizNo":"1"}"#);
+        let snake = CAMEL_CASE.scope(false, async { item().into_response() }).await;
+        assert_eq!(body(snake).await, r#"{"biz_no":"1"}"#);
+    }
+
+    #[test]
+    fn test_requested_case() {
+        assert_eq!(requested_case(Some("q=abc&case=camel")), Some(true));
+        assert_eq!(requested_case(Some("case=snake")), Some(false));
+        assert_eq!(requested_case(Some("case=kebab")), None);
+        assert_eq!(requested_case(None), None);
+    }
+}
@@ -1,29 +1,49 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::{
     extract::{Path, Query, State},
     routing::get,
-    Json, Router,
+    Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+use kiep_core::models::StatementType;
+use kiep_core::units::{Amount, MoneyUnit};
 
 use crate::AppState;
+use super::case::Json;
+use super::complexes::{self, CompanySort, ComplexCompanyItem, ComplexDetail};
+use super::{like_contains, Paginated};
+use super::openapi::ErrorBody;
 use super::regions::AppError;
 
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/search", get(search_companies))
         .route("/{biz_no}", get(get_company))
+        .route("/{biz_no}/procurements", get(get_company_procurements))
+        .route("/{biz_no}/complex", get(get_company_complex))
+        .route("/{biz_no}/complex-history", get(get_company_complex_history))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct SearchParams {
-    q: String,
+    /// 이름 부분 일치 또는 사업자번호 정확히 일치, 생략 시 업종/지역 필터만으로 조회
+    q: Option<String>,
+    /// KSIC 업종코드 앞부분
+    industry_code: Option<String>,
+    /// 법정동코드 앞부분 (시도 2자리면 소속 시군구 전체)
+    region_code: Option<String>,
     limit: Option<i64>,
+    offset: Option<i64>,
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct CompanySearchResult {
     biz_no: String,
     name: String,
@@ -34,33 +54,94 @@ pub struct CompanySearchResult {
     market_type: Option<String>,
 }
 
+/// $1 = like_contains()로 이스케이프된 패턴, $2 = 검색어, $3 = 업종코드 앞부분, $4 = 법정동코드 앞부분
+/// (각각 NULL이면 조건 없음), biz_no를 마지막 정렬 키로 써서 페이지 경계 고정
+const SEARCH_COMPANIES_SQL: &str = r#"
+    SELECT biz_no, name, biz_status, industry_code, bjd_code, stock_code, market_type
+    FROM companies
+    WHERE ($2::text IS NULL OR name ILIKE $1 ESCAPE '\' OR biz_no = $2)
+      AND ($3::text IS NULL OR industry_code LIKE $3 || '%')
+      AND ($4::text IS NULL OR bjd_code LIKE $4 || '%')
+    ORDER BY similarity(name, COALESCE($2, '')) DESC, biz_no
+    LIMIT $5 OFFSET $6
+"#;
+
+/// SEARCH_COMPANIES_SQL과 같은 조건의 전체 건수
+const COUNT_COMPANIES_SQL: &str = r#"
+    SELECT COUNT(*)
+    FROM companies
+    WHERE ($2::text IS NULL OR name ILIKE $1 ESCAPE '\' OR biz_no = $2)
+      AND ($3::text IS NULL OR industry_code LIKE $3 || '%')
+      AND ($4::text IS NULL OR bjd_code LIKE $4 || '%')
+"#;
+
+/// 코드 앞부분 필터 검증: 빈 값은 None, 영숫자 10자 이하만 허용 (LIKE 와일드카드 차단)
+fn code_prefix(name: &str, raw: Option<&str>) -> Result<Option<String>, AppError> {
+    let Some(code) = raw.map(str::trim).filter(|c| !c.is_empty()) else {
+        return Ok(None);
+    };
+    if code.len() > 10 || !code.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err(AppError::bad_request(format!(
+            "{} must be up to 10 letters or digits, got {:?}",
+            name, code
+        )));
+    }
+    Ok(Some(code.to_string()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/search",
+    tag = "companies",
+    params(SearchParams),
+    responses(
+        (status = 200, description = "검색 결과 (검색어 유사도 순)", body = Paginated<CompanySearchResult>),
+        (status = 400, description = "검색 조건 없음 또는 잘못된 코드", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(
+    q = ?params.q,
+    industry_code = ?params.industry_code,
+    region_code = ?params.region_code,
+    limit = ?params.limit,
+    offset = ?params.offset
+))]
 async fn search_companies(
     State(state): State<Arc<AppState>>,
     Query(params): Query<SearchParams>,
-) -> Result<Json<Vec<CompanySearchResult>>, AppError> {
-    let limit = params.limit.unwrap_or(20).max(1).min(100);
-    let pattern = format!("%{}%", params.q);
+) -> Result<Json<Paginated<CompanySearchResult>>, AppError> {
+    let limit = state.config.limits.company_search.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let q = params.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+    let industry_code = code_prefix("industry_code", params.industry_code.as_deref())?;
+    let region_code = code_prefix("region_code", params.region_code.as_deref())?;
+    if q.is_none() && industry_code.is_none() && region_code.is_none() {
+        return Err(AppError::bad_request("one of q, industry_code or region_code is required"));
+    }
+    let pattern = q.map(like_contains);
 
-    let results = sqlx::query_as::<_, CompanySearchResult>(
-        r#"
-        SELECT biz_no, name, biz_status, industry_code, bjd_code, stock_code, market_type
-        FROM companies
-        WHERE name ILIKE $1 OR biz_no = $2
-        ORDER BY similarity(name, $3) DESC
-        LIMIT $4
-        "#,
-    )
-    .bind(&pattern)
-    .bind(&params.q)
-    .bind(&params.q)
-    .bind(limit)
-    .fetch_all(&state.pool)
-    .await?;
+    let total: i64 = sqlx::query_scalar(COUNT_COMPANIES_SQL)
+        .bind(&pattern)
+        .bind(q)
+        .bind(&industry_code)
+        .bind(&region_code)
+        .fetch_one(&state.pool)
+        .await?;
 
-    Ok(Json(results))
+    let items = sqlx::query_as::<_, CompanySearchResult>(SEARCH_COMPANIES_SQL)
+        .bind(&pattern)
+        .bind(q)
+        .bind(&industry_code)
+        .bind(&region_code)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&state.pool)
+        .await?;
+
+    Ok(Json(Paginated { total, limit, offset, items }))
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct CompanyDetail {
     biz_no: String,
     name: String,
@@ -77,7 +158,7 @@ pub struct CompanyDetail {
     complex_id: Option<String>,
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct EmploymentEntry {
     year_month: String,
     employee_count: i32,
@@ -93,19 +174,157 @@ pub struct FinancialEntry {
     operating_income: Option<i64>,
     net_income: Option<i64>,
     total_assets: Option<i64>,
+    statement_type: String,
 }
 
-#[derive(Serialize)]
+/// 단위 환산된 재무 항목 (금액: 원 단위면 숫자, 그 외 단위는 {value, remainder})
+#[derive(Serialize, ToSchema)]
+pub struct FinancialView {
+    fiscal_year: i32,
+    quarter: i16,
+    statement_type: String,
+    #[schema(value_type = Option<Object>)]
+    revenue: Option<Amount>,
+    #[schema(value_type = Option<Object>)]
+    operating_income: Option<Amount>,
+    #[schema(value_type = Option<Object>)]
+    net_income: Option<Amount>,
+    #[schema(value_type = Option<Object>)]
+    total_assets: Option<Amount>,
+}
+
+impl FinancialEntry {
+    fn scaled(self, unit: MoneyUnit) -> FinancialView {
+        FinancialView {
+            fiscal_year: self.fiscal_year,
+            quarter: self.quarter,
+            statement_type: self.statement_type,
+            revenue: self.revenue.map(|v| unit.scale(v)),
+            operating_income: self.operating_income.map(|v| unit.scale(v)),
+            net_income: self.net_income.map(|v| unit.scale(v)),
+            total_assets: self.total_assets.map(|v| unit.scale(v)),
+        }
+    }
+}
+
+/// $3 = 구분(NULL이면 연결 우선, 연결이 없는 기업만 별도)
+const COMPANY_FINANCIALS_SQL: &str = r#"
+    SELECT fiscal_year, quarter, revenue, operating_income, net_income, total_assets,
+           statement_type
+    FROM financials
+    WHERE biz_no = $1
+      AND statement_type = COALESCE($3, (
+          SELECT CASE WHEN bool_or(statement_type = 'consolidated')
+                      THEN 'consolidated' ELSE 'separate' END
+          FROM financials WHERE biz_no = $1
+      ))
+    ORDER BY fiscal_year DESC, quarter DESC
+    LIMIT $2
+    "#;
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CompanyParams {
+    /// won(기본)/manwon/eokwon
+    #[serde(default)]
+    #[param(value_type = Option<String>)]
+    unit: MoneyUnit,
+    /// consolidated/separate, 없으면 연결 우선 (연결이 없을 때만 별도)
+    #[param(value_type = Option<String>)]
+    statement: Option<StatementType>,
+}
+
+#[derive(Serialize, ToSchema)]
 pub struct CompanyFullProfile {
     company: CompanyDetail,
     employment: Vec<EmploymentEntry>,
-    financials: Vec<FinancialEntry>,
+    financials: Vec<FinancialView>,
+    amount_unit: &'static str,
 }
 
+/// 앞부분이 같은 사업자번호가 여럿일 때의 후보 목록
+#[derive(Serialize, ToSchema)]
+pub struct CompanyCandidates {
+    prefix: String,
+    /// 사업자번호 순으로 최대 company_search 기본 건수
+    candidates: Vec<CompanySearchResult>,
+}
+
+/// 전체 프로필, 또는 앞부분 조회가 여러 기업에 걸리면 후보 목록
+#[derive(Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum CompanyLookup {
+    Profile(Box<CompanyFullProfile>),
+    Ambiguous(CompanyCandidates),
+}
+
+/// $1 = 숫자만으로 된 사업자번호 앞부분
+/// 적재 시 10자리로 0을 채운 번호(normalize_biz_no, 예: NPS 6자리 '123456' → '0000123456')도 함께 찾는다
+const COMPANY_PREFIX_SQL: &str = r#"
+    SELECT biz_no, name, biz_status, industry_code, bjd_code, stock_code, market_type
+    FROM companies
+    WHERE biz_no LIKE $1 || '%' OR biz_no = LPAD($1, 10, '0')
+    ORDER BY biz_no
+    LIMIT $2
+"#;
+
+/// 10자리 미만 숫자면 사업자번호 앞부분 (NPS는 앞 6자리만 제공)
+fn biz_no_prefix(biz_no: &str) -> Option<&str> {
+    (!biz_no.is_empty() && biz_no.len() < 10 && biz_no.bytes().all(|b| b.is_ascii_digit()))
+        .then_some(biz_no)
+}
+
+/// 사업자번호로 기업 프로필 조회
+/// 10자리 미만 숫자는 앞부분 일치로 찾아 1건이면 그 기업의 프로필, 여러 건이면 후보 목록을 반환
+/// 그 외 입력은 정확히 일치하는 기업만 찾으며, 없으면 404
+#[utoipa::path(
+    get,
+    path = "/companies/{biz_no}",
+    tag = "companies",
+    params(("biz_no" = String, Path, description = "사업자번호 10자리 또는 앞부분 숫자"), CompanyParams),
+    responses(
+        (status = 200, description = "기업 프로필 또는 후보 목록", body = CompanyLookup),
+        (status = 404, description = "일치하는 기업 없음", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(biz_no = %biz_no, statement = ?params.statement))]
 async fn get_company(
     State(state): State<Arc<AppState>>,
     Path(biz_no): Path<String>,
-) -> Result<Json<Option<CompanyFullProfile>>, AppError> {
+    Query(params): Query<CompanyParams>,
+) -> Result<Json<CompanyLookup>, AppError> {
+    let missing = || AppError::not_found(format!("company {} not found", biz_no));
+    let Some(prefix) = biz_no_prefix(&biz_no) else {
+        let profile = fetch_company_profile(&state, &biz_no, &params).await?.ok_or_else(missing)?;
+        return Ok(Json(CompanyLookup::Profile(Box::new(profile))));
+    };
+
+    let mut candidates = sqlx::query_as::<_, CompanySearchResult>(COMPANY_PREFIX_SQL)
+        .bind(prefix)
+        .bind(state.config.limits.company_search.default)
+        .fetch_all(&state.pool)
+        .await?;
+
+    let lookup = match candidates.len() {
+        0 => return Err(missing()),
+        1 => {
+            let biz_no = candidates.remove(0).biz_no;
+            let profile = fetch_company_profile(&state, &biz_no, &params).await?.ok_or_else(missing)?;
+            CompanyLookup::Profile(Box::new(profile))
+        }
+        _ => CompanyLookup::Ambiguous(CompanyCandidates {
+            prefix: prefix.to_string(),
+            candidates,
+        }),
+    };
+    Ok(Json(lookup))
+}
+
+async fn fetch_company_profile(
+    state: &AppState,
+    biz_no: &str,
+    params: &CompanyParams,
+) -> Result<Option<CompanyFullProfile>, AppError> {
     let company = sqlx::query_as::<_, CompanyDetail>(
         r#"
         SELECT biz_no, name, corp_no, ceo_name, biz_status, biz_type, biz_sector,
@@ -113,12 +332,12 @@ async fn get_company(
         FROM companies WHERE biz_no = $1
         "#,
     )
-    .bind(&biz_no)
+    .bind(biz_no)
     .fetch_optional(&state.pool)
     .await?;
 
     let Some(company) = company else {
-        return Ok(Json(None));
+        return Ok(None);
     };
 
     let employment = sqlx::query_as::<_, EmploymentEntry>(
@@ -127,29 +346,502 @@ async fn get_company(
         FROM employment_series
         WHERE biz_no = $1
         ORDER BY year_month DESC
-        LIMIT 36
+        LIMIT $2
         "#,
     )
-    .bind(&biz_no)
+    .bind(biz_no)
+    .bind(state.config.limits.company_employment.default)
     .fetch_all(&state.pool)
     .await?;
 
-    let financials = sqlx::query_as::<_, FinancialEntry>(
+    let financials = sqlx::query_as::<_, FinancialEntry>(COMPANY_FINANCIALS_SQL)
+        .bind(biz_no)
+        .bind(state.config.limits.company_financials.default)
+        .bind(params.statement.map(|s| s.as_str()))
+        .fetch_all(&state.pool)
+        .await?;
+
+    Ok(Some(CompanyFullProfile {
+        company,
+        employment,
+        financials: financials.into_iter().map(|f| f.scaled(params.unit)).collect(),
+        amount_unit: params.unit.label(),
+    }))
+}
+
+#[derive(FromRow)]
+pub struct ProcurementRow {
+    bid_no: Option<String>,
+    contract_no: Option<String>,
+    title: Option<String>,
+    contract_type: Option<String>,
+    amount: Option<i64>,
+    contract_date: Option<String>,
+    agency: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ProcurementEntry {
+    bid_no: Option<String>,
+    contract_no: Option<String>,
+    title: Option<String>,
+    contract_type: Option<String>,
+    amount: Option<Amount>,
+    contract_date: Option<String>,
+    agency: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CompanyProcurements {
+    biz_no: String,
+    amount_unit: &'static str,
+    contracts: Vec<ProcurementEntry>,
+}
+
+#[derive(Deserialize)]
+pub struct ProcurementParams {
+    #[serde(default)]
+    unit: MoneyUnit,
+    limit: Option<i64>,
+}
+
+#[tracing::instrument(skip_all, fields(biz_no = %biz_no, limit = ?params.limit))]
+async fn get_company_procurements(
+    State(state): State<Arc<AppState>>,
+    Path(biz_no): Path<String>,
+    Query(params): Query<ProcurementParams>,
+) -> Result<Json<CompanyProcurements>, AppError> {
+    let rows = sqlx::query_as::<_, ProcurementRow>(
         r#"
-        SELECT fiscal_year, quarter, revenue, operating_income, net_income, total_assets
-        FROM financials
+        SELECT bid_no, contract_no, title, contract_type, amount,
+               to_char(contract_date, 'YYYY-MM-DD') as contract_date, agency
+        FROM procurement
         WHERE biz_no = $1
-        ORDER BY fiscal_year DESC, quarter DESC
-        LIMIT 12
+        ORDER BY contract_date DESC NULLS LAST
+        LIMIT $2
         "#,
     )
     .bind(&biz_no)
+    .bind(state.config.limits.company_procurements.resolve(params.limit))
     .fetch_all(&state.pool)
     .await?;
 
-    Ok(Json(Some(CompanyFullProfile {
-        company,
-        employment,
-        financials,
-    })))
+    let contracts = rows
+        .into_iter()
+        .map(|r| ProcurementEntry {
+            bid_no: r.bid_no,
+            contract_no: r.contract_no,
+            title: r.title,
+            contract_type: r.contract_type,
+            amount: r.amount.map(|v| params.unit.scale(v)),
+            contract_date: r.contract_date,
+            agency: r.agency,
+        })
+        .collect();
+
+    Ok(Json(CompanyProcurements {
+        biz_no,
+        amount_unit: params.unit.label(),
+        contracts,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct NeighborParams {
+    #[serde(default)]
+    company_sort: CompanySort,
+    limit: Option<i64>,
+}
+
+#[derive(Serialize)]
+pub struct CompanyComplexContext {
+    biz_no: String,
+    complex: ComplexDetail,
+    /// 같은 산단 입주기업 (자기 자신 제외)
+    neighbors: Vec<ComplexCompanyItem>,
+}
+
+/// 기업이 속한 산업단지 정보 + 입주기업 샘플, 산단 소속이 아니면 404
+#[tracing::instrument(skip_all, fields(biz_no = %biz_no, limit = ?params.limit))]
+async fn get_company_complex(
+    State(state): State<Arc<AppState>>,
+    Path(biz_no): Path<String>,
+    Query(params): Query<NeighborParams>,
+) -> Result<Json<CompanyComplexContext>, AppError> {
+    let complex_id: Option<Option<String>> =
+        sqlx::query_scalar("SELECT complex_id FROM companies WHERE biz_no = $1")
+            .bind(&biz_no)
+            .fetch_optional(&state.pool)
+            .await?;
+
+    let Some(complex_id) = complex_id else {
+        return Err(AppError::not_found(format!("company {} not found", biz_no)));
+    };
+    let Some(complex_id) = complex_id else {
+        return Err(AppError::not_found(format!(
+            "company {} is not in an industrial complex",
+            biz_no
+        )));
+    };
+    let Some(complex) = complexes::fetch_complex_detail(&state.pool, &complex_id).await? else {
+        return Err(AppError::not_found(format!("complex {} not found", complex_id)));
+    };
+
+    let neighbors = complexes::fetch_complex_companies(
+        &state.pool,
+        &complex_id,
+        params.company_sort,
+        state.config.limits.complex_companies.resolve(params.limit),
+        0,
+        Some(&biz_no),
+    )
+    .await?;
+
+    Ok(Json(CompanyComplexContext { biz_no, complex, neighbors }))
+}
+
+/// company_history의 산단 소속 변경 1건
+#[derive(FromRow)]
+struct ComplexChange {
+    old_value: Option<String>,
+    new_value: Option<String>,
+    changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ComplexPeriod {
+    complex_id: String,
+    complex_name: Option<String>,
+    /// 소속 시작, 이력 기록 이전부터 소속이면 null
+    from: Option<DateTime<Utc>>,
+    /// 소속 종료, 현재 소속이면 null
+    to: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+pub struct CompanyComplexHistory {
+    biz_no: String,
+    current_complex_id: Option<String>,
+    /// 시간순
+    periods: Vec<ComplexPeriod>,
+}
+
+fn period(complex_id: &str, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> ComplexPeriod {
+    ComplexPeriod { complex_id: complex_id.to_string(), complex_name: None, from, to }
+}
+
+/// 변경 이력(시간순) → 산단별 소속 기간
+/// 이력이 없으면 현재 소속을 기간 미상으로, 첫 변경의 이전 값은 시작 미상 기간으로 본다
+fn complex_periods(current: Option<&str>, changes: &[ComplexChange]) -> Vec<ComplexPeriod> {
+    let Some(first) = changes.first() else {
+        return current.map(|id| period(id, None, None)).into_iter().collect();
+    };
+
+    let mut periods = Vec::new();
+    if let Some(old) = &first.old_value {
+        periods.push(period(old, None, Some(first.changed_at)));
+    }
+    for (i, change) in changes.iter().enumerate() {
+        if let Some(id) = &change.new_value {
+            let to = changes.get(i + 1).map(|next| next.changed_at);
+            periods.push(period(id, Some(change.changed_at), to));
+        }
+    }
+    periods
+}
+
+/// 기업의 산단 소속 이력 (이전/현재 산단별 기간)
+#[tracing::instrument(skip_all, fields(biz_no = %biz_no))]
+async fn get_company_complex_history(
+    State(state): State<Arc<AppState>>,
+    Path(biz_no): Path<String>,
+) -> Result<Json<CompanyComplexHistory>, AppError> {
+    let current: Option<Option<String>> =
+        sqlx::query_scalar("SELECT complex_id FROM companies WHERE biz_no = $1")
+            .bind(&biz_no)
+            .fetch_optional(&state.pool)
+            .await?;
+    let Some(current) = current else {
+        return Err(AppError::not_found(format!("company {} not found", biz_no)));
+    };
+
+    let changes = sqlx::query_as::<_, ComplexChange>(
+        r#"
+        SELECT old_value, new_value, changed_at
+        FROM company_history
+        WHERE biz_no = $1 AND field = 'complex_id'
+        ORDER BY changed_at, id
+        "#,
+    )
+    .bind(&biz_no)
+    .fetch_all(&state.pool)
+    .await?;
+
+    let mut periods = complex_periods(current.as_deref(), &changes);
+
+    let ids: Vec<&str> = periods.iter().map(|p| p.complex_id.as_str()).collect();
+    let names: HashMap<String, String> = sqlx::query_as::<_, (String, String)>(
+        "SELECT id, name FROM industrial_complexes WHERE id = ANY($1)",
+    )
+    .bind(&ids)
+    .fetch_all(&state.pool)
+    .await?
+    .into_iter()
+    .collect();
+    for p in &mut periods {
+        p.complex_name = names.get(&p.complex_id).cloned();
+    }
+
+    Ok(Json(CompanyComplexHistory { biz_no, current_complex_id: current, periods }))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    use super::*;
+    use crate::routes::{create_test_tables, get_json, test_pool, test_state};
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_get_company_404_for_unknown_biz_no() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        let app = router().with_state(test_state(pool));
+
+        // 정확히 일치 조회와 앞부분 조회 모두 404
+        for biz_no in ["1234567890", "123456"] {
+            let (status, body) = get_json(app.clone(), &format!("/{}", biz_no)).await;
+            assert_eq!(status, StatusCode::NOT_FOUND, "{}", biz_no);
+            assert_eq!(body["code"], "not_found");
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_search_percent_matches_literally() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO companies (biz_no, name) VALUES
+                ('0000000001', '50%할인마트'), ('0000000002', '500상사'),
+                ('0000000003', '5000건설'), ('0000000004', '오십_50%')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let q = "50%";
+        let mut names: Vec<String> = sqlx::query_as::<_, CompanySearchResult>(SEARCH_COMPANIES_SQL)
+            .bind(like_contains(q))
+            .bind(q)
+            .bind(None::<String>)
+            .bind(None::<String>)
+            .bind(10i64)
+            .bind(0i64)
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.name)
+            .collect();
+        names.sort();
+
+        assert_eq!(names, ["50%할인마트", "오십_50%"]);
+
+        let total: i64 = sqlx::query_scalar(COUNT_COMPANIES_SQL)
+            .bind(like_contains(q))
+            .bind(q)
+            .bind(None::<String>)
+            .bind(None::<String>)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(total, 2);
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_search_filters_by_industry_and_region() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO companies (biz_no, name, industry_code, bjd_code) VALUES
+                ('0000000001', '청주반도체', 'C26110', '4311110100'),
+                ('0000000002', '충주반도체', 'C26120', '4313010100'),
+                ('0000000003', '청주식품', 'C10710', '4311110100'),
+                ('0000000004', '부산반도체', 'C26110', '2611010100')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let search = |q: Option<&'static str>, industry: Option<&'static str>, region: Option<&'static str>| {
+            let pool = pool.clone();
+            async move {
+                let pattern = q.map(like_contains);
+                let total: i64 = sqlx::query_scalar(COUNT_COMPANIES_SQL)
+                    .bind(&pattern)
+                    .bind(q)
+                    .bind(industry)
+                    .bind(region)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                let biz_nos: Vec<String> = sqlx::query_as::<_, CompanySearchResult>(SEARCH_COMPANIES_SQL)
+                    .bind(&pattern)
+                    .bind(q)
+                    .bind(industry)
+                    .bind(region)
+                    .bind(10i64)
+                    .bind(0i64)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| r.biz_no)
+                    .collect();
+                assert_eq!(total, biz_nos.len() as i64);
+                biz_nos
+            }
+        };
+
+        // 시도 코드(43)는 소속 시군구 전체와 맞고, 검색어 없이 업종+지역만으로 조회
+        assert_eq!(search(None, Some("C26"), Some("43")).await, ["0000000001", "0000000002"]);
+        assert_eq!(search(None, Some("C26"), Some("43111")).await, ["0000000001"]);
+        assert_eq!(search(Some("청주"), None, Some("43")).await, ["0000000001", "0000000003"]);
+        assert_eq!(search(Some("반도체"), Some("C261"), None).await.len(), 3);
+    }
+
+    #[test]
+    fn test_code_prefix_validation() {
+        assert_eq!(code_prefix("region_code", Some(" 43 ")).ok().flatten().as_deref(), Some("43"));
+        assert!(matches!(code_prefix("region_code", Some("")), Ok(None)));
+        assert!(matches!(code_prefix("region_code", None), Ok(None)));
+        assert!(code_prefix("industry_code", Some("C%")).is_err());
+        assert!(code_prefix("region_code", Some("43111101001")).is_err());
+    }
+
+    #[test]
+    fn test_biz_no_prefix_only_for_short_digits() {
+        assert_eq!(biz_no_prefix("123456"), Some("123456"));
+        assert_eq!(biz_no_prefix("1234567890"), None);
+        assert_eq!(biz_no_prefix("123-45"), None);
+        assert_eq!(biz_no_prefix(""), None);
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_prefix_lookup_lists_shared_prefix() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO companies (biz_no, name) VALUES
+                ('1234567890', '청주정밀'), ('1234561111', '청주기계'),
+                ('9876543210', '오송바이오'), ('0000123456', 'NPS패딩')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let lookup = |prefix: &'static str| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, CompanySearchResult>(COMPANY_PREFIX_SQL)
+                    .bind(prefix)
+                    .bind(10i64)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| r.biz_no)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(lookup("123456").await, ["0000123456", "1234561111", "1234567890"]);
+        assert_eq!(lookup("98765").await, ["9876543210"]);
+        assert!(lookup("555").await.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_financials_do_not_mix_statement_types() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        sqlx::query(
+            r#"
+            INSERT INTO financials (biz_no, fiscal_year, quarter, revenue, statement_type) VALUES
+                ('both', 2023, 4, 1000, 'consolidated'), ('both', 2023, 4, 700, 'separate'),
+                ('both', 2022, 4, 900, 'consolidated'), ('both', 2022, 4, 600, 'separate'),
+                ('sep_only', 2023, 4, 300, 'separate')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let fetch = |biz_no: &'static str, statement: Option<StatementType>| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, FinancialEntry>(COMPANY_FINANCIALS_SQL)
+                    .bind(biz_no)
+                    .bind(10i64)
+                    .bind(statement.map(|s| s.as_str()))
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let default = fetch("both", None).await;
+        assert_eq!(default.len(), 2);
+        assert!(default.iter().all(|f| f.statement_type == "consolidated"));
+
+        let separate = fetch("both", Some(StatementType::Separate)).await;
+        assert_eq!(separate.iter().map(|f| f.revenue).collect::<Vec<_>>(), [Some(700), Some(600)]);
+
+        let fallback = fetch("sep_only", None).await;
+        assert_eq!(fallback.len(), 1);
+        assert_eq!(fallback[0].statement_type, "separate");
+    }
+
+    #[test]
+    fn test_complex_periods_from_history() {
+        let at = |day: u32| DateTime::parse_from_rfc3339(&format!("2024-01-{:02}T00:00:00Z", day))
+            .unwrap()
+            .with_timezone(&Utc);
+        let change = |old: Option<&str>, new: Option<&str>, day| ComplexChange {
+            old_value: old.map(String::from),
+            new_value: new.map(String::from),
+            changed_at: at(day),
+        };
+
+        // 이력 이전 A 소속 → B 이전 → 탈퇴 → C 입주
+        let changes = [
+            change(Some("A"), Some("B"), 10),
+            change(Some("B"), None, 20),
+            change(None, Some("C"), 25),
+        ];
+        assert_eq!(
+            complex_periods(Some("C"), &changes),
+            [
+                period("A", None, Some(at(10))),
+                period("B", Some(at(10)), Some(at(20))),
+                period("C", Some(at(25)), None),
+            ]
+        );
+
+        assert_eq!(complex_periods(Some("A"), &[]), [period("A", None, None)]);
+        assert!(complex_periods(None, &[]).is_empty());
+    }
 }
@@ -3,24 +3,32 @@ use std::sync::Arc;
 use axum::{
     extract::{Path, Query, State},
     routing::get,
-    Json, Router,
+    Router,
 };
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::{FromRow, PgPool};
+
+use kiep_core::period::YearQuarter;
 
 use crate::AppState;
+use super::case::Json;
+use super::{like_contains, Paginated};
 use super::regions::AppError;
 
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(list_complexes))
+        .route("/compare", get(compare_complexes))
         .route("/{id}", get(get_complex))
+        .route("/{id}/companies", get(list_complex_companies))
 }
 
 #[derive(Deserialize)]
 pub struct ListParams {
     complex_type: Option<String>,
     province: Option<String>,
+    /// 산단명 부분 일치
+    q: Option<String>,
 }
 
 #[derive(Serialize, FromRow)]
@@ -34,6 +42,7 @@ pub struct ComplexListItem {
     occupancy_rate: Option<f64>,
 }
 
+#[tracing::instrument(skip_all, fields(complex_type = ?params.complex_type, province = ?params.province, q = ?params.q))]
 async fn list_complexes(
     State(state): State<Arc<AppState>>,
     Query(params): Query<ListParams>,
@@ -44,17 +53,212 @@ async fn list_complexes(
         FROM industrial_complexes
         WHERE ($1::text IS NULL OR complex_type = $1)
           AND ($2::text IS NULL OR province = $2)
-        ORDER BY tenant_count DESC NULLS LAST
+          AND ($3::text IS NULL OR name ILIKE $3 ESCAPE '\')
+        ORDER BY tenant_count DESC NULLS LAST, id
         "#,
     )
     .bind(&params.complex_type)
     .bind(&params.province)
+    .bind(params.q.as_deref().map(like_contains))
     .fetch_all(&state.pool)
     .await?;
 
     Ok(Json(complexes))
 }
 
+#[derive(Deserialize)]
+pub struct CompareParams {
+    /// 쉼표로 구분한 산단 ID
+    ids: String,
+}
+
+/// 산단 ID 형식 검사 (영숫자, '-', '_' / 최대 20자)
+fn validate_complex_id(raw: &str) -> Result<String, AppError> {
+    let valid = !raw.is_empty()
+        && raw.len() <= 20
+        && raw.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(AppError::bad_request(format!("invalid complex id: {}", raw)));
+    }
+    Ok(raw.to_string())
+}
+
+/// 중복 제거 후 요청 순서 유지, max개까지만 사용
+fn parse_compare_ids(raw: &str, max: usize) -> Result<Vec<String>, AppError> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let id = validate_complex_id(id)?;
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids.truncate(max);
+    Ok(ids)
+}
+
+#[derive(FromRow)]
+pub struct ComplexCompareRow {
+    id: String,
+    name: String,
+    complex_type: String,
+    province: String,
+    tenant_count: Option<i32>,
+    operating_count: Option<i32>,
+    occupancy_rate: Option<f64>,
+    year_quarter: Option<YearQuarter>,
+    production: Option<i64>,
+    export_amount: Option<i64>,
+    employment: Option<i32>,
+}
+
+/// 비교 지표 (표/레이더 차트 공통)
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct CompareMetrics {
+    occupancy_rate: Option<f64>,
+    tenant_count: Option<f64>,
+    operating_count: Option<f64>,
+    production: Option<f64>,
+    export_amount: Option<f64>,
+    employment: Option<f64>,
+    /// 고용 1인당 생산액 (백만원)
+    productivity: Option<f64>,
+}
+
+impl CompareMetrics {
+    fn to_array(&self) -> [Option<f64>; 7] {
+        [
+            self.occupancy_rate,
+            self.tenant_count,
+            self.operating_count,
+            self.production,
+            self.export_amount,
+            self.employment,
+            self.productivity,
+        ]
+    }
+
+    fn from_array(v: [Option<f64>; 7]) -> Self {
+        Self {
+            occupancy_rate: v[0],
+            tenant_count: v[1],
+            operating_count: v[2],
+            production: v[3],
+            export_amount: v[4],
+            employment: v[5],
+            productivity: v[6],
+        }
+    }
+}
+
+impl From<&ComplexCompareRow> for CompareMetrics {
+    fn from(row: &ComplexCompareRow) -> Self {
+        let productivity = match (row.production, row.employment) {
+            (Some(p), Some(e)) if e > 0 => Some(p as f64 / e as f64),
+            _ => None,
+        };
+        Self {
+            occupancy_rate: row.occupancy_rate,
+            tenant_count: row.tenant_count.map(f64::from),
+            operating_count: row.operating_count.map(f64::from),
+            production: row.production.map(|v| v as f64),
+            export_amount: row.export_amount.map(|v| v as f64),
+            employment: row.employment.map(f64::from),
+            productivity,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ComplexCompareItem {
+    id: String,
+    name: String,
+    complex_type: String,
+    province: String,
+    /// 생산/수출/고용 기준 분기 (시계열이 없으면 null)
+    year_quarter: Option<YearQuarter>,
+    metrics: CompareMetrics,
+    /// 비교 대상 중 최댓값 대비 비율 (0~1, 값이 없거나 최댓값이 0이면 null)
+    relative: CompareMetrics,
+}
+
+/// 지표별로 비교 대상 최댓값으로 나눠 0~1로 정규화
+fn relative_metrics(metrics: &[CompareMetrics]) -> Vec<CompareMetrics> {
+    let arrays: Vec<_> = metrics.iter().map(CompareMetrics::to_array).collect();
+    let mut max = [None::<f64>; 7];
+    for values in &arrays {
+        for (m, v) in max.iter_mut().zip(values) {
+            if let Some(v) = *v {
+                *m = Some(m.map_or(v, |cur: f64| cur.max(v)));
+            }
+        }
+    }
+
+    arrays
+        .into_iter()
+        .map(|values| {
+            let mut out = [None; 7];
+            for (i, v) in values.into_iter().enumerate() {
+                out[i] = match (v, max[i]) {
+                    (Some(v), Some(m)) if m > 0.0 => Some((v / m).max(0.0)),
+                    _ => None,
+                };
+            }
+            CompareMetrics::from_array(out)
+        })
+        .collect()
+}
+
+// 요청 순서(ord)를 유지하고 산단별 최신 분기 시계열 1건을 붙임
+const COMPARE_COMPLEXES_SQL: &str = r#"
+    SELECT ic.id, ic.name, ic.complex_type, ic.province,
+           ic.tenant_count, ic.operating_count, ic.occupancy_rate,
+           latest.year_quarter, latest.production, latest.export_amount, latest.employment
+    FROM unnest($1::text[]) WITH ORDINALITY AS req(id, ord)
+    JOIN industrial_complexes ic ON ic.id = req.id
+    LEFT JOIN LATERAL (
+        SELECT cs.year_quarter, cs.production, cs.export_amount, cs.employment
+        FROM complex_series cs
+        WHERE cs.complex_id = ic.id
+        ORDER BY cs.year_quarter DESC
+        LIMIT 1
+    ) latest ON true
+    ORDER BY req.ord
+"#;
+
+#[tracing::instrument(skip_all, fields(ids = %params.ids))]
+async fn compare_complexes(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<CompareParams>,
+) -> Result<Json<Vec<ComplexCompareItem>>, AppError> {
+    let ids = parse_compare_ids(&params.ids, state.config.limits.complex_compare.max as usize)?;
+
+    // 없는 ID는 결과에서 빠짐
+    let rows = sqlx::query_as::<_, ComplexCompareRow>(COMPARE_COMPLEXES_SQL)
+        .bind(&ids)
+        .fetch_all(&state.pool)
+        .await?;
+
+    let metrics: Vec<CompareMetrics> = rows.iter().map(CompareMetrics::from).collect();
+    let relative = relative_metrics(&metrics);
+
+    let items = rows
+        .into_iter()
+        .zip(metrics)
+        .zip(relative)
+        .map(|((row, metrics), relative)| ComplexCompareItem {
+            id: row.id,
+            name: row.name,
+            complex_type: row.complex_type,
+            province: row.province,
+            year_quarter: row.year_quarter,
+            metrics,
+            relative,
+        })
+        .collect();
+
+    Ok(Json(items))
+}
+
 #[derive(Serialize, FromRow)]
 pub struct ComplexDetail {
     id: String,
@@ -71,7 +275,7 @@ pub struct ComplexDetail {
 
 #[derive(Serialize, FromRow)]
 pub struct ComplexSeriesEntry {
-    year_quarter: String,
+    year_quarter: YearQuarter,
     production: Option<i64>,
     export_amount: Option<i64>,
     employment: Option<i32>,
@@ -93,23 +297,56 @@ pub struct ComplexFullProfile {
     top_companies: Vec<ComplexCompanyItem>,
 }
 
+#[derive(Deserialize)]
+pub struct ComplexDetailParams {
+    /// 'YYYY-Qn' (포함)
+    from: Option<String>,
+    /// 'YYYY-Qn' (포함)
+    to: Option<String>,
+    #[serde(default)]
+    company_sort: CompanySort,
+}
+
+/// top_companies 정렬 기준
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CompanySort {
+    /// 상장사 우선, 이름순
+    #[default]
+    Listed,
+    /// 최신 고용인원 많은 순
+    Employees,
+    Name,
+}
+
+impl CompanySort {
+    /// 고정된 ORDER BY 절로만 매핑 (입력값이 SQL에 직접 들어가지 않음)
+    fn order_by(self) -> &'static str {
+        match self {
+            Self::Listed => "c.stock_code IS NOT NULL DESC, c.name, c.biz_no",
+            Self::Employees => "latest_emp.employee_count DESC NULLS LAST, c.name, c.biz_no",
+            Self::Name => "c.name, c.biz_no",
+        }
+    }
+}
+
+fn validate_year_quarter(raw: Option<&str>) -> Result<Option<YearQuarter>, AppError> {
+    raw.map(YearQuarter::parse)
+        .transpose()
+        .map_err(AppError::bad_request)
+}
+
+#[tracing::instrument(skip_all, fields(id = %id, from = ?params.from, to = ?params.to))]
 async fn get_complex(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
-) -> Result<Json<Option<ComplexFullProfile>>, AppError> {
-    let complex = sqlx::query_as::<_, ComplexDetail>(
-        r#"
-        SELECT id, name, complex_type, province, sigungu,
-               designated_area, industrial_area, tenant_count, operating_count, occupancy_rate
-        FROM industrial_complexes WHERE id = $1
-        "#,
-    )
-    .bind(&id)
-    .fetch_optional(&state.pool)
-    .await?;
+    Query(params): Query<ComplexDetailParams>,
+) -> Result<Json<ComplexFullProfile>, AppError> {
+    let from = validate_year_quarter(params.from.as_deref())?;
+    let to = validate_year_quarter(params.to.as_deref())?;
 
-    let Some(complex) = complex else {
-        return Ok(Json(None));
+    let Some(complex) = fetch_complex_detail(&state.pool, &id).await? else {
+        return Err(AppError::not_found(format!("complex {} not found", id)));
     };
 
     let series = sqlx::query_as::<_, ComplexSeriesEntry>(
@@ -117,16 +354,99 @@ async fn get_complex(
         SELECT year_quarter, production, export_amount, employment, operating_count
         FROM complex_series
         WHERE complex_id = $1
+          AND ($2::text IS NULL OR year_quarter >= $2)
+          AND ($3::text IS NULL OR year_quarter <= $3)
         ORDER BY year_quarter DESC
-        LIMIT 12
+        LIMIT $4
         "#,
     )
     .bind(&id)
+    .bind(from)
+    .bind(to)
+    .bind(state.config.limits.complex_series.default)
     .fetch_all(&state.pool)
     .await?;
 
+    let top_companies = fetch_complex_companies(
+        &state.pool,
+        &id,
+        params.company_sort,
+        state.config.limits.complex_companies.default,
+        0,
+        None,
+    )
+    .await?;
+
+    Ok(Json(ComplexFullProfile {
+        complex,
+        series,
+        top_companies,
+    }))
+}
+
+pub(crate) async fn fetch_complex_detail(
+    pool: &PgPool,
+    id: &str,
+) -> Result<Option<ComplexDetail>, sqlx::Error> {
+    sqlx::query_as::<_, ComplexDetail>(
+        r#"
+        SELECT id, name, complex_type, province, sigungu,
+               designated_area, industrial_area, tenant_count, operating_count, occupancy_rate
+        FROM industrial_complexes WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+#[derive(Deserialize)]
+pub struct ComplexCompaniesParams {
+    limit: Option<i64>,
+    offset: Option<i64>,
+    #[serde(default)]
+    sort: CompanySort,
+}
+
+/// 산단 입주기업 전체를 페이지 단위로 조회, 없는 산단이면 404
+#[tracing::instrument(skip_all, fields(id = %id, limit = ?params.limit, offset = ?params.offset))]
+async fn list_complex_companies(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(params): Query<ComplexCompaniesParams>,
+) -> Result<Json<Paginated<ComplexCompanyItem>>, AppError> {
+    let id = validate_complex_id(&id)?;
+    let limit = state.config.limits.complex_companies.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM industrial_complexes WHERE id = $1)")
+        .bind(&id)
+        .fetch_one(&state.pool)
+        .await?;
+    if !exists {
+        return Err(AppError::not_found(format!("complex {} not found", id)));
+    }
+
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM companies WHERE complex_id = $1")
+        .bind(&id)
+        .fetch_one(&state.pool)
+        .await?;
+    let items = fetch_complex_companies(&state.pool, &id, params.sort, limit, offset, None).await?;
+
+    Ok(Json(Paginated { total, limit, offset, items }))
+}
+
+/// 산단 입주기업 (exclude_biz_no: 결과에서 뺄 기업, 예: 조회 기준 기업 자신)
+pub(crate) async fn fetch_complex_companies(
+    pool: &PgPool,
+    id: &str,
+    sort: CompanySort,
+    limit: i64,
+    offset: i64,
+    exclude_biz_no: Option<&str>,
+) -> Result<Vec<ComplexCompanyItem>, sqlx::Error> {
     // Use LEFT JOIN with LATERAL to avoid N+1 subquery
-    let top_companies = sqlx::query_as::<_, ComplexCompanyItem>(
+    let sql = format!(
         r#"
         SELECT c.biz_no, c.name, c.stock_code, latest_emp.employee_count
         FROM companies c
@@ -138,17 +458,115 @@ async fn get_complex(
             LIMIT 1
         ) latest_emp ON true
         WHERE c.complex_id = $1
-        ORDER BY c.stock_code IS NOT NULL DESC, c.name
-        LIMIT 20
+          AND ($3::text IS NULL OR c.biz_no <> $3)
+        ORDER BY {}
+        LIMIT $2 OFFSET $4
         "#,
-    )
-    .bind(&id)
-    .fetch_all(&state.pool)
-    .await?;
+        sort.order_by()
+    );
+    sqlx::query_as::<_, ComplexCompanyItem>(&sql)
+        .bind(id)
+        .bind(limit)
+        .bind(exclude_biz_no)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+}
 
-    Ok(Json(Some(ComplexFullProfile {
-        complex,
-        series,
-        top_companies,
-    })))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::{create_test_tables, get_json, test_pool, test_state};
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_get_complex_404_for_unknown_id() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        let (status, body) = get_json(router().with_state(test_state(pool)), "/NO-SUCH").await;
+        assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+        assert_eq!(body, serde_json::json!({ "error": "complex NO-SUCH not found", "code": "not_found" }));
+    }
+
+    #[test]
+    fn test_company_sort_parses_known_values_only() {
+        let parse = |q: &str| {
+            let uri: axum::http::Uri = format!("/complexes/1?{q}").parse().unwrap();
+            Query::<ComplexDetailParams>::try_from_uri(&uri).map(|Query(p)| p)
+        };
+        assert_eq!(parse("").unwrap().company_sort.order_by(), CompanySort::Listed.order_by());
+        assert_eq!(parse("company_sort=name").unwrap().company_sort.order_by(), "c.name, c.biz_no");
+        assert!(parse("company_sort=employees").is_ok());
+        assert!(parse("company_sort=biz_no;DROP").is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_complex_companies_pages_by_employees() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            r#"
+            INSERT INTO companies (biz_no, name, stock_code, complex_id) VALUES
+                ('1', '가', NULL, 'K1'), ('2', '나', NULL, 'K1'), ('3', '다', NULL, 'K1'),
+                ('4', '라', NULL, 'K2')
+            "#,
+            r#"
+            INSERT INTO employment_series VALUES
+                ('1', '2024-01', 50), ('1', '2024-02', 5), ('2', '2024-02', 30), ('4', '2024-02', 99)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let page = |offset: i64| {
+            let pool = pool.clone();
+            async move {
+                fetch_complex_companies(&pool, "K1", CompanySort::Employees, 2, offset, None)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|c| (c.biz_no, c.employee_count))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // 최신 월 기준 고용인원 순, 고용 기록이 없는 기업은 마지막
+        assert_eq!(page(0).await, [("2".to_string(), Some(30)), ("1".to_string(), Some(5))]);
+        assert_eq!(page(2).await, [("3".to_string(), None)]);
+        assert!(page(4).await.is_empty());
+    }
+
+    #[test]
+    fn test_parse_compare_ids() {
+        let ids = parse_compare_ids(" A001, B-2 ,,A001,C_3,D4 ", 3).unwrap_or_default();
+        assert_eq!(ids, ["A001", "B-2", "C_3"]);
+        assert!(parse_compare_ids("A001,'; DROP", 5).is_err());
+        assert!(parse_compare_ids(&"X".repeat(21), 5).is_err());
+    }
+
+    #[test]
+    fn test_relative_metrics_scale_to_max() {
+        let metrics = [
+            CompareMetrics {
+                production: Some(1000.0),
+                employment: Some(10.0),
+                productivity: Some(100.0),
+                ..Default::default()
+            },
+            CompareMetrics {
+                production: Some(250.0),
+                employment: Some(0.0),
+                ..Default::default()
+            },
+        ];
+        let rel = relative_metrics(&metrics);
+        assert_eq!(rel[0].production, Some(1.0));
+        assert_eq!(rel[1].production, Some(0.25));
+        assert_eq!(rel[1].employment, Some(0.0));
+        assert_eq!(rel[1].productivity, None);
+        assert_eq!(rel[0].occupancy_rate, None);
+    }
 }
@@ -0,0 +1,113 @@
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+const CSV_CONTENT_TYPE: &str = "text/csv";
+
+/// 목록 응답 형식, 기본은 JSON
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ListFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl ListFormat {
+    /// `?format=`이 우선하고, 없으면 Accept 헤더로 판단
+    pub fn negotiate(requested: Option<Self>, headers: &HeaderMap) -> Self {
+        requested.unwrap_or_else(|| {
+            let wants_csv = headers
+                .get(header::ACCEPT)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|accept| accept.contains(CSV_CONTENT_TYPE));
+            if wants_csv { Self::Csv } else { Self::Json }
+        })
+    }
+}
+
+/// 행 목록을 첨부 파일 CSV로 응답 (필드 이름이 헤더 행, 중첩 없는 구조체만)
+/// Excel이 한글을 UTF-8로 읽도록 BOM을 붙인다
+pub struct Csv<T> {
+    pub filename: &'static str,
+    pub rows: Vec<T>,
+}
+
+fn to_csv<T: Serialize>(rows: &[T]) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer("\u{feff}".as_bytes().to_vec());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+impl<T: Serialize> IntoResponse for Csv<T> {
+    fn into_response(self) -> Response {
+        let body = match to_csv(&self.rows) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("Failed to write CSV: {}", e);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        let disposition = format!("attachment; filename=\"{}\"", self.filename);
+        let mut resp = body.into_response();
+        let headers = resp.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/csv; charset=utf-8"),
+        );
+        if let Ok(value) = HeaderValue::from_str(&disposition) {
+            headers.insert(header::CONTENT_DISPOSITION, value);
+        }
+        resp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        code: &'static str,
+        name: &'static str,
+        score: Option<f64>,
+    }
+
+    #[test]
+    fn test_to_csv_quotes_commas_and_quotes() {
+        let rows = [
+            Row { code: "43111", name: "청주시, 상당구", score: Some(61.5) },
+            Row { code: "43112", name: "\"서원\"구", score: None },
+        ];
+        let out = String::from_utf8(to_csv(&rows).unwrap()).unwrap();
+        assert_eq!(
+            out,
+            "\u{feff}code,name,score\n43111,\"청주시, 상당구\",61.5\n43112,\"\"\"서원\"\"구\",\n"
+        );
+    }
+
+    #[test]
+    fn test_format_negotiation() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ListFormat::negotiate(None, &headers), ListFormat::Json);
+
+        headers.insert(header::ACCEPT, HeaderValue::from_static("text/csv"));
+        assert_eq!(ListFormat::negotiate(None, &headers), ListFormat::Csv);
+        assert_eq!(ListFormat::negotiate(Some(ListFormat::Json), &headers), ListFormat::Json);
+    }
+
+    #[test]
+    fn test_csv_response_headers() {
+        let resp = Csv { filename: "regions.csv", rows: Vec::<Row>::new() }.into_response();
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], "text/csv; charset=utf-8");
+        assert_eq!(
+            resp.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"regions.csv\""
+        );
+    }
+}
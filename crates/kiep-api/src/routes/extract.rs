@@ -0,0 +1,23 @@
+use axum::{
+    extract::{FromRequestParts, Path},
+    http::request::Parts,
+};
+
+use super::regions::AppError;
+
+/// 경로의 지역코드를 DB 조회 전에 검증하는 extractor
+/// 5자리 시군구 또는 10자리 법정동코드만 허용하며, 10자리는 시군구 코드로 축약
+pub struct ValidatedBjd(pub String);
+
+impl<S: Send + Sync> FromRequestParts<S> for ValidatedBjd {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(raw) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(AppError::bad_request)?;
+        kiep_core::bjd::validate_region_code(&raw)
+            .map(Self)
+            .map_err(AppError::bad_request)
+    }
+}
@@ -0,0 +1,93 @@
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{header, HeaderValue, StatusCode, Uri},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+
+use super::case::Json;
+
+/// 등록되지 않은 경로 → JSON 404
+pub async fn not_found(uri: Uri) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({ "error": "not found", "code": "not_found", "path": uri.path() })),
+    )
+        .into_response()
+}
+
+/// 빈 본문의 405 응답을 허용 메서드 목록이 담긴 JSON으로 교체
+/// axum은 `Allow` 헤더를 MethodRouter 바깥에서 붙이므로 Router 전체를 감싸는 미들웨어로 사용
+pub async fn method_not_allowed(req: Request, next: Next) -> Response {
+    let resp = next.run(req).await;
+    if resp.status() != StatusCode::METHOD_NOT_ALLOWED {
+        return resp;
+    }
+
+    let (mut parts, _) = resp.into_parts();
+    let allowed: Vec<String> = parts
+        .headers
+        .get(header::ALLOW)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| {
+            v.split(',')
+                .map(str::trim)
+                .filter(|m| !m.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let body = json!({ "error": "method not allowed", "code": "method_not_allowed", "allowed": allowed })
+        .to_string();
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware, routing::get, Router};
+    use tower::{Layer, ServiceExt};
+
+    async fn call(method: &str, path: &str) -> (StatusCode, Option<String>, serde_json::Value) {
+        let router = Router::new()
+            .nest("/api/v1", Router::new().route("/regions", get(|| async { "ok" })))
+            .fallback(not_found);
+        let app = middleware::from_fn(method_not_allowed).layer(router);
+
+        let req = Request::builder().method(method).uri(path).body(Body::empty()).unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        let status = resp.status();
+        let allow = resp
+            .headers()
+            .get(header::ALLOW)
+            .map(|v| v.to_str().unwrap().to_string());
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+        (status, allow, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_returns_json_404() {
+        let (status, _, body) = call("GET", "/api/v1/nope?x=1").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body, json!({ "error": "not found", "code": "not_found", "path": "/api/v1/nope" }));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_method_returns_json_405_with_allowed() {
+        let (status, allow, body) = call("POST", "/api/v1/regions").await;
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(allow.as_deref(), Some("GET,HEAD"));
+        assert_eq!(
+            body,
+            json!({ "error": "method not allowed", "code": "method_not_allowed", "allowed": ["GET", "HEAD"] })
+        );
+    }
+}
@@ -2,51 +2,142 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Query, State},
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
     routing::get,
-    Json, Router,
+    Router,
 };
+use kiep_core::config::RegionGeometry;
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::{FromRow, PgPool};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::AppState;
-use super::regions::AppError;
+use super::case::Json;
+use super::caching;
+use super::openapi::ErrorBody;
+use super::regions::{validate_year_month, AppError};
 
 pub fn router() -> Router<Arc<AppState>> {
     Router::new().route("/choropleth", get(get_choropleth))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ChoroplethParams {
+    /// 'YYYY-MM', 생략 시 지역별 최신 월
     year_month: Option<String>,
+    #[param(inline)]
+    format: Option<ChoroplethFormat>,
+    /// 경계 단순화 허용 오차, 경계 컬럼 좌표계 단위 (SRID 4326이면 도, 5179 등 투영 좌표계면 m)
+    simplify: Option<f64>,
 }
 
-#[derive(Serialize, FromRow)]
+/// 단순화 허용 오차 상한, 이보다 크면 시군구 경계가 점/선으로 뭉개진다
+fn max_simplify_tolerance(srid: i32) -> f64 {
+    if srid == 4326 { 0.1 } else { 10_000.0 }
+}
+
+fn validate_simplify(raw: Option<f64>, srid: i32) -> Result<Option<f64>, AppError> {
+    let Some(tolerance) = raw else { return Ok(None) };
+    let max = max_simplify_tolerance(srid);
+    if !(tolerance > 0.0 && tolerance <= max) {
+        return Err(AppError::bad_request(format!(
+            "simplify must be greater than 0 and at most {} (SRID {} units)",
+            max, srid
+        )));
+    }
+    Ok(Some(tolerance))
+}
+
+/// 응답 형식, 기본은 지역별 평면 배열
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChoroplethFormat {
+    Flat,
+    /// GeoJSON FeatureCollection (`Accept: application/geo+json`과 같음)
+    Geojson,
+}
+
+const GEOJSON_CONTENT_TYPE: &str = "application/geo+json";
+
+impl ChoroplethFormat {
+    /// `?format=`이 우선하고, 없으면 Accept 헤더로 판단
+    fn negotiate(requested: Option<Self>, headers: &HeaderMap) -> Self {
+        requested.unwrap_or_else(|| {
+            let wants_geojson = headers
+                .get(header::ACCEPT)
+                .and_then(|v| v.to_str().ok())
+                .is_some_and(|accept| accept.contains(GEOJSON_CONTENT_TYPE));
+            if wants_geojson { Self::Geojson } else { Self::Flat }
+        })
+    }
+}
+
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct ChoroplethEntry {
     code: String,
     name: String,
     province: String,
+    /// insufficient_data이면 null
     health_score: Option<f64>,
+    score_version: Option<i32>,
+    insufficient_data: Option<bool>,
     company_count: Option<i32>,
     employee_count: Option<i32>,
+    /// WGS84 GeoJSON geometry
+    #[schema(value_type = Option<Object>)]
     geojson: Option<serde_json::Value>,
 }
 
-async fn get_choropleth(
-    State(state): State<Arc<AppState>>,
-    Query(params): Query<ChoroplethParams>,
-) -> Result<Json<Vec<ChoroplethEntry>>, AppError> {
-    let year_month = params.year_month.unwrap_or_default();
+/// 경계가 없는 지역은 feature로 만들지 않는다
+fn feature_collection(entries: Vec<ChoroplethEntry>) -> serde_json::Value {
+    let features: Vec<_> = entries
+        .into_iter()
+        .filter_map(|e| {
+            let geometry = e.geojson.filter(|g| !g.is_null())?;
+            Some(serde_json::json!({
+                "type": "Feature",
+                "geometry": geometry,
+                "properties": {
+                    "code": e.code,
+                    "name": e.name,
+                    "health_score": e.health_score,
+                    "company_count": e.company_count,
+                    "employee_count": e.employee_count,
+                },
+            }))
+        })
+        .collect();
+    serde_json::json!({ "type": "FeatureCollection", "features": features })
+}
 
-    let entries = sqlx::query_as::<_, ChoroplethEntry>(
+/// 경계 컬럼/SRID 설정을 반영한 choropleth 쿼리 ($1 = 기준 월, ''이면 지역별 최신)
+/// simplify이면 $2 = 허용 오차, 원래 좌표계에서 단순화한 뒤 WGS84로 변환
+fn choropleth_sql(geometry: &RegionGeometry, simplify: bool) -> String {
+    let column = format!("r.{}", geometry.column);
+    let source = if simplify {
+        format!("ST_SimplifyPreserveTopology({}, $2::float8)", column)
+    } else {
+        column.clone()
+    };
+    let wgs84 = if geometry.srid == 4326 {
+        source
+    } else {
+        format!("ST_Transform(ST_SetSRID({}, {}), 4326)", source, geometry.srid)
+    };
+    format!(
         r#"
         SELECT
             r.code,
             r.name,
             r.province,
-            rh.health_score,
+            CASE WHEN rh.insufficient_data THEN NULL ELSE rh.health_score END as health_score,
+            rh.score_version,
+            rh.insufficient_data,
             rh.company_count,
             rh.employee_count,
-            ST_AsGeoJSON(r.geom)::jsonb as geojson
+            ST_AsGeoJSON({wgs84})::jsonb as geojson
         FROM regions r
         LEFT JOIN region_health rh ON rh.region_code = r.code
             AND ($1::text = '' OR rh.year_month = $1)
@@ -55,13 +146,241 @@ async fn get_choropleth(
                 WHERE region_code = r.code
                 AND ($1::text = '' OR year_month = $1)
             )
-        WHERE r.geom IS NOT NULL
+        WHERE {column} IS NOT NULL
         ORDER BY r.code
+        "#
+    )
+}
+
+/// 시작 시 경계 컬럼이 설정대로 있는지 확인, 다르면 경고만 남긴다
+/// (컬럼이 없으면 choropleth가 오류/빈 결과, SRID가 다르면 좌표가 어긋남)
+pub async fn check_region_geometry(pool: &PgPool, geometry: &RegionGeometry) {
+    let column_type: Result<Option<String>, _> = sqlx::query_scalar(
+        r#"
+        SELECT udt_name::text FROM information_schema.columns
+        WHERE table_schema = current_schema() AND table_name = 'regions' AND column_name = $1
         "#,
     )
-    .bind(&year_month)
-    .fetch_all(&state.pool)
-    .await?;
+    .bind(&geometry.column)
+    .fetch_optional(pool)
+    .await;
+
+    match column_type {
+        Ok(Some(t)) if t == "geometry" => {}
+        Ok(Some(t)) => {
+            tracing::warn!(
+                "regions.{} is {}, not a PostGIS geometry; choropleth boundaries will not work",
+                geometry.column, t
+            );
+            return;
+        }
+        Ok(None) => {
+            tracing::error!(
+                "regions.{} does not exist (REGIONS_GEOM_COLUMN); choropleth will fail",
+                geometry.column
+            );
+            return;
+        }
+        Err(e) => {
+            tracing::warn!("Could not inspect regions.{}: {:?}", geometry.column, e);
+            return;
+        }
+    }
+
+    let declared: Result<Option<i32>, _> = sqlx::query_scalar(
+        r#"
+        SELECT srid FROM geometry_columns
+        WHERE f_table_schema = current_schema() AND f_table_name = 'regions'
+          AND f_geometry_column = $1
+        "#,
+    )
+    .bind(&geometry.column)
+    .fetch_optional(pool)
+    .await;
+
+    match declared {
+        Ok(Some(srid)) if srid == geometry.srid => {
+            tracing::info!("regions.{} geometry SRID {}", geometry.column, srid);
+        }
+        Ok(Some(0)) => tracing::warn!(
+            "regions.{} has no declared SRID; assuming {} (REGIONS_GEOM_SRID)",
+            geometry.column, geometry.srid
+        ),
+        Ok(Some(srid)) => tracing::error!(
+            "regions.{} is SRID {} but REGIONS_GEOM_SRID is {}; choropleth coordinates will be wrong",
+            geometry.column, srid, geometry.srid
+        ),
+        Ok(None) => tracing::warn!("regions.{} is not registered in geometry_columns", geometry.column),
+        Err(e) => tracing::warn!("Could not read SRID of regions.{}: {:?}", geometry.column, e),
+    }
+}
+
+#[tracing::instrument(skip_all, fields(year_month = ?params.year_month))]
+#[utoipa::path(
+    get,
+    path = "/geo/choropleth",
+    tag = "geo",
+    params(ChoroplethParams),
+    responses(
+        (status = 200, description = "지역별 건강도와 경계 (format=geojson이면 FeatureCollection)",
+         content((Vec<ChoroplethEntry> = "application/json"), (Object = "application/geo+json"))),
+        (status = 304, description = "If-Modified-Since 이후 변경 없음"),
+        (status = 400, description = "잘못된 기준 월 또는 simplify", body = ErrorBody),
+    )
+)]
+async fn get_choropleth(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(params): Query<ChoroplethParams>,
+) -> Result<Response, AppError> {
+    let year_month = validate_year_month(params.year_month.as_deref())?.unwrap_or_default();
+    let format = ChoroplethFormat::negotiate(params.format, &headers);
+    let geometry = &state.config.region_geometry;
+    let simplify = validate_simplify(params.simplify, geometry.srid)?;
+
+    let version = caching::data_version(&state.pool, "region_health").await?;
+    if let Some(resp) = caching::not_modified_response(&headers, version) {
+        return Ok(resp);
+    }
+
+    let sql = choropleth_sql(geometry, simplify.is_some());
+    let mut query = sqlx::query_as::<_, ChoroplethEntry>(&sql).bind(&year_month);
+    if let Some(tolerance) = simplify {
+        query = query.bind(tolerance);
+    }
+    let entries = query.fetch_all(&state.pool).await?;
+
+    let resp = match format {
+        ChoroplethFormat::Flat => Json(entries).into_response(),
+        ChoroplethFormat::Geojson => {
+            // GeoJSON은 표준 키 그대로 (?case=camel 미적용)
+            let mut resp = axum::Json(feature_collection(entries)).into_response();
+            resp.headers_mut()
+                .insert(header::CONTENT_TYPE, HeaderValue::from_static(GEOJSON_CONTENT_TYPE));
+            resp
+        }
+    };
+    Ok(caching::with_last_modified(resp, version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::{create_test_tables, test_pool};
+
+    #[test]
+    fn test_choropleth_sql_transforms_other_srid() {
+        let default = choropleth_sql(&RegionGeometry::default(), false);
+        assert!(default.contains("ST_AsGeoJSON(r.geom)"));
+        assert!(default.contains("WHERE r.geom IS NOT NULL"));
+
+        let korea = RegionGeometry { column: "boundary".into(), srid: 5179 };
+        let sql = choropleth_sql(&korea, false);
+        assert!(sql.contains("ST_AsGeoJSON(ST_Transform(ST_SetSRID(r.boundary, 5179), 4326))"));
+        assert!(sql.contains("WHERE r.boundary IS NOT NULL"));
+
+        // 단순화는 변환 전 원래 좌표계에서
+        let simplified = choropleth_sql(&korea, true);
+        assert!(simplified.contains(
+            "ST_Transform(ST_SetSRID(ST_SimplifyPreserveTopology(r.boundary, $2::float8), 5179), 4326)"
+        ));
+    }
+
+    #[test]
+    fn test_validate_simplify() {
+        assert_eq!(validate_simplify(None, 4326).ok(), Some(None));
+        assert_eq!(validate_simplify(Some(0.001), 4326).ok(), Some(Some(0.001)));
+        assert_eq!(validate_simplify(Some(50.0), 5179).ok(), Some(Some(50.0)));
+        for bad in [0.0, -1.0, 0.5, f64::NAN] {
+            assert!(validate_simplify(Some(bad), 4326).is_err(), "{}", bad);
+        }
+        assert!(validate_simplify(Some(20_000.0), 5179).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_simplify_shrinks_geojson() {
+        let pool = test_pool().await;
+        let postgis: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'postgis')")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        if !postgis {
+            eprintln!("PostGIS not installed, skipping");
+            return;
+        }
+
+        // 꼭짓점이 많은 원형 경계
+        create_test_tables(&pool).await;
+        for ddl in [
+            "ALTER TABLE regions ADD COLUMN geom geometry",
+            "INSERT INTO regions (code, name, province, geom) VALUES ('43111', '상당구', '충청북도', \
+             ST_SetSRID(ST_Buffer(ST_MakePoint(127.5, 36.6), 0.05, 2048), 4326))",
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let geometry = RegionGeometry::default();
+        let geojson_len = |entries: Vec<ChoroplethEntry>| entries[0].geojson.as_ref().unwrap().to_string().len();
+        let full = sqlx::query_as::<_, ChoroplethEntry>(&choropleth_sql(&geometry, false))
+            .bind("")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let simplified = sqlx::query_as::<_, ChoroplethEntry>(&choropleth_sql(&geometry, true))
+            .bind("")
+            .bind(0.001)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        assert!(geojson_len(simplified) * 4 < geojson_len(full));
+    }
+
+    fn entry(code: &str, geojson: Option<serde_json::Value>) -> ChoroplethEntry {
+        ChoroplethEntry {
+            code: code.into(),
+            name: "청주시 상당구".into(),
+            province: "충청북도".into(),
+            health_score: Some(61.5),
+            score_version: Some(1),
+            insufficient_data: Some(false),
+            company_count: Some(12),
+            employee_count: Some(340),
+            geojson,
+        }
+    }
+
+    #[test]
+    fn test_feature_collection_skips_null_geometry() {
+        let polygon = serde_json::json!({ "type": "Polygon", "coordinates": [] });
+        let fc = feature_collection(vec![
+            entry("43111", Some(polygon.clone())),
+            entry("43112", None),
+            entry("43113", Some(serde_json::Value::Null)),
+        ]);
+
+        assert_eq!(fc["type"], "FeatureCollection");
+        let features = fc["features"].as_array().unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0]["type"], "Feature");
+        assert_eq!(features[0]["geometry"], polygon);
+        assert_eq!(features[0]["properties"]["code"], "43111");
+        assert_eq!(features[0]["properties"]["employee_count"], 340);
+        assert!(features[0]["properties"].get("province").is_none());
+    }
+
+    #[test]
+    fn test_format_negotiation() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(ChoroplethFormat::negotiate(None, &headers), ChoroplethFormat::Flat);
 
-    Ok(Json(entries))
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/geo+json"));
+        assert_eq!(ChoroplethFormat::negotiate(None, &headers), ChoroplethFormat::Geojson);
+        assert_eq!(
+            ChoroplethFormat::negotiate(Some(ChoroplethFormat::Flat), &headers),
+            ChoroplethFormat::Flat
+        );
+    }
 }
@@ -1,14 +1,22 @@
 use std::sync::Arc;
 
 use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
     routing::get,
-    Json, Router,
+    Router,
 };
+use serde::Serialize;
+use sqlx::FromRow;
 
 use crate::AppState;
+use super::case::Json;
 
 pub fn router() -> Router<Arc<AppState>> {
-    Router::new().route("/ping", get(ping))
+    Router::new()
+        .route("/ping", get(ping))
+        .route("/ready", get(ready))
 }
 
 async fn ping() -> Json<serde_json::Value> {
@@ -18,3 +26,71 @@ async fn ping() -> Json<serde_json::Value> {
         "version": env!("CARGO_PKG_VERSION")
     }))
 }
+
+/// 핵심 테이블별 데이터 적재 여부
+#[derive(Serialize, FromRow)]
+pub struct DataAvailability {
+    regions: bool,
+    companies: bool,
+    employment_series: bool,
+    region_health: bool,
+}
+
+#[derive(Serialize)]
+pub struct ReadyResponse {
+    status: &'static str,
+    /// 기업/고용/건강도 테이블이 모두 비어 있음 (지역 경계만 적재된 DB 포함)
+    /// 프론트엔드에서 "데이터 미적재" 상태 표시용
+    no_data: bool,
+    tables: DataAvailability,
+    /// 성공 기록이 없거나 SOURCE_MAX_AGE_HOURS 넘게 갱신되지 않은 소스 (준비 상태에는 영향 없음)
+    stale_sources: Vec<String>,
+}
+
+/// 상태 조회에 실패하면 빈 목록 (source_status 마이그레이션 전 DB 등)
+async fn stale_sources(state: &AppState) -> Vec<String> {
+    sqlx::query_scalar(
+        r#"
+        SELECT source FROM source_status
+        WHERE last_success_at IS NULL OR last_success_at < NOW() - make_interval(hours => $1)
+        ORDER BY source
+        "#,
+    )
+    .bind(state.config.source_max_age_hours as i32)
+    .fetch_all(&state.pool)
+    .await
+    .unwrap_or_else(|e| {
+        tracing::warn!("Failed to check source freshness: {:?}", e);
+        Vec::new()
+    })
+}
+
+async fn ready(State(state): State<Arc<AppState>>) -> Response {
+    let availability = sqlx::query_as::<_, DataAvailability>(
+        r#"
+        SELECT
+            EXISTS (SELECT 1 FROM regions) as regions,
+            EXISTS (SELECT 1 FROM companies) as companies,
+            EXISTS (SELECT 1 FROM employment_series) as employment_series,
+            EXISTS (SELECT 1 FROM region_health) as region_health
+        "#,
+    )
+    .fetch_one(&state.pool)
+    .await;
+
+    match availability {
+        Ok(tables) => {
+            let no_data = !(tables.companies || tables.employment_series || tables.region_health);
+            let stale_sources = stale_sources(&state).await;
+            Json(ReadyResponse { status: "ready", no_data, tables, stale_sources }).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Readiness check failed: {:?}", e);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({ "status": "unavailable" })),
+            )
+                .into_response()
+        }
+    }
+}
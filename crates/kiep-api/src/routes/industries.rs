@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::AppState;
+use super::case::Json;
+use super::regions::{validate_year_month, AppError};
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new().route("/ranking", get(industry_ranking))
+}
+
+/// 순위 기준
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RankingSort {
+    /// 전년 동월 대비 고용 증감률
+    #[default]
+    Growth,
+    /// 총 고용인원
+    Employment,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Desc,
+    Asc,
+}
+
+impl RankingSort {
+    /// 고정된 ORDER BY 절로만 매핑
+    fn order_by(self, order: SortOrder) -> &'static str {
+        match (self, order) {
+            (Self::Growth, SortOrder::Desc) => "growth_rate DESC NULLS LAST, industry_code",
+            (Self::Growth, SortOrder::Asc) => "growth_rate ASC NULLS LAST, industry_code",
+            (Self::Employment, SortOrder::Desc) => "employee_count DESC, industry_code",
+            (Self::Employment, SortOrder::Asc) => "employee_count ASC, industry_code",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RankingParams {
+    /// 'YYYY-MM', 없으면 최신 고용 데이터 월
+    year_month: Option<String>,
+    /// 시도명 (regions.province)
+    province: Option<String>,
+    #[serde(default)]
+    sort: RankingSort,
+    #[serde(default)]
+    order: SortOrder,
+    limit: Option<i64>,
+}
+
+#[derive(Serialize, FromRow)]
+pub struct IndustryRankItem {
+    #[sqlx(skip)]
+    rank: usize,
+    /// KSIC 중분류 2자리 (대분류 문자는 떼어냄), KSIC 코드가 아니거나 없으면 "unknown"
+    industry_code: String,
+    company_count: i64,
+    employee_count: i64,
+    prev_employee_count: Option<i64>,
+    growth_rate: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct IndustryRanking {
+    year_month: Option<String>,
+    province: Option<String>,
+    items: Vec<IndustryRankItem>,
+}
+
+/// 기업별 당월/전년 동월 고용을 KSIC 중분류로 합산
+/// 업종코드는 "C26110"/"26110" 모두 "26"으로 묶고, NPS 업종명처럼 코드가 아닌 값은 "unknown"으로 모은다
+/// $1 = year_month, $2 = 시도명, $3 = limit
+fn industry_ranking_sql(sort: RankingSort, order: SortOrder) -> String {
+    format!(
+        r#"
+        WITH periods AS (
+            SELECT $1::text as cur,
+                   to_char(to_date($1, 'YYYY-MM') - interval '1 year', 'YYYY-MM') as prev
+        ),
+        agg AS (
+            SELECT COALESCE(substring(BTRIM(c.industry_code) FROM '^[A-Za-z]?([0-9]{{2}})[0-9]*$'), 'unknown')
+                       as industry_code,
+                   COUNT(DISTINCT c.biz_no) FILTER (WHERE es.year_month = p.cur) as company_count,
+                   SUM(es.employee_count) FILTER (WHERE es.year_month = p.cur) as employee_count,
+                   SUM(es.employee_count) FILTER (WHERE es.year_month = p.prev) as prev_employee_count
+            FROM periods p
+            JOIN employment_series es ON es.year_month IN (p.cur, p.prev)
+            JOIN companies c ON c.biz_no = es.biz_no
+            LEFT JOIN regions r ON r.code = LEFT(c.bjd_code, 5)
+            WHERE $2::text IS NULL OR r.province = $2
+            GROUP BY 1
+        )
+        SELECT industry_code, company_count, employee_count, prev_employee_count,
+               CASE WHEN prev_employee_count > 0
+                    THEN (employee_count - prev_employee_count)::float8 / prev_employee_count * 100
+               END as growth_rate
+        FROM agg
+        WHERE employee_count IS NOT NULL
+        ORDER BY {}
+        LIMIT $3
+        "#,
+        sort.order_by(order)
+    )
+}
+
+#[tracing::instrument(skip_all, fields(year_month = ?params.year_month, province = ?params.province))]
+async fn industry_ranking(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<RankingParams>,
+) -> Result<Json<IndustryRanking>, AppError> {
+    let year_month = validate_year_month(params.year_month.as_deref())?;
+    let limit = state.config.limits.industry_ranking.resolve(params.limit);
+
+    let year_month: Option<String> = match year_month {
+        Some(ym) => Some(ym),
+        None => {
+            sqlx::query_scalar("SELECT MAX(year_month) FROM employment_series")
+                .fetch_one(&state.pool)
+                .await?
+        }
+    };
+    let Some(ym) = year_month else {
+        return Ok(Json(IndustryRanking {
+            year_month: None,
+            province: params.province,
+            items: vec![],
+        }));
+    };
+
+    let sql = industry_ranking_sql(params.sort, params.order);
+    let mut items = sqlx::query_as::<_, IndustryRankItem>(&sql)
+        .bind(&ym)
+        .bind(&params.province)
+        .bind(limit)
+        .fetch_all(&state.pool)
+        .await?;
+    for (i, item) in items.iter_mut().enumerate() {
+        item.rank = i + 1;
+    }
+
+    Ok(Json(IndustryRanking { year_month: Some(ym), province: params.province, items }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::{create_test_tables, test_pool};
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_industry_ranking_groups_by_ksic_division() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            "INSERT INTO regions (code, name, province) VALUES ('43111', '상당구', '충북')",
+            // 대분류 문자 유무와 무관하게 같은 중분류, NPS 업종명은 코드가 아니다
+            r#"
+            INSERT INTO companies (biz_no, industry_code, bjd_code) VALUES
+                ('a', 'C26110', '43111'), ('b', '26110', '43111'), ('c', '전자부품 제조업', '43111')
+            "#,
+            r#"
+            INSERT INTO employment_series VALUES
+                ('a', '2024-03', 30), ('b', '2024-03', 20), ('c', '2024-03', 5),
+                ('a', '2023-03', 25), ('b', '2023-03', 15), ('c', '2023-03', 10)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let items = sqlx::query_as::<_, IndustryRankItem>(&industry_ranking_sql(
+            RankingSort::Employment,
+            SortOrder::Desc,
+        ))
+        .bind("2024-03")
+        .bind(Some("충북"))
+        .bind(10i64)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let summary: Vec<_> = items
+            .iter()
+            .map(|i| (i.industry_code.as_str(), i.company_count, i.employee_count, i.growth_rate))
+            .collect();
+        assert_eq!(summary, [("26", 2, 50, Some(25.0)), ("unknown", 1, 5, Some(-50.0))]);
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{routing::get, Router};
+use serde::Serialize;
+
+use kiep_core::models::{BizStatus, CodeLabel, ComplexType, MarketType};
+
+use crate::AppState;
+use super::case::Json;
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new().route("/enums", get(get_enums))
+}
+
+#[derive(Serialize)]
+pub struct EnumValue {
+    value: &'static str,
+    label_ko: &'static str,
+    label_en: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct EnumDictionary {
+    biz_status: Vec<EnumValue>,
+    market_type: Vec<EnumValue>,
+    complex_type: Vec<EnumValue>,
+}
+
+fn values<T: CodeLabel>() -> Vec<EnumValue> {
+    T::ALL
+        .iter()
+        .map(|v| EnumValue {
+            value: v.code(),
+            label_ko: v.label_ko(),
+            label_en: v.label_en(),
+        })
+        .collect()
+}
+
+/// 코어 enum 정의에서 생성하므로 값을 추가하면 자동 반영
+async fn get_enums() -> Json<EnumDictionary> {
+    Json(EnumDictionary {
+        biz_status: values::<BizStatus>(),
+        market_type: values::<MarketType>(),
+        complex_type: values::<ComplexType>(),
+    })
+}
@@ -1,20 +1,240 @@
 use std::sync::Arc;
 
-use axum::Router;
+use axum::{middleware, Router};
+use serde::Serialize;
+use utoipa::ToSchema;
 
+pub mod admin;
+pub mod auth;
+pub mod caching;
+pub mod case;
 pub mod regions;
 pub mod companies;
 pub mod complexes;
+pub mod export;
+pub mod extract;
+pub mod fallback;
 pub mod geo;
 pub mod health;
+pub mod industries;
+pub mod meta;
+pub mod openapi;
+pub mod provinces;
+pub mod throttle;
 
 use crate::AppState;
 
-pub fn api_router() -> Router<Arc<AppState>> {
+/// /admin만 API 키 검사, 나머지 조회 라우트는 공개
+/// region_health만 읽는 지역 건강도/choropleth 조회에는 ETag + Cache-Control
+pub fn api_router(state: &Arc<AppState>) -> Router<Arc<AppState>> {
+    let etag = || middleware::from_fn_with_state(state.clone(), caching::etag);
     Router::new()
-        .nest("/regions", regions::router())
+        .nest("/regions", regions::router().merge(regions::health_router().route_layer(etag())))
+        .nest("/provinces", provinces::router())
         .nest("/companies", companies::router())
         .nest("/complexes", complexes::router())
-        .nest("/geo", geo::router())
+        .nest("/industries", industries::router())
+        .nest("/geo", geo::router().route_layer(etag()))
         .nest("/health", health::router())
+        .nest("/meta", meta::router())
+        .nest(
+            "/admin",
+            admin::router().route_layer(middleware::from_fn_with_state(
+                state.clone(),
+                auth::require_api_key,
+            )),
+        )
+        .fallback(fallback::not_found)
+}
+
+/// 부분 일치 LIKE 패턴 생성, 입력의 '%', '_', '\'는 문자 그대로 매칭되도록 이스케이프
+/// SQL에서는 `ILIKE $n ESCAPE '\'`와 함께 사용
+pub fn like_contains(q: &str) -> String {
+    let mut pattern = String::with_capacity(q.len() + 2);
+    pattern.push('%');
+    for ch in q.chars() {
+        if matches!(ch, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(ch);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// 페이지네이션 응답 봉투
+#[derive(Serialize, ToSchema)]
+pub struct Paginated<T> {
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    pub items: Vec<T>,
+}
+
+/// DB 테스트용 단일 커넥션 풀, 해당 테스트는 #[ignore]라 `-- --ignored`로 돌릴 때만 만든다
+/// TEST_DATABASE_URL이 없거나 접속에 실패하면 건너뛰지 않고 실패한다
+#[cfg(test)]
+pub(crate) async fn test_pool() -> sqlx::PgPool {
+    let url = std::env::var("TEST_DATABASE_URL").expect("DB tests need TEST_DATABASE_URL");
+    sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&url)
+        .await
+        .expect("failed to connect to TEST_DATABASE_URL")
+}
+
+/// DB 테스트용 TEMP 테이블 (같은 세션의 실제 테이블을 가림), 각 테스트는 필요한 행만 넣는다
+/// 핸들러 SQL이 읽는 컬럼만 실제 스키마 순서대로 두고, PostGIS 컬럼은 필요한 테스트가 직접 추가한다
+#[cfg(test)]
+pub(crate) async fn create_test_tables(pool: &sqlx::PgPool) {
+    for ddl in [
+        r#"
+        CREATE TEMP TABLE regions (
+            code TEXT PRIMARY KEY, name TEXT, province TEXT,
+            center_lon FLOAT8, center_lat FLOAT8, area_km2 FLOAT8
+        )
+        "#,
+        r#"
+        CREATE TEMP TABLE companies (
+            biz_no TEXT PRIMARY KEY, corp_no TEXT, name TEXT, ceo_name TEXT, biz_status TEXT,
+            biz_type TEXT, biz_sector TEXT, industry_code TEXT, bjd_code TEXT, address TEXT,
+            stock_code TEXT, market_type TEXT, complex_id TEXT
+        )
+        "#,
+        "CREATE TEMP TABLE employment_series (biz_no TEXT, year_month TEXT, employee_count INT)",
+        r#"
+        CREATE TEMP TABLE financials (
+            biz_no TEXT, fiscal_year INT, quarter SMALLINT, revenue BIGINT,
+            operating_income BIGINT, net_income BIGINT, total_assets BIGINT, statement_type TEXT
+        )
+        "#,
+        r#"
+        CREATE TEMP TABLE industrial_complexes (
+            id TEXT PRIMARY KEY, name TEXT, complex_type TEXT, province TEXT, sigungu TEXT,
+            designated_area FLOAT8, industrial_area FLOAT8, tenant_count INT,
+            operating_count INT, occupancy_rate FLOAT8
+        )
+        "#,
+        r#"
+        CREATE TEMP TABLE region_health (
+            region_code TEXT, year_month TEXT, health_score FLOAT8, score_version INT NOT NULL DEFAULT 1,
+            score_weights TEXT, insufficient_data BOOL NOT NULL DEFAULT false,
+            company_count INT, employee_count INT, new_biz_count INT, closed_biz_count INT,
+            employment_growth FLOAT8, new_biz_rate FLOAT8, closure_rate FLOAT8,
+            avg_revenue_growth FLOAT8, complex_utilization FLOAT8
+        )
+        "#,
+        "CREATE TEMP TABLE data_version (name TEXT PRIMARY KEY, updated_at TIMESTAMPTZ)",
+    ] {
+        sqlx::query(ddl).execute(pool).await.unwrap();
+    }
+}
+
+/// test_pool 위에 기본 설정으로 만든 상태 (환경 변수를 읽지 않음)
+#[cfg(test)]
+pub(crate) fn test_state(pool: sqlx::PgPool) -> Arc<AppState> {
+    use kiep_core::config::{Config, Limits, RegionGeometry};
+
+    let config = Config {
+        database_url: String::new(),
+        db_application_name: None,
+        api_host: "127.0.0.1".into(),
+        api_port: 0,
+        camel_case_responses: false,
+        admin_api_key: None,
+        api_requests_per_minute: None,
+        trusted_proxies: Vec::new(),
+        nps_api_key: None,
+        nts_api_key: None,
+        fsc_api_key: None,
+        pps_api_key: None,
+        kicox_api_key: None,
+        vworld_api_key: None,
+        api_rate_limit_per_sec: None,
+        api_rate_limit_burst: None,
+        api_cache_dir: None,
+        api_cache_ttl_secs: 0,
+        health_min_companies: 5,
+        health_weights: Default::default(),
+        source_max_age_hours: 24,
+        region_geometry: RegionGeometry::default(),
+        limits: Limits::default(),
+    };
+    Arc::new(AppState { pool, config, throttle: None })
+}
+
+/// router에 GET 요청을 보내 상태 코드와 JSON 본문 반환
+#[cfg(test)]
+pub(crate) async fn get_json(router: Router, uri: &str) -> (axum::http::StatusCode, serde_json::Value) {
+    use tower::ServiceExt;
+
+    let req = axum::http::Request::builder().uri(uri).body(axum::body::Body::empty()).unwrap();
+    let resp = router.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_like_contains_escapes_wildcards() {
+        assert_eq!(like_contains("삼성"), "%삼성%");
+        assert_eq!(like_contains("50%"), "%50\\%%");
+        assert_eq!(like_contains("a_b"), "%a\\_b%");
+        assert_eq!(like_contains("c:\\x"), "%c:\\\\x%");
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_company_writes_are_never_served_from_etag() {
+        use axum::http::{header, Request, StatusCode};
+        use tower::ServiceExt;
+
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        for ddl in [
+            "INSERT INTO data_version VALUES ('region_health', NOW())",
+            "INSERT INTO regions (code, name, province) VALUES ('43111', '상당구', '충청북도')",
+            "INSERT INTO region_health (region_code, year_month) VALUES ('43111', '2024-03')",
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+        let state = test_state(pool.clone());
+        let app = api_router(&state).with_state(state);
+        let get = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let req = Request::builder()
+                    .uri(uri)
+                    .header(header::IF_NONE_MATCH, "*")
+                    .body(axum::body::Body::empty())
+                    .unwrap();
+                let resp = app.oneshot(req).await.unwrap();
+                let (status, etag) = (resp.status(), resp.headers().get(header::ETAG).cloned());
+                let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+                (status, etag, serde_json::from_slice::<serde_json::Value>(&bytes).ok())
+            }
+        };
+
+        // region_health만 읽는 라우트는 ETag 캐시 대상
+        let (status, etag, _) = get("/regions/ranking").await;
+        assert_eq!(status, StatusCode::NOT_MODIFIED);
+        assert!(etag.is_some());
+
+        // 기업 수를 읽는 지역 상세는 region_health 버전과 무관하게 매번 새로 조회
+        let (status, etag, body) = get("/regions/43111").await;
+        assert_eq!((status, etag), (StatusCode::OK, None));
+        assert_eq!(body.unwrap()["company_count"], 0);
+
+        sqlx::query("INSERT INTO companies (biz_no, bjd_code, biz_status) VALUES ('1234567890', '43111', 'active')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (status, _, body) = get("/regions/43111").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.unwrap()["company_count"], 1);
+    }
 }
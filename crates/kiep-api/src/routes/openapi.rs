@@ -0,0 +1,135 @@
+use std::sync::LazyLock;
+
+use axum::http::header;
+use axum::response::IntoResponse;
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::{OpenApi, ToSchema};
+
+use super::{companies, geo, regions};
+
+/// 오류 응답 본문 (AppError)
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody {
+    /// 사람이 읽는 메시지, 서버 내부 오류는 상세 내용을 숨긴다
+    error: String,
+    /// bad_request / not_found / database_error / internal_error
+    code: String,
+}
+
+/// 핸들러 어노테이션에서 생성한 API 명세, 경로는 /api/v1 기준
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "KIEP API", description = "지역 산업 생태계 건강도 조회 API"),
+    servers((url = "/api/v1")),
+    paths(
+        regions::list_regions,
+        regions::get_region,
+        regions::get_region_health,
+        regions::get_region_health_trend,
+        regions::compare_regions,
+        regions::region_ranking,
+        companies::search_companies,
+        companies::get_company,
+        geo::get_choropleth,
+    ),
+    tags(
+        (name = "regions", description = "시군구 지역과 건강도"),
+        (name = "companies", description = "기업 검색/프로필"),
+        (name = "geo", description = "지도 표시용"),
+    )
+)]
+pub struct ApiDoc;
+
+/// 객체 키를 사전순으로 다시 정렬 (serde_json preserve_order 여부와 무관하게 같은 순서)
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().map(|(k, v)| (k, sort_keys(v))).collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+/// 명세 JSON (키 정렬 + 들여쓰기 + 끝 줄바꿈), 같은 코드면 실행마다 같은 바이트
+/// 서버 응답과 `kiep-cli export-openapi` 파일이 모두 이 값을 쓴다
+pub fn spec_json() -> String {
+    let value = serde_json::to_value(ApiDoc::openapi()).expect("OpenAPI document serializes");
+    let mut json = serde_json::to_string_pretty(&sort_keys(value)).expect("JSON value serializes");
+    json.push('\n');
+    json
+}
+
+static SPEC_JSON: LazyLock<String> = LazyLock::new(spec_json);
+
+pub async fn openapi_json() -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "application/json")], SPEC_JSON.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_served_spec_lists_region_and_company_paths() {
+        let app: Router = Router::new().route("/openapi.json", get(openapi_json));
+        let req = Request::builder().uri("/openapi.json").body(Body::empty()).unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+        let spec: utoipa::openapi::OpenApi = serde_json::from_slice(&bytes).unwrap();
+
+        for path in ["/regions", "/regions/{code}", "/regions/compare", "/companies/search", "/companies/{biz_no}"] {
+            assert!(spec.paths.paths.contains_key(path), "missing {}", path);
+        }
+
+        let params: Vec<_> = spec.paths.paths["/companies/search"]
+            .get
+            .as_ref()
+            .and_then(|op| op.parameters.as_ref())
+            .unwrap()
+            .iter()
+            .map(|p| p.name.clone())
+            .collect();
+        assert!(params.contains(&"q".to_string()) && params.contains(&"limit".to_string()));
+
+        let schemas = &spec.components.as_ref().unwrap().schemas;
+        assert!(schemas.contains_key("RegionListItem") && schemas.contains_key("CompanySearchResult"));
+
+        // 서버 응답과 파일 내보내기가 같은 바이트
+        assert_eq!(bytes, spec_json().as_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_spec_is_not_camelized() {
+        let pool = sqlx::postgres::PgPoolOptions::new().connect_lazy("postgres://localhost/unused").unwrap();
+        let mut state = crate::routes::test_state(pool);
+        std::sync::Arc::get_mut(&mut state).unwrap().config.camel_case_responses = true;
+        let app = crate::build_app(state);
+
+        for uri in ["/api/v1/openapi.json", "/api/v1/openapi.json?case=camel"] {
+            let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let resp = app.clone().oneshot(req).await.unwrap();
+            let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+            assert_eq!(bytes, spec_json().as_bytes(), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_spec_json_is_byte_stable_and_sorted() {
+        let json = spec_json();
+        assert_eq!(json, spec_json());
+        assert!(json.ends_with("}\n"));
+
+        let value: Value = serde_json::from_str(&json).unwrap();
+        let keys: Vec<_> = value["paths"].as_object().unwrap().keys().cloned().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+}
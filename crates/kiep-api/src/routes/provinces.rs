@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::AppState;
+use super::case::Json;
+use super::regions::{validate_year_month, AppError};
+
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new().route("/summary", get(province_summary))
+}
+
+/// 시군구 점수를 시도로 묶을 때의 가중치
+#[derive(Deserialize, Serialize, Default, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RollupWeight {
+    /// 고용인원 가중 (소규모 지역 과대반영 방지)
+    #[default]
+    Employees,
+    Companies,
+    Equal,
+}
+
+#[derive(Deserialize)]
+pub struct SummaryParams {
+    /// 'YYYY-MM', 없으면 최신 건전성 데이터 월
+    year_month: Option<String>,
+    #[serde(default)]
+    weight: RollupWeight,
+}
+
+#[derive(FromRow)]
+pub struct RegionScoreRow {
+    province: String,
+    health_score: Option<f64>,
+    company_count: Option<i32>,
+    employee_count: Option<i32>,
+}
+
+impl RegionScoreRow {
+    fn weight(&self, weight: RollupWeight) -> f64 {
+        match weight {
+            RollupWeight::Employees => self.employee_count.unwrap_or(0).max(0) as f64,
+            RollupWeight::Companies => self.company_count.unwrap_or(0).max(0) as f64,
+            RollupWeight::Equal => 1.0,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct ProvinceSummary {
+    province: String,
+    region_count: usize,
+    company_count: i64,
+    employee_count: i64,
+    /// 가중 평균 건전성 점수 (가중치 합이 0이면 null)
+    health_score: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct ProvinceSummaryResponse {
+    year_month: Option<String>,
+    weight: RollupWeight,
+    provinces: Vec<ProvinceSummary>,
+}
+
+/// 점수가 있는 지역만 대상으로 시도별 가중 평균
+fn rollup(rows: &[RegionScoreRow], weight: RollupWeight) -> Vec<ProvinceSummary> {
+    // (요약, 가중 점수 합, 가중치 합)
+    let mut acc: BTreeMap<&str, (ProvinceSummary, f64, f64)> = BTreeMap::new();
+
+    for row in rows {
+        let (summary, weighted_sum, weight_sum) =
+            acc.entry(row.province.as_str()).or_insert_with(|| {
+                let summary = ProvinceSummary {
+                    province: row.province.clone(),
+                    region_count: 0,
+                    company_count: 0,
+                    employee_count: 0,
+                    health_score: None,
+                };
+                (summary, 0.0, 0.0)
+            });
+
+        summary.region_count += 1;
+        summary.company_count += row.company_count.unwrap_or(0) as i64;
+        summary.employee_count += row.employee_count.unwrap_or(0) as i64;
+
+        if let Some(score) = row.health_score {
+            let w = row.weight(weight);
+            *weighted_sum += score * w;
+            *weight_sum += w;
+        }
+    }
+
+    acc.into_values()
+        .map(|(mut summary, weighted_sum, weight_sum)| {
+            summary.health_score = (weight_sum > 0.0).then(|| weighted_sum / weight_sum);
+            summary
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip_all, fields(year_month = ?params.year_month, weight = ?params.weight))]
+async fn province_summary(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SummaryParams>,
+) -> Result<Json<ProvinceSummaryResponse>, AppError> {
+    let year_month = validate_year_month(params.year_month.as_deref())?;
+
+    let year_month: Option<String> = match year_month {
+        Some(ym) => Some(ym),
+        None => {
+            sqlx::query_scalar("SELECT MAX(year_month) FROM region_health")
+                .fetch_one(&state.pool)
+                .await?
+        }
+    };
+
+    let rows = sqlx::query_as::<_, RegionScoreRow>(
+        r#"
+        -- 공개하지 않는 점수(insufficient_data)는 시도 평균에서 제외
+        SELECT r.province,
+               CASE WHEN rh.insufficient_data THEN NULL ELSE rh.health_score END as health_score,
+               rh.company_count, rh.employee_count
+        FROM region_health rh
+        JOIN regions r ON r.code = rh.region_code
+        WHERE rh.year_month = $1
+        "#,
+    )
+    .bind(&year_month)
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(ProvinceSummaryResponse {
+        year_month,
+        weight: params.weight,
+        provinces: rollup(&rows, params.weight),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(province: &str, score: f64, companies: i32, employees: i32) -> RegionScoreRow {
+        RegionScoreRow {
+            province: province.into(),
+            health_score: Some(score),
+            company_count: Some(companies),
+            employee_count: Some(employees),
+        }
+    }
+
+    #[test]
+    fn test_weighted_vs_equal_on_skewed_distribution() {
+        // 대도시 1곳(고용 99,000, 점수 80) + 소규모 지역 1곳(고용 1,000, 점수 20)
+        let rows = [row("충북", 80.0, 900, 99_000), row("충북", 20.0, 100, 1_000)];
+
+        let equal = rollup(&rows, RollupWeight::Equal);
+        let employees = rollup(&rows, RollupWeight::Employees);
+        let companies = rollup(&rows, RollupWeight::Companies);
+
+        assert_eq!(equal[0].health_score, Some(50.0));
+        assert!((employees[0].health_score.unwrap() - 79.4).abs() < 1e-9);
+        assert!((companies[0].health_score.unwrap() - 74.0).abs() < 1e-9);
+        assert_eq!(employees[0].region_count, 2);
+        assert_eq!(employees[0].employee_count, 100_000);
+    }
+
+    #[test]
+    fn test_rollup_skips_missing_scores_and_zero_weights() {
+        let mut missing = row("부산", 0.0, 10, 100);
+        missing.health_score = None;
+        let rows = [missing, row("부산", 60.0, 5, 50), row("세종", 70.0, 0, 0)];
+
+        let summary = rollup(&rows, RollupWeight::Employees);
+        assert_eq!(summary[0].province, "부산");
+        assert_eq!(summary[0].health_score, Some(60.0));
+        assert_eq!(summary[0].region_count, 2);
+        assert_eq!(summary[1].health_score, None);
+    }
+}
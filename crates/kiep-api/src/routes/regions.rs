@@ -1,60 +1,140 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Json, Router,
+    routing::{get, post},
+    Router,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+use kiep_core::models::{
+    HealthInputs, HealthWeights, PartialComponents, RegionComparison, RegionHealth, RegionSummary,
+};
+use kiep_core::period::YearMonth;
 
 use crate::AppState;
+use super::case::Json;
+use super::caching;
+use super::export::{Csv, ListFormat};
+use super::extract::ValidatedBjd;
+use super::openapi::ErrorBody;
+use super::Paginated;
 
+/// 기업/고용 테이블을 읽는 조회 (ETL이 region_health 버전 없이 바꾸므로 ETag 캐시 제외)
 pub fn router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(list_regions))
         .route("/{code}", get(get_region))
-        .route("/{code}/health", get(get_region_health))
+        .route("/{code}/employment-series", get(get_region_employment_series))
+        .route("/{code}/new-businesses", get(get_new_businesses))
+        .route("/{code}/closed-businesses", get(get_closed_businesses))
         .route("/compare", get(compare_regions))
+        .route("/health/custom", post(custom_health))
 }
 
-#[derive(Deserialize)]
+/// region_health만 읽는 조회 (ETag 캐시 대상)
+pub fn health_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/{code}/health", get(get_region_health))
+        .route("/{code}/health/trend", get(get_region_health_trend))
+        .route("/movers", get(region_movers))
+        .route("/ranking", get(region_ranking))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ListParams {
+    /// 시도명 (예: 충청북도)
     province: Option<String>,
+    /// true면 기업도 건전성 데이터도 없는 지역 제외 (기본 false: 전체 지역)
+    #[serde(default)]
+    only_with_data: bool,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    /// csv면 현재 페이지를 CSV 첨부 파일로 (`Accept: text/csv`와 같음)
+    #[param(inline)]
+    format: Option<ListFormat>,
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct RegionListItem {
     code: String,
     name: String,
     province: String,
 }
 
+// 이름이 같은 지역이 있어도 페이지 경계가 흔들리지 않도록 code를 마지막 정렬 키로 사용
+// $1 = 시도명(NULL이면 전체), $2 = only_with_data, $3 = limit, $4 = offset
+const LIST_REGIONS_SQL: &str = r#"
+    SELECT r.code, r.name, r.province
+    FROM regions r
+    WHERE ($1::text IS NULL OR r.province = $1)
+      AND (NOT $2
+           OR EXISTS (SELECT 1 FROM region_health rh WHERE rh.region_code = r.code)
+           OR EXISTS (SELECT 1 FROM companies c WHERE c.bjd_code = r.code))
+    ORDER BY r.province, r.name, r.code
+    LIMIT $3 OFFSET $4
+"#;
+
+// LIST_REGIONS_SQL과 같은 조건의 전체 건수
+const COUNT_REGIONS_SQL: &str = r#"
+    SELECT COUNT(*)
+    FROM regions r
+    WHERE ($1::text IS NULL OR r.province = $1)
+      AND (NOT $2
+           OR EXISTS (SELECT 1 FROM region_health rh WHERE rh.region_code = r.code)
+           OR EXISTS (SELECT 1 FROM companies c WHERE c.bjd_code = r.code))
+"#;
+
+#[utoipa::path(
+    get,
+    path = "/regions",
+    tag = "regions",
+    params(ListParams),
+    responses(
+        (status = 200, description = "지역 목록 (format=csv면 현재 페이지 CSV, 전체 건수는 X-Total-Count)",
+         content((Paginated<RegionListItem> = "application/json"), (String = "text/csv"))),
+    )
+)]
+#[tracing::instrument(skip_all, fields(province = ?params.province, only_with_data = params.only_with_data, limit = ?params.limit, offset = ?params.offset))]
 async fn list_regions(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Query(params): Query<ListParams>,
-) -> Result<Json<Vec<RegionListItem>>, AppError> {
-    let regions = if let Some(province) = params.province {
-        sqlx::query_as::<_, RegionListItem>(
-            "SELECT code, name, province FROM regions WHERE province = $1 ORDER BY name",
-        )
-        .bind(province)
-        .fetch_all(&state.pool)
-        .await?
-    } else {
-        sqlx::query_as::<_, RegionListItem>(
-            "SELECT code, name, province FROM regions ORDER BY province, name",
-        )
+) -> Result<Response, AppError> {
+    let limit = state.config.limits.region_list.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let total: i64 = sqlx::query_scalar(COUNT_REGIONS_SQL)
+        .bind(&params.province)
+        .bind(params.only_with_data)
+        .fetch_one(&state.pool)
+        .await?;
+
+    let items = sqlx::query_as::<_, RegionListItem>(LIST_REGIONS_SQL)
+        .bind(&params.province)
+        .bind(params.only_with_data)
+        .bind(limit)
+        .bind(offset)
         .fetch_all(&state.pool)
-        .await?
-    };
+        .await?;
 
-    Ok(Json(regions))
+    match ListFormat::negotiate(params.format, &headers) {
+        ListFormat::Json => Ok(Json(Paginated { total, limit, offset, items }).into_response()),
+        // CSV에는 봉투가 없으므로 전체 건수는 헤더로
+        ListFormat::Csv => {
+            let csv = Csv { filename: "regions.csv", rows: items };
+            Ok(([("x-total-count", total.to_string())], csv).into_response())
+        }
+    }
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct RegionDetail {
     code: String,
     name: String,
@@ -63,118 +143,1376 @@ pub struct RegionDetail {
     center_lat: Option<f64>,
     area_km2: Option<f64>,
     company_count: Option<i64>,
+    active_count: Option<i64>,
+    suspended_count: Option<i64>,
+    closed_count: Option<i64>,
+    /// biz_status가 NULL이거나 알 수 없는 값인 기업 수
+    unknown_status_count: Option<i64>,
     employee_count: Option<i64>,
 }
 
+// biz_status 분포는 LATERAL 집계 한 번으로 계산 (NULL/미정의 상태는 unknown으로 분리)
+const REGION_DETAIL_SQL: &str = r#"
+    SELECT
+        r.code, r.name, r.province, r.center_lon, r.center_lat, r.area_km2,
+        cs.company_count, cs.active_count, cs.suspended_count, cs.closed_count,
+        cs.unknown_status_count,
+        (SELECT COALESCE(SUM(es.employee_count), 0)::bigint
+         FROM employment_series es
+         JOIN companies c ON c.biz_no = es.biz_no
+         WHERE c.bjd_code = r.code
+         AND es.year_month = (SELECT MAX(year_month) FROM employment_series)
+        ) as employee_count
+    FROM regions r
+    CROSS JOIN LATERAL (
+        SELECT
+            COUNT(*) as company_count,
+            COUNT(*) FILTER (WHERE c.biz_status = 'active') as active_count,
+            COUNT(*) FILTER (WHERE c.biz_status = 'suspended') as suspended_count,
+            COUNT(*) FILTER (WHERE c.biz_status = 'closed') as closed_count,
+            COUNT(*) FILTER (
+                WHERE c.biz_status IS NULL
+                   OR c.biz_status NOT IN ('active', 'suspended', 'closed')
+            ) as unknown_status_count
+        FROM companies c
+        WHERE c.bjd_code = r.code
+    ) cs
+    WHERE r.code = $1
+"#;
+
+#[utoipa::path(
+    get,
+    path = "/regions/{code}",
+    tag = "regions",
+    params(("code" = String, Path, description = "시군구 법정동코드 5자리")),
+    responses(
+        (status = 200, description = "지역 상세", body = RegionDetail),
+        (status = 400, description = "잘못된 지역코드", body = ErrorBody),
+        (status = 404, description = "없는 지역", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(code = %code))]
 async fn get_region(
     State(state): State<Arc<AppState>>,
-    Path(code): Path<String>,
-) -> Result<Json<Option<RegionDetail>>, AppError> {
+    ValidatedBjd(code): ValidatedBjd,
+) -> Result<Json<RegionDetail>, AppError> {
     let region = sqlx::query_as::<_, RegionDetail>(
-        r#"
-        SELECT
-            r.code, r.name, r.province, r.center_lon, r.center_lat, r.area_km2,
-            (SELECT COUNT(*) FROM companies c WHERE c.bjd_code = r.code) as company_count,
-            (SELECT COALESCE(SUM(es.employee_count::bigint), 0)
-             FROM employment_series es
-             JOIN companies c ON c.biz_no = es.biz_no
-             WHERE c.bjd_code = r.code
-             AND es.year_month = (SELECT MAX(year_month) FROM employment_series)
-            ) as employee_count
-        FROM regions r
-        WHERE r.code = $1
-        "#,
+        REGION_DETAIL_SQL,
     )
     .bind(&code)
     .fetch_optional(&state.pool)
-    .await?;
+    .await?
+    .ok_or_else(|| AppError::not_found(format!("region {} not found", code)))?;
 
     Ok(Json(region))
 }
 
-#[derive(Serialize, FromRow)]
+#[derive(Serialize, FromRow, ToSchema)]
 pub struct RegionHealthEntry {
     year_month: String,
-    health_score: f64,
+    /// insufficient_data이면 null
+    health_score: Option<f64>,
+    score_version: i32,
+    /// 기본 가중치가 아닌 HEALTH_WEIGHTS로 산출했으면 그 가중치 ('0.3,0.25,0.2,0.15,0.1' 형식)
+    score_weights: Option<String>,
+    /// 기업 수가 최소 기준(HEALTH_MIN_COMPANIES) 미만
+    insufficient_data: bool,
     company_count: Option<i32>,
     employee_count: Option<i32>,
+    new_biz_count: Option<i32>,
+    closed_biz_count: Option<i32>,
+    // 원천 지표 (저장된 값 그대로)
+    employment_growth: Option<f64>,
+    new_biz_rate: Option<f64>,
+    closure_rate: Option<f64>,
+    avg_revenue_growth: Option<f64>,
+    complex_utilization: Option<f64>,
+    /// 정규화 구성 요소, 현재 모델 버전·기본 가중치로 계산된 행에만 제공 (없는 지표는 null)
+    #[sqlx(skip)]
+    #[schema(value_type = Option<Object>)]
+    components: Option<PartialComponents>,
+}
+
+impl RegionHealthEntry {
+    fn inputs(&self) -> HealthInputs {
+        HealthInputs {
+            employment_growth: self.employment_growth,
+            new_biz_rate: self.new_biz_rate,
+            closure_rate: self.closure_rate,
+            avg_revenue_growth: self.avg_revenue_growth,
+            complex_utilization: self.complex_utilization,
+        }
+    }
+
+    /// 재계산 시와 동일하게 있는 지표만 정규화 (점수를 공개하지 않는 행은 제외)
+    fn with_components(mut self) -> Self {
+        if self.score_version == RegionHealth::SCORE_VERSION
+            && self.score_weights.is_none()
+            && !self.insufficient_data
+        {
+            self.components = Some(RegionHealth::partial_components(&self.inputs()));
+        }
+        self
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HealthRangeParams {
+    /// 'YYYY-MM' (포함)
+    from: Option<String>,
+    /// 'YYYY-MM' (포함)
+    to: Option<String>,
+    limit: Option<i64>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/regions/{code}/health",
+    tag = "regions",
+    params(("code" = String, Path, description = "시군구 법정동코드 5자리"), HealthRangeParams),
+    responses(
+        (status = 200, description = "월별 건강도 (최신 월부터)", body = Vec<RegionHealthEntry>),
+        (status = 304, description = "If-Modified-Since 이후 변경 없음"),
+        (status = 400, description = "잘못된 지역코드 또는 기간", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(code = %code, from = ?params.from, to = ?params.to))]
 async fn get_region_health(
     State(state): State<Arc<AppState>>,
-    Path(code): Path<String>,
-) -> Result<Json<Vec<RegionHealthEntry>>, AppError> {
+    headers: HeaderMap,
+    ValidatedBjd(code): ValidatedBjd,
+    Query(params): Query<HealthRangeParams>,
+) -> Result<Response, AppError> {
+    let from = validate_year_month(params.from.as_deref())?;
+    let to = validate_year_month(params.to.as_deref())?;
+
+    let version = caching::data_version(&state.pool, "region_health").await?;
+    if let Some(resp) = caching::not_modified_response(&headers, version) {
+        return Ok(resp);
+    }
+
     let entries = sqlx::query_as::<_, RegionHealthEntry>(
         r#"
-        SELECT year_month, health_score, company_count, employee_count
+        SELECT year_month,
+               CASE WHEN insufficient_data THEN NULL ELSE health_score END as health_score,
+               score_version, score_weights, insufficient_data, company_count, employee_count,
+               new_biz_count, closed_biz_count, employment_growth, new_biz_rate,
+               closure_rate, avg_revenue_growth, complex_utilization
         FROM region_health
         WHERE region_code = $1
+          AND ($2::text IS NULL OR year_month >= $2)
+          AND ($3::text IS NULL OR year_month <= $3)
         ORDER BY year_month DESC
-        LIMIT 36
+        LIMIT $4
         "#,
     )
     .bind(&code)
+    .bind(&from)
+    .bind(&to)
+    .bind(state.config.limits.region_health.resolve(params.limit))
     .fetch_all(&state.pool)
     .await?;
+    let entries: Vec<_> = entries.into_iter().map(RegionHealthEntry::with_components).collect();
 
-    Ok(Json(entries))
+    Ok(caching::with_last_modified(Json(entries).into_response(), version))
+}
+
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct RegionHealthTrendEntry {
+    year_month: String,
+    /// 비교 대상인 직전 기록 월, 첫 기록이면 null (중간에 빠진 월이 있으면 바로 앞 달이 아닐 수 있음)
+    prev_year_month: Option<String>,
+    /// insufficient_data이면 null
+    health_score: Option<f64>,
+    /// 이번 달 - 직전 달, 어느 한쪽 점수가 없으면 null
+    delta: Option<f64>,
+    /// delta / 직전 점수 × 100, 직전 점수가 0이면 null
+    pct_change: Option<f64>,
+    company_count: Option<i32>,
+    company_count_delta: Option<i32>,
+    employee_count: Option<i32>,
+    employee_count_delta: Option<i32>,
+}
+
+// 지역의 전체 기록에 LAG을 적용한 뒤 기간으로 자르므로 범위 첫 달도 이전 기록과 비교된다
+// $1 = 지역코드, $2 = from, $3 = to, $4 = limit
+const REGION_HEALTH_TREND_SQL: &str = r#"
+    SELECT year_month, prev_year_month, health_score,
+           health_score - prev_score as delta,
+           (health_score - prev_score) / NULLIF(prev_score, 0) * 100 as pct_change,
+           company_count, company_count - prev_company_count as company_count_delta,
+           employee_count, employee_count - prev_employee_count as employee_count_delta
+    FROM (
+        SELECT year_month, health_score, company_count, employee_count,
+               LAG(year_month) OVER w as prev_year_month,
+               LAG(health_score) OVER w as prev_score,
+               LAG(company_count) OVER w as prev_company_count,
+               LAG(employee_count) OVER w as prev_employee_count
+        FROM (
+            SELECT year_month, company_count, employee_count,
+                   CASE WHEN insufficient_data THEN NULL ELSE health_score END as health_score
+            FROM region_health
+            WHERE region_code = $1
+        ) rh
+        WINDOW w AS (ORDER BY year_month)
+    ) h
+    WHERE ($2::text IS NULL OR year_month >= $2)
+      AND ($3::text IS NULL OR year_month <= $3)
+    ORDER BY year_month DESC
+    LIMIT $4
+"#;
+
+/// 월별 건강도와 직전 기록 대비 변화량 (최신 월부터)
+#[utoipa::path(
+    get,
+    path = "/regions/{code}/health/trend",
+    tag = "regions",
+    params(("code" = String, Path, description = "시군구 법정동코드 5자리"), HealthRangeParams),
+    responses(
+        (status = 200, description = "월별 건강도 변화량 (최신 월부터)", body = Vec<RegionHealthTrendEntry>),
+        (status = 304, description = "If-Modified-Since 이후 변경 없음"),
+        (status = 400, description = "잘못된 지역코드 또는 기간", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(code = %code, from = ?params.from, to = ?params.to))]
+async fn get_region_health_trend(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    ValidatedBjd(code): ValidatedBjd,
+    Query(params): Query<HealthRangeParams>,
+) -> Result<Response, AppError> {
+    let from = validate_year_month(params.from.as_deref())?;
+    let to = validate_year_month(params.to.as_deref())?;
+
+    let version = caching::data_version(&state.pool, "region_health").await?;
+    if let Some(resp) = caching::not_modified_response(&headers, version) {
+        return Ok(resp);
+    }
+
+    let entries = sqlx::query_as::<_, RegionHealthTrendEntry>(REGION_HEALTH_TREND_SQL)
+        .bind(&code)
+        .bind(&from)
+        .bind(&to)
+        .bind(state.config.limits.region_health.resolve(params.limit))
+        .fetch_all(&state.pool)
+        .await?;
+
+    Ok(caching::with_last_modified(Json(entries).into_response(), version))
+}
+
+#[derive(Deserialize)]
+pub struct EmploymentSeriesParams {
+    /// 최신 고용 데이터 월부터 거슬러 올라갈 개월 수
+    months: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Serialize, FromRow)]
+pub struct RegionEmploymentPoint {
+    year_month: String,
+    /// 합산 대상 기업의 고용인원 합
+    employee_count: i64,
+    /// 합산 대상 기업 수 (해당 월 보고 + 이월)
+    company_count: i64,
+    /// 해당 월에 실제 보고된 기업 수
+    reported_count: i64,
+}
+
+#[derive(Serialize)]
+pub struct RegionEmploymentSeries {
+    code: String,
+    carry_forward_months: u32,
+    /// 오름차순
+    points: Vec<RegionEmploymentPoint>,
+}
+
+/// 보고가 없는 달은 직전 값을 최대 이 개월 수만큼 이월 (NPS 반영 지연 보정)
+const EMPLOYMENT_CARRY_FORWARD_MONTHS: u32 = 3;
+
+/// 월별 합산: 기업마다 (해당 월 - 이월 한도)..=해당 월 중 가장 최근 값 1개를 더한다
+/// 이월 한도를 넘게 보고가 끊긴 기업은 그 달 합계에서 빠지므로, 커버리지는 company_count/reported_count로 확인
+/// $1 = 지역코드, $2 = 월 목록, $3 = 월별 이월 하한 (같은 길이)
+const REGION_EMPLOYMENT_SERIES_SQL: &str = r#"
+    SELECT m.year_month,
+           COALESCE(SUM(latest.employee_count), 0)::bigint as employee_count,
+           COUNT(latest.biz_no) as company_count,
+           COUNT(latest.biz_no) FILTER (WHERE latest.year_month = m.year_month) as reported_count
+    FROM unnest($2::text[], $3::text[]) as m(year_month, carry_from)
+    LEFT JOIN LATERAL (
+        SELECT DISTINCT ON (es.biz_no) es.biz_no, es.year_month, es.employee_count
+        FROM employment_series es
+        JOIN companies c ON c.biz_no = es.biz_no
+        WHERE c.bjd_code = $1
+          AND es.year_month BETWEEN m.carry_from AND m.year_month
+        ORDER BY es.biz_no, es.year_month DESC
+    ) latest ON true
+    GROUP BY m.year_month
+    ORDER BY m.year_month
+"#;
+
+/// latest까지 months개월 (오름차순)과 월별 이월 하한
+fn series_window(latest: YearMonth, months: i64) -> (Vec<String>, Vec<String>) {
+    let mut from = latest;
+    for _ in 1..months {
+        from = from.prev();
+    }
+    YearMonth::range_inclusive(from, latest)
+        .into_iter()
+        .map(|ym| {
+            let mut carry_from = ym;
+            for _ in 0..EMPLOYMENT_CARRY_FORWARD_MONTHS {
+                carry_from = carry_from.prev();
+            }
+            (ym.to_string(), carry_from.to_string())
+        })
+        .unzip()
+}
+
+/// 지역 고용인원 월별 추이 (스파크라인용)
+#[tracing::instrument(skip_all, fields(code = %code, months = ?params.months))]
+async fn get_region_employment_series(
+    State(state): State<Arc<AppState>>,
+    ValidatedBjd(code): ValidatedBjd,
+    Query(params): Query<EmploymentSeriesParams>,
+) -> Result<Json<RegionEmploymentSeries>, AppError> {
+    let months = state.config.limits.region_employment.resolve(params.months);
+
+    let latest: Option<String> = sqlx::query_scalar("SELECT MAX(year_month) FROM employment_series")
+        .fetch_one(&state.pool)
+        .await?;
+    let points = match latest.as_deref().map(YearMonth::parse).transpose()? {
+        Some(latest) => {
+            let (year_months, carry_from) = series_window(latest, months);
+            sqlx::query_as::<_, RegionEmploymentPoint>(REGION_EMPLOYMENT_SERIES_SQL)
+                .bind(&code)
+                .bind(&year_months)
+                .bind(&carry_from)
+                .fetch_all(&state.pool)
+                .await?
+        }
+        None => vec![],
+    };
+
+    Ok(Json(RegionEmploymentSeries {
+        code,
+        carry_forward_months: EMPLOYMENT_CARRY_FORWARD_MONTHS,
+        points,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct CustomHealthRequest {
+    /// 'YYYY-MM', 없으면 최신 건전성 데이터 월
+    year_month: Option<String>,
+    #[serde(default)]
+    weights: HealthWeights,
+}
+
+#[derive(Serialize, FromRow)]
+pub struct CustomHealthEntry {
+    code: String,
+    name: String,
+    province: String,
+    /// 요청 가중치로 계산한 점수, insufficient_data이면 null
+    #[sqlx(skip)]
+    health_score: Option<f64>,
+    /// 저장된 점수 (비교용), insufficient_data이면 null
+    stored_score: Option<f64>,
+    insufficient_data: bool,
+    #[serde(skip)]
+    employment_growth: Option<f64>,
+    #[serde(skip)]
+    new_biz_rate: Option<f64>,
+    #[serde(skip)]
+    closure_rate: Option<f64>,
+    #[serde(skip)]
+    avg_revenue_growth: Option<f64>,
+    #[serde(skip)]
+    complex_utilization: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct CustomHealthResponse {
+    year_month: Option<String>,
+    weights: HealthWeights,
+    regions: Vec<CustomHealthEntry>,
+}
+
+/// 저장된 원천 지표로 가중치만 바꿔 재계산 (저장하지 않음)
+#[tracing::instrument(skip_all, fields(year_month = ?body.year_month))]
+async fn custom_health(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<CustomHealthRequest>,
+) -> Result<Json<CustomHealthResponse>, AppError> {
+    body.weights.validate().map_err(AppError::bad_request)?;
+    let year_month = validate_year_month(body.year_month.as_deref())?;
+
+    let year_month: Option<String> = match year_month {
+        Some(ym) => Some(ym),
+        None => {
+            sqlx::query_scalar("SELECT MAX(year_month) FROM region_health")
+                .fetch_one(&state.pool)
+                .await?
+        }
+    };
+
+    let mut regions = sqlx::query_as::<_, CustomHealthEntry>(
+        r#"
+        SELECT r.code, r.name, r.province,
+               CASE WHEN rh.insufficient_data THEN NULL ELSE rh.health_score END as stored_score,
+               rh.insufficient_data, rh.employment_growth, rh.new_biz_rate, rh.closure_rate,
+               rh.avg_revenue_growth, rh.complex_utilization
+        FROM region_health rh
+        JOIN regions r ON r.code = rh.region_code
+        WHERE rh.year_month = $1
+        ORDER BY r.code
+        "#,
+    )
+    .bind(&year_month)
+    .fetch_all(&state.pool)
+    .await?;
+
+    // 재계산 시와 동일하게 없는 지표는 가중치에서 빼고 다시 맞춘다
+    for entry in regions.iter_mut().filter(|entry| !entry.insufficient_data) {
+        let inputs = HealthInputs {
+            employment_growth: entry.employment_growth,
+            new_biz_rate: entry.new_biz_rate,
+            closure_rate: entry.closure_rate,
+            avg_revenue_growth: entry.avg_revenue_growth,
+            complex_utilization: entry.complex_utilization,
+        };
+        entry.health_score = RegionHealth::calculate_score_weighted(&inputs, &body.weights)
+            .map_err(AppError::bad_request)?;
+    }
+
+    Ok(Json(CustomHealthResponse {
+        year_month,
+        weights: body.weights,
+        regions,
+    }))
 }
 
 #[derive(Deserialize)]
+pub struct PeriodParams {
+    /// 'YYYY-MM'
+    period: Option<String>,
+    limit: Option<i64>,
+}
+
+#[derive(Serialize, FromRow)]
+pub struct NewBusinessEntry {
+    biz_no: String,
+    name: String,
+    industry_code: Option<String>,
+    biz_status: Option<String>,
+    first_seen_month: Option<String>,
+}
+
+/// 해당 기간에 처음 관측된 기업 목록 (period 생략 시 최신 고용 데이터 월)
+/// 최초 관측 시점 = min(companies.created_at 월, 최초 employment_series 월)
+#[tracing::instrument(skip_all, fields(code = %code, period = ?params.period))]
+async fn get_new_businesses(
+    State(state): State<Arc<AppState>>,
+    ValidatedBjd(code): ValidatedBjd,
+    Query(params): Query<PeriodParams>,
+) -> Result<Json<Vec<NewBusinessEntry>>, AppError> {
+    let period = validate_year_month(params.period.as_deref())?;
+
+    let entries = sqlx::query_as::<_, NewBusinessEntry>(
+        r#"
+        WITH first_seen AS (
+            SELECT
+                c.biz_no, c.name, c.industry_code, c.biz_status,
+                LEAST(
+                    to_char(c.created_at, 'YYYY-MM'),
+                    (SELECT MIN(es.year_month) FROM employment_series es WHERE es.biz_no = c.biz_no)
+                ) as first_seen_month
+            FROM companies c
+            WHERE c.bjd_code = $1
+        )
+        SELECT biz_no, name, industry_code, biz_status, first_seen_month
+        FROM first_seen
+        WHERE first_seen_month = COALESCE($2, (SELECT MAX(year_month) FROM employment_series))
+        ORDER BY name, biz_no
+        LIMIT $3
+        "#,
+    )
+    .bind(&code)
+    .bind(&period)
+    .bind(state.config.limits.region_businesses.resolve(params.limit))
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(entries))
+}
+
+#[derive(Serialize, FromRow)]
+pub struct ClosedBusinessEntry {
+    biz_no: String,
+    name: String,
+    industry_code: Option<String>,
+    previous_status: Option<String>,
+    closed_at: String,
+    /// 폐업 사유 (출처에서 제공되는 경우)
+    reason: Option<String>,
+    /// 마지막으로 관측된 고용인원
+    last_employee_count: Option<i32>,
+    last_employment_month: Option<String>,
+}
+
+/// 해당 기간에 폐업으로 전환된 기업 목록 (period 생략 시 이번 달)
+/// company_history의 biz_status 변경 이력 기준
+#[tracing::instrument(skip_all, fields(code = %code, period = ?params.period))]
+async fn get_closed_businesses(
+    State(state): State<Arc<AppState>>,
+    ValidatedBjd(code): ValidatedBjd,
+    Query(params): Query<PeriodParams>,
+) -> Result<Json<Vec<ClosedBusinessEntry>>, AppError> {
+    let period = validate_year_month(params.period.as_deref())?;
+
+    let entries = sqlx::query_as::<_, ClosedBusinessEntry>(
+        r#"
+        SELECT
+            c.biz_no, c.name, c.industry_code,
+            h.old_value as previous_status,
+            to_char(h.changed_at, 'YYYY-MM-DD') as closed_at,
+            h.reason,
+            last_emp.employee_count as last_employee_count,
+            last_emp.year_month as last_employment_month
+        FROM company_history h
+        JOIN companies c ON c.biz_no = h.biz_no
+        LEFT JOIN LATERAL (
+            SELECT es.employee_count, es.year_month
+            FROM employment_series es
+            WHERE es.biz_no = c.biz_no
+            ORDER BY es.year_month DESC
+            LIMIT 1
+        ) last_emp ON true
+        WHERE c.bjd_code = $1
+          AND h.field = 'biz_status'
+          AND h.new_value = 'closed'
+          AND to_char(h.changed_at, 'YYYY-MM') = COALESCE($2, to_char(NOW(), 'YYYY-MM'))
+        ORDER BY last_emp.employee_count DESC NULLS LAST, c.biz_no
+        LIMIT $3
+        "#,
+    )
+    .bind(&code)
+    .bind(&period)
+    .bind(state.config.limits.region_businesses.resolve(params.limit))
+    .fetch_all(&state.pool)
+    .await?;
+
+    Ok(Json(entries))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct CompareParams {
+    /// 쉼표로 구분한 지역코드 (예: 43111,43112)
     codes: String,
 }
 
+/// 비교 응답의 레이더 차트 축 (RegionSummary 필드 이름)
+const COMPARE_AXES: [&str; 4] = ["health_score", "growth_rate", "company_count", "employee_count"];
+
+#[derive(FromRow)]
+struct CompareRow {
+    code: String,
+    name: String,
+    province: String,
+    health_score: Option<f64>,
+    company_count: i64,
+    employee_count: Option<i32>,
+    growth_rate: Option<f64>,
+}
+
+// 지역별 최신 region_health 한 행 (insufficient_data면 점수는 null)
+// $1 = 지역코드 배열
+const COMPARE_REGIONS_SQL: &str = r#"
+    SELECT r.code, r.name, r.province,
+           CASE WHEN rh.insufficient_data THEN NULL ELSE rh.health_score END as health_score,
+           (SELECT COUNT(*) FROM companies c WHERE c.bjd_code = r.code) as company_count,
+           rh.employee_count,
+           rh.employment_growth as growth_rate
+    FROM regions r
+    LEFT JOIN LATERAL (
+        SELECT health_score, insufficient_data, employee_count, employment_growth
+        FROM region_health
+        WHERE region_code = r.code
+        ORDER BY year_month DESC
+        LIMIT 1
+    ) rh ON true
+    WHERE r.code = ANY($1)
+"#;
+
+// $1 = 지역코드 배열
+const COMPARE_INDUSTRIES_SQL: &str = r#"
+    SELECT bjd_code, COALESCE(NULLIF(industry_code, ''), 'unknown') as industry_code, COUNT(*) as company_count
+    FROM companies
+    WHERE bjd_code = ANY($1)
+    GROUP BY 1, 2
+"#;
+
+/// (지역, 업종, 기업 수) → 지역별 업종 비중 (지역마다 합 1.0)
+fn industry_shares(rows: Vec<(String, String, i64)>) -> HashMap<String, HashMap<String, f64>> {
+    let mut totals: HashMap<String, i64> = HashMap::new();
+    for (region, _, count) in &rows {
+        *totals.entry(region.clone()).or_default() += count;
+    }
+    let mut shares: HashMap<String, HashMap<String, f64>> = HashMap::new();
+    for (region, industry, count) in rows {
+        let total = totals[&region];
+        if total > 0 {
+            shares.entry(region).or_default().insert(industry, count as f64 / total as f64);
+        }
+    }
+    shares
+}
+
+/// 지역 비교 (최대 region_compare.max개, 요청 순서 유지, 없는 지역은 제외)
+#[utoipa::path(
+    get,
+    path = "/regions/compare",
+    tag = "regions",
+    params(CompareParams),
+    responses(
+        (status = 200, description = "RegionComparison: 지역별 최신 지표(regions)와 레이더 차트 축(axes)", body = Object),
+        (status = 400, description = "잘못된 지역코드", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(codes = %params.codes))]
 async fn compare_regions(
     State(state): State<Arc<AppState>>,
     Query(params): Query<CompareParams>,
-) -> Result<Json<Vec<RegionDetail>>, AppError> {
-    let codes: Vec<&str> = params.codes.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+) -> Result<Json<RegionComparison>, AppError> {
+    let mut codes = params
+        .codes
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(kiep_core::bjd::validate_region_code)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(AppError::bad_request)?;
+    codes.truncate(state.config.limits.region_compare.max as usize);
 
-    let mut results = Vec::new();
-    for code in codes.iter().take(10) {
-        if let Some(region) = sqlx::query_as::<_, RegionDetail>(
-            r#"
-            SELECT
-                r.code, r.name, r.province, r.center_lon, r.center_lat, r.area_km2,
-                (SELECT COUNT(*) FROM companies c WHERE c.bjd_code = r.code) as company_count,
-                (SELECT COALESCE(SUM(es.employee_count::bigint), 0)
-                 FROM employment_series es
-                 JOIN companies c ON c.biz_no = es.biz_no
-                 WHERE c.bjd_code = r.code
-                 AND es.year_month = (SELECT MAX(year_month) FROM employment_series)
-                ) as employee_count
-            FROM regions r
-            WHERE r.code = $1
-            "#,
-        )
-        .bind(code)
-        .fetch_optional(&state.pool)
-        .await?
-        {
-            results.push(region);
+    let rows = sqlx::query_as::<_, CompareRow>(COMPARE_REGIONS_SQL)
+        .bind(&codes)
+        .fetch_all(&state.pool)
+        .await?;
+    let industries: Vec<(String, String, i64)> = sqlx::query_as(COMPARE_INDUSTRIES_SQL)
+        .bind(&codes)
+        .fetch_all(&state.pool)
+        .await?;
+    let mut shares = industry_shares(industries);
+
+    let mut by_code: HashMap<String, CompareRow> = rows.into_iter().map(|r| (r.code.clone(), r)).collect();
+    let regions = codes
+        .iter()
+        .filter_map(|code| by_code.remove(code))
+        .map(|row| RegionSummary {
+            industry_distribution: shares.remove(&row.code).unwrap_or_default(),
+            code: row.code,
+            name: row.name,
+            province: row.province,
+            health_score: row.health_score,
+            company_count: row.company_count.clamp(0, i32::MAX as i64) as i32,
+            employee_count: row.employee_count.unwrap_or(0),
+            growth_rate: row.growth_rate,
+        })
+        .collect();
+
+    Ok(Json(RegionComparison {
+        regions,
+        axes: COMPARE_AXES.iter().map(|a| a.to_string()).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct MoversParams {
+    /// 'YYYY-MM'
+    from: Option<String>,
+    /// 'YYYY-MM'
+    to: Option<String>,
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct RegionMover {
+    code: String,
+    name: String,
+    province: String,
+    from_rank: i64,
+    to_rank: i64,
+    /// 양수 = 순위 상승 (from_rank - to_rank)
+    rank_change: i64,
+    from_score: f64,
+    to_score: f64,
+}
+
+#[derive(Serialize)]
+pub struct RegionMoversResponse {
+    from: String,
+    to: String,
+    /// 두 기간 모두 공개 점수가 있어 순위를 비교한 지역 수
+    region_count: usize,
+    gainers: Vec<RegionMover>,
+    decliners: Vec<RegionMover>,
+}
+
+/// 기간별 건강도 순위 (1 = 최고점, 동점은 같은 순위), 두 기간 모두 공개 점수가 있는 지역만 비교
+/// $1 = from, $2 = to
+const REGION_MOVERS_SQL: &str = r#"
+    WITH common AS (
+        SELECT region_code
+        FROM region_health
+        WHERE year_month IN ($1, $2) AND NOT insufficient_data
+        GROUP BY region_code
+        HAVING COUNT(DISTINCT year_month) = CASE WHEN $1 = $2 THEN 1 ELSE 2 END
+    ),
+    ranked AS (
+        SELECT rh.region_code, rh.year_month, rh.health_score,
+               RANK() OVER (PARTITION BY rh.year_month ORDER BY rh.health_score DESC) as rank
+        FROM region_health rh
+        JOIN common USING (region_code)
+        WHERE rh.year_month IN ($1, $2)
+    )
+    SELECT r.code, r.name, r.province,
+           f.rank as from_rank, t.rank as to_rank, f.rank - t.rank as rank_change,
+           f.health_score as from_score, t.health_score as to_score
+    FROM ranked f
+    JOIN ranked t ON t.region_code = f.region_code AND t.year_month = $2
+    JOIN regions r ON r.code = f.region_code
+    WHERE f.year_month = $1
+    ORDER BY rank_change DESC, r.code
+"#;
+
+/// 두 기간 사이 순위가 가장 많이 오른/내린 지역
+#[tracing::instrument(skip_all, fields(from = ?params.from, to = ?params.to, limit = ?params.limit))]
+async fn region_movers(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<MoversParams>,
+) -> Result<Json<RegionMoversResponse>, AppError> {
+    let (Some(from), Some(to)) = (
+        validate_year_month(params.from.as_deref())?,
+        validate_year_month(params.to.as_deref())?,
+    ) else {
+        return Err(AppError::bad_request("from and to are required (YYYY-MM)"));
+    };
+    let limit = state.config.limits.region_movers.resolve(params.limit) as usize;
+
+    let movers = sqlx::query_as::<_, RegionMover>(REGION_MOVERS_SQL)
+        .bind(&from)
+        .bind(&to)
+        .fetch_all(&state.pool)
+        .await?;
+    let region_count = movers.len();
+    let (gainers, decliners) = split_movers(movers, limit);
+
+    Ok(Json(RegionMoversResponse { from, to, region_count, gainers, decliners }))
+}
+
+/// rank_change 내림차순 목록 → (상승 상위 limit, 하락 상위 limit), 변동 없는 지역은 제외
+fn split_movers(mut movers: Vec<RegionMover>, limit: usize) -> (Vec<RegionMover>, Vec<RegionMover>) {
+    let mut decliners: Vec<RegionMover> = Vec::new();
+    while decliners.len() < limit && movers.last().is_some_and(|m| m.rank_change < 0) {
+        decliners.extend(movers.pop());
+    }
+    movers.retain(|m| m.rank_change > 0);
+    movers.truncate(limit);
+    (movers, decliners)
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RankingParams {
+    /// 'YYYY-MM', 생략 시 최신 월
+    year_month: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    #[serde(default)]
+    #[param(inline)]
+    order: RankOrder,
+}
+
+/// 순위 목록 정렬 방향 (desc = 건강한 지역부터)
+#[derive(Deserialize, Default, Clone, Copy, Debug, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RankOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl RankOrder {
+    /// 고정된 ORDER BY 절로만 매핑, 동점은 code 순으로 고정해 페이지 경계가 흔들리지 않게 함
+    fn order_by(self) -> &'static str {
+        match self {
+            Self::Asc => "rh.health_score ASC, r.code",
+            Self::Desc => "rh.health_score DESC, r.code",
         }
     }
+}
 
-    Ok(Json(results))
+#[derive(Serialize, FromRow, ToSchema)]
+pub struct RegionRankItem {
+    /// 1 = 최고점, 동점은 같은 순위 (정렬 방향과 무관)
+    rank: i64,
+    code: String,
+    name: String,
+    province: String,
+    health_score: f64,
+    score_version: i32,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RegionRankingResponse {
+    year_month: Option<String>,
+    total: i64,
+    limit: i64,
+    offset: i64,
+    items: Vec<RegionRankItem>,
+}
+
+/// 공개 점수가 있는 지역만 순위 매김 (insufficient_data 제외)
+/// $1 = year_month, $2 = limit, $3 = offset
+fn region_ranking_sql(order: RankOrder) -> String {
+    format!(
+        r#"
+        SELECT RANK() OVER (ORDER BY rh.health_score DESC) as rank,
+               r.code, r.name, r.province, rh.health_score, rh.score_version
+        FROM region_health rh
+        JOIN regions r ON r.code = rh.region_code
+        WHERE rh.year_month = $1 AND NOT rh.insufficient_data
+        ORDER BY {}
+        LIMIT $2 OFFSET $3
+        "#,
+        order.order_by()
+    )
+}
+
+/// 한 달의 지역 건강도 순위
+#[utoipa::path(
+    get,
+    path = "/regions/ranking",
+    tag = "regions",
+    params(RankingParams),
+    responses(
+        (status = 200, description = "건강도 순위", body = RegionRankingResponse),
+        (status = 400, description = "잘못된 기준 월", body = ErrorBody),
+    )
+)]
+#[tracing::instrument(skip_all, fields(year_month = ?params.year_month, limit = ?params.limit, order = ?params.order))]
+async fn region_ranking(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<RankingParams>,
+) -> Result<Json<RegionRankingResponse>, AppError> {
+    let limit = state.config.limits.region_ranking.resolve(params.limit);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let year_month: Option<String> = match validate_year_month(params.year_month.as_deref())? {
+        Some(ym) => Some(ym),
+        None => {
+            sqlx::query_scalar("SELECT MAX(year_month) FROM region_health")
+                .fetch_one(&state.pool)
+                .await?
+        }
+    };
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*)
+        FROM region_health rh
+        JOIN regions r ON r.code = rh.region_code
+        WHERE rh.year_month = $1 AND NOT rh.insufficient_data
+        "#,
+    )
+    .bind(&year_month)
+    .fetch_one(&state.pool)
+    .await?;
+
+    let items = sqlx::query_as::<_, RegionRankItem>(&region_ranking_sql(params.order))
+        .bind(&year_month)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&state.pool)
+        .await?;
+
+    Ok(Json(RegionRankingResponse { year_month, total, limit, offset, items }))
 }
 
 // Shared error type for API routes
-pub struct AppError(anyhow::Error);
+/// API 오류, 응답 본문은 `{"error": 메시지, "code": 종류}`
+/// 서버 내부 오류는 메시지를 숨기고 전체 내용은 로그로만 남긴다
+pub enum AppError {
+    /// 잘못된 요청 파라미터 (메시지는 클라이언트에 그대로 노출)
+    BadRequest(String),
+    NotFound(String),
+    Database(sqlx::Error),
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    pub fn bad_request(err: impl std::fmt::Display) -> Self {
+        Self::BadRequest(err.to_string())
+    }
+
+    pub fn not_found(msg: impl std::fmt::Display) -> Self {
+        Self::NotFound(msg.to_string())
+    }
+
+    /// 클라이언트가 분기에 쓰는 고정 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BadRequest(_) => "bad_request",
+            Self::NotFound(_) => "not_found",
+            Self::Database(_) => "database_error",
+            Self::Internal(_) => "internal_error",
+        }
+    }
+
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Database(_) | Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        tracing::error!("API error: {:?}", self.0);
+        let message = match &self {
+            Self::BadRequest(msg) | Self::NotFound(msg) => msg.clone(),
+            Self::Database(err) => {
+                tracing::error!("Database error: {:?}", err);
+                "Database error".into()
+            }
+            Self::Internal(err) => {
+                tracing::error!("API error: {:?}", err);
+                "Internal server error".into()
+            }
+        };
         (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({ "error": "Internal server error" })),
+            self.status(),
+            Json(serde_json::json!({ "error": message, "code": self.code() })),
         )
             .into_response()
     }
 }
 
-impl<E: Into<anyhow::Error>> From<E> for AppError {
-    fn from(err: E) -> Self {
-        Self(err.into())
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        Self::Database(err)
+    }
+}
+
+impl From<kiep_core::Error> for AppError {
+    fn from(err: kiep_core::Error) -> Self {
+        match err {
+            kiep_core::Error::Validation(msg) => Self::BadRequest(msg),
+            kiep_core::Error::NotFound(msg) => Self::NotFound(msg),
+            kiep_core::Error::Database(err) => Self::Database(err),
+            other => Self::Internal(other.into()),
+        }
+    }
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        Self::Internal(err)
+    }
+}
+
+/// 선택적 'YYYY-MM' 파라미터 검증 (정규화된 문자열로 반환)
+pub fn validate_year_month(raw: Option<&str>) -> Result<Option<String>, AppError> {
+    raw.map(|v| YearMonth::parse(v).map(|ym| ym.to_string()))
+        .transpose()
+        .map_err(AppError::bad_request)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::routes::{create_test_tables, get_json, test_pool, test_state};
+
+    #[test]
+    fn test_components_leave_missing_metrics_null() {
+        let entry = |score_weights: Option<&str>| RegionHealthEntry {
+            year_month: "2024-03".into(),
+            health_score: Some(80.0),
+            score_version: RegionHealth::SCORE_VERSION,
+            score_weights: score_weights.map(String::from),
+            insufficient_data: false,
+            company_count: Some(50),
+            employee_count: Some(110),
+            new_biz_count: Some(5),
+            closed_biz_count: Some(1),
+            employment_growth: Some(10.0),
+            new_biz_rate: Some(10.0),
+            closure_rate: Some(2.0),
+            avg_revenue_growth: None,
+            complex_utilization: None,
+            components: None,
+        };
+        let components = entry(None).with_components().components.unwrap();
+        assert_eq!(components.employment_growth, Some(1.0));
+        assert_eq!(components.new_biz_rate, Some(0.5));
+        assert_eq!(components.avg_revenue_growth, None);
+        assert_eq!(components.complex_utilization, None);
+
+        // 기본 가중치가 아닌 행은 현재 모델로 볼 수 없다
+        assert!(entry(Some("1,0,0,0,0")).with_components().components.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_get_region_404_for_unknown_code() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        sqlx::query("INSERT INTO regions (code, name, province) VALUES ('43111', '상당구', '충청북도')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let app = router().with_state(test_state(pool));
+
+        let (status, body) = get_json(app.clone(), "/99999").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body, serde_json::json!({ "error": "region 99999 not found", "code": "not_found" }));
+
+        // 있는 지역은 Option 없이 객체 그대로
+        let (status, body) = get_json(app, "/43111").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["code"], "43111");
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_list_regions_paging_is_stable_with_duplicate_names() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        sqlx::query(
+            r#"
+            INSERT INTO regions (code, name, province) VALUES
+                ('26110', '중구', '부산'), ('27110', '중구', '부산'), ('28110', '중구', '부산'),
+                ('11140', '중구', '부산'), ('26140', '서구', '부산'), ('26170', '동구', '부산'),
+                ('43110', '청주시', '충북')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        for province in [None, Some("부산")] {
+            let mut seen = Vec::new();
+            for page in 0..10i64 {
+                let rows = sqlx::query_as::<_, RegionListItem>(LIST_REGIONS_SQL)
+                    .bind(province)
+                    .bind(false)
+                    .bind(2i64)
+                    .bind(page * 2)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap();
+                if rows.is_empty() {
+                    break;
+                }
+                seen.extend(rows.into_iter().map(|r| r.code));
+            }
+
+            let expected = if province.is_some() { 6 } else { 7 };
+            let total: i64 = sqlx::query_scalar(COUNT_REGIONS_SQL)
+                .bind(province)
+                .bind(false)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+            assert_eq!(total, expected as i64);
+            let unique: HashSet<_> = seen.iter().collect();
+            assert_eq!(seen.len(), expected, "rows skipped or repeated: {:?}", seen);
+            assert_eq!(unique.len(), expected, "rows repeated: {:?}", seen);
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_list_regions_only_with_data() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            "INSERT INTO regions (code, name, province) VALUES ('43110', '청주시', '충북'), ('43130', '충주시', '충북'), ('43150', '제천시', '충북')",
+            "INSERT INTO region_health (region_code) VALUES ('43110')",
+            "INSERT INTO companies (biz_no, bjd_code) VALUES ('1234567890', '43130')",
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let codes = |only_with_data: bool| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, RegionListItem>(LIST_REGIONS_SQL)
+                    .bind(None::<String>)
+                    .bind(only_with_data)
+                    .bind(200i64)
+                    .bind(0i64)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| r.code)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(codes(false).await, ["43150", "43110", "43130"]);
+        assert_eq!(codes(true).await, ["43110", "43130"]);
+    }
+
+    #[test]
+    fn test_industry_shares_sum_to_one_per_region() {
+        let shares = industry_shares(vec![
+            ("43110".into(), "C26".into(), 3),
+            ("43110".into(), "unknown".into(), 1),
+            ("43130".into(), "C10".into(), 2),
+        ]);
+        assert_eq!(shares["43110"]["C26"], 0.75);
+        assert_eq!(shares["43110"]["unknown"], 0.25);
+        assert_eq!(shares["43130"]["C10"], 1.0);
+        assert!(!shares.contains_key("43150"));
+    }
+
+    #[tokio::test]
+    async fn test_app_error_body_has_code_and_hides_internals() {
+        let body = |err: AppError| async move {
+            let resp = err.into_response();
+            let status = resp.status();
+            let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap())
+        };
+
+        let (status, json) = body(kiep_core::Error::NotFound("region 43111".into()).into()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(json, serde_json::json!({ "error": "region 43111", "code": "not_found" }));
+
+        let (status, json) = body(kiep_core::Error::Validation("bad month".into()).into()).await;
+        assert_eq!((status, json["code"].as_str()), (StatusCode::BAD_REQUEST, Some("bad_request")));
+
+        let (status, json) = body(sqlx::Error::PoolTimedOut.into()).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(json, serde_json::json!({ "error": "Database error", "code": "database_error" }));
+
+        let (_, json) = body(kiep_core::Error::Api("secret upstream detail".into()).into()).await;
+        assert_eq!(json, serde_json::json!({ "error": "Internal server error", "code": "internal_error" }));
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_region_health_trend_deltas() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        sqlx::query(
+            r#"
+            INSERT INTO region_health
+                (region_code, year_month, health_score, insufficient_data, company_count, employee_count)
+            VALUES
+                ('43110', '2024-01', 50, false, 10, 100),
+                ('43110', '2024-02', 60, false, 12, 90),
+                ('43110', '2024-03', 70, true, 4, 95),
+                ('43130', '2024-02', 10, false, 1, 1)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let trend = |from: Option<&'static str>| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, RegionHealthTrendEntry>(REGION_HEALTH_TREND_SQL)
+                    .bind("43110")
+                    .bind(from)
+                    .bind(None::<String>)
+                    .bind(36i64)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+
+        let all = trend(None).await;
+        let months: Vec<_> = all.iter().map(|e| e.year_month.as_str()).collect();
+        assert_eq!(months, ["2024-03", "2024-02", "2024-01"]);
+        // 점수를 공개하지 않는 달은 delta도 null, 고용/기업 수 변화는 계산
+        assert_eq!((all[0].health_score, all[0].delta), (None, None));
+        assert_eq!((all[0].company_count_delta, all[0].employee_count_delta), (Some(-8), Some(5)));
+        assert_eq!((all[1].delta, all[1].pct_change), (Some(10.0), Some(20.0)));
+        assert_eq!(all[1].prev_year_month.as_deref(), Some("2024-01"));
+        // 가장 이른 달은 비교 대상 없음
+        assert_eq!((all[2].prev_year_month.as_deref(), all[2].delta, all[2].company_count_delta), (None, None, None));
+
+        // 기간을 잘라도 범위 첫 달은 이전 기록과 비교
+        let ranged = trend(Some("2024-02")).await;
+        assert_eq!(ranged.len(), 2);
+        assert_eq!(ranged[1].delta, Some(10.0));
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_region_ranking_ties_break_by_code() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            r#"
+            INSERT INTO regions (code, name, province) VALUES
+                ('43110', '청주시', '충북'), ('43130', '충주시', '충북'),
+                ('43150', '제천시', '충북'), ('43720', '보은군', '충북')
+            "#,
+            r#"
+            INSERT INTO region_health (region_code, year_month, health_score, score_version, insufficient_data) VALUES
+                ('43150', '2024-03', 70, 1, false), ('43110', '2024-03', 70, 1, false),
+                ('43130', '2024-03', 50, 1, false), ('43720', '2024-03', 90, 1, true)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let page = |order: RankOrder, offset: i64| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, RegionRankItem>(&region_ranking_sql(order))
+                    .bind("2024-03")
+                    .bind(2i64)
+                    .bind(offset)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|r| (r.rank, r.code))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // 동점(70)은 같은 순위, code 순으로 나열. insufficient_data 지역은 제외
+        let desc = [page(RankOrder::Desc, 0).await, page(RankOrder::Desc, 2).await].concat();
+        assert_eq!(desc, [(1, "43110".into()), (1, "43150".into()), (3, "43130".to_string())]);
+        let asc = page(RankOrder::Asc, 0).await;
+        assert_eq!(asc, [(3, "43130".into()), (1, "43110".to_string())]);
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_custom_health_renormalizes_and_hides_insufficient() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            "INSERT INTO regions (code, name, province) VALUES ('43110', '청주시', '충북'), ('43720', '보은군', '충북')",
+            r#"
+            INSERT INTO region_health
+                (region_code, year_month, health_score, insufficient_data, employment_growth, new_biz_rate, closure_rate)
+            VALUES
+                ('43110', '2024-03', 80, false, 5, NULL, NULL),
+                ('43720', '2024-03', 90, true, 5, 10, 2)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let body = CustomHealthRequest {
+            year_month: Some("2024-03".into()),
+            weights: "0.5,0.5,0,0,0".parse().unwrap(),
+        };
+        let Ok(Json(resp)) = custom_health(State(test_state(pool)), Json(body)).await else {
+            panic!("custom_health failed");
+        };
+
+        // 고용 +5%(0.75)만 있는 지역: 신규사업자 지표를 0으로 채우지 않는다
+        assert_eq!(resp.regions[0].health_score, Some(75.0));
+        assert_eq!(resp.regions[0].stored_score, Some(80.0));
+        assert_eq!(resp.regions[1].health_score, None);
+        assert_eq!(resp.regions[1].stored_score, None);
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_region_employment_series_carries_forward() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            "INSERT INTO companies (biz_no, bjd_code) VALUES ('a', '43110'), ('b', '43110'), ('other', '43130')",
+            // a: 매월 보고, b: 2024-01 이후 보고 없음 (3개월 이월 후 제외)
+            r#"
+            INSERT INTO employment_series VALUES
+                ('a', '2024-01', 10), ('a', '2024-02', 11), ('a', '2024-03', 12),
+                ('a', '2024-04', 13), ('a', '2024-05', 14),
+                ('b', '2024-01', 100), ('other', '2024-05', 999)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let (year_months, carry_from) = series_window(YearMonth::parse("2024-05").unwrap(), 5);
+        let points = sqlx::query_as::<_, RegionEmploymentPoint>(REGION_EMPLOYMENT_SERIES_SQL)
+            .bind("43110")
+            .bind(&year_months)
+            .bind(&carry_from)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        let summary: Vec<_> = points
+            .iter()
+            .map(|p| (p.year_month.as_str(), p.employee_count, p.company_count, p.reported_count))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("2024-01", 110, 2, 2),
+                ("2024-02", 111, 2, 1),
+                ("2024-03", 112, 2, 1),
+                ("2024-04", 113, 2, 1),
+                ("2024-05", 14, 1, 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_region_movers_ranks_common_regions() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        for ddl in [
+            "INSERT INTO regions (code, name, province) VALUES ('a', 'A', 'p'), ('b', 'B', 'p'), ('c', 'C', 'p'), ('d', 'D', 'p'), ('e', 'E', 'p')",
+            // 2024-01: a > b > c,  2024-06: c > a > b,  d는 한 기간만, e는 점수 비공개
+            r#"
+            INSERT INTO region_health (region_code, year_month, health_score, insufficient_data) VALUES
+                ('a', '2024-01', 80, false), ('b', '2024-01', 70, false), ('c', '2024-01', 60, false),
+                ('a', '2024-06', 75, false), ('b', '2024-06', 65, false), ('c', '2024-06', 90, false),
+                ('d', '2024-06', 99, false),
+                ('e', '2024-01', 10, true), ('e', '2024-06', 95, true)
+            "#,
+        ] {
+            sqlx::query(ddl).execute(&pool).await.unwrap();
+        }
+
+        let movers = sqlx::query_as::<_, RegionMover>(REGION_MOVERS_SQL)
+            .bind("2024-01")
+            .bind("2024-06")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        let ranks: Vec<_> = movers
+            .iter()
+            .map(|m| (m.code.as_str(), m.from_rank, m.to_rank, m.rank_change))
+            .collect();
+        assert_eq!(ranks, [("c", 3, 1, 2), ("a", 1, 2, -1), ("b", 2, 3, -1)]);
+
+        let (gainers, decliners) = split_movers(movers, 1);
+        assert_eq!(gainers.iter().map(|m| m.code.as_str()).collect::<Vec<_>>(), ["c"]);
+        assert_eq!(decliners.iter().map(|m| m.code.as_str()).collect::<Vec<_>>(), ["b"]);
     }
 }
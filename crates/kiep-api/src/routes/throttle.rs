@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::AppState;
+use super::case::Json;
+
+/// 클라이언트 IP별 토큰 버킷, clone한 핸들은 같은 버킷 표를 공유한다
+/// 분당 per_minute개씩 채워지고 최대 per_minute개까지 쌓인다
+#[derive(Clone, Debug)]
+pub struct IpThrottle {
+    per_minute: u32,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last: Instant,
+}
+
+impl IpThrottle {
+    pub fn new(per_minute: u32) -> Self {
+        assert!(per_minute > 0, "per_minute must be positive");
+        Self { per_minute, buckets: Arc::default() }
+    }
+
+    fn rate_per_sec(&self) -> f64 {
+        f64::from(self.per_minute) / 60.0
+    }
+
+    /// now 시점에 ip의 토큰 1개 사용, 부족하면 다음 토큰까지 남은 시간
+    fn take(&self, ip: IpAddr, now: Instant) -> Result<(), Duration> {
+        let burst = f64::from(self.per_minute);
+        let rate = self.rate_per_sec();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(ip).or_insert(Bucket { tokens: burst, last: now });
+
+        let elapsed = now.saturating_duration_since(bucket.last).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(burst);
+        bucket.last = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+
+    /// 다시 가득 찼을 버킷 제거 (새 버킷과 구분되지 않으므로 지워도 동작이 같다), 반환값: 제거 수
+    fn purge(&self, now: Instant) -> usize {
+        let refill = Duration::from_secs(60);
+        let mut buckets = self.buckets.lock().unwrap();
+        let before = buckets.len();
+        buckets.retain(|_, b| now.saturating_duration_since(b.last) < refill);
+        before - buckets.len()
+    }
+
+    /// every마다 오래된 버킷 정리 (tokio 런타임 안에서 호출)
+    pub fn spawn_cleanup(&self, every: Duration) -> tokio::task::JoinHandle<()> {
+        let throttle = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(every);
+            loop {
+                ticker.tick().await;
+                let removed = throttle.purge(Instant::now());
+                if removed > 0 {
+                    tracing::debug!("Purged {} idle rate limit buckets", removed);
+                }
+            }
+        })
+    }
+}
+
+/// 접속 주소, 단 접속 주소가 신뢰 프록시면 X-Forwarded-For를 뒤에서부터 읽어 처음 나오는 신뢰하지 않는 주소
+/// X-Forwarded-For 앞쪽은 클라이언트가 임의로 넣을 수 있으므로 프록시가 덧붙인 뒤쪽부터 본다
+fn client_ip(headers: &HeaderMap, peer: Option<SocketAddr>, trusted: &[IpAddr]) -> Option<IpAddr> {
+    let peer = peer?.ip();
+    if !trusted.contains(&peer) {
+        return Some(peer);
+    }
+    let forwarded = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|ip| ip.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>();
+    for ip in forwarded.into_iter().rev() {
+        match ip {
+            Some(ip) if trusted.contains(&ip) => continue,
+            Some(ip) => return Some(ip),
+            // 해석할 수 없는 주소 앞쪽은 믿을 수 없다
+            None => break,
+        }
+    }
+    Some(peer)
+}
+
+/// IP별 분당 요청 한도 초과 시 429 + Retry-After, 한도 미설정 또는 IP를 알 수 없으면 통과
+pub async fn limit_per_ip(State(state): State<Arc<AppState>>, req: Request, next: Next) -> Response {
+    let Some(throttle) = &state.throttle else {
+        return next.run(req).await;
+    };
+    let peer = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|c| c.0);
+    let Some(ip) = client_ip(req.headers(), peer, &state.config.trusted_proxies) else {
+        return next.run(req).await;
+    };
+
+    match throttle.take(ip, Instant::now()) {
+        Ok(()) => next.run(req).await,
+        Err(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(serde_json::json!({ "error": "rate limit exceeded", "code": "rate_limited" })),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_buckets_are_per_ip() {
+        let throttle = IpThrottle::new(2);
+        let start = Instant::now();
+        let a: IpAddr = [10, 0, 0, 1].into();
+        let b: IpAddr = [10, 0, 0, 2].into();
+
+        assert!(throttle.take(a, start).is_ok());
+        assert!(throttle.take(a, start).is_ok());
+        // 분당 2개 = 30초에 1개
+        assert_eq!(throttle.take(a, start), Err(Duration::from_secs(30)));
+        assert!(throttle.take(b, start).is_ok());
+        assert!(throttle.take(a, start + Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_purge_removes_idle_buckets() {
+        let throttle = IpThrottle::new(60);
+        let start = Instant::now();
+        throttle.take([10, 0, 0, 1].into(), start).unwrap();
+        throttle.take([10, 0, 0, 2].into(), start + Duration::from_secs(50)).unwrap();
+
+        assert_eq!(throttle.purge(start + Duration::from_secs(70)), 1);
+        assert_eq!(throttle.buckets.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_client_ip_trusts_forwarded_for_only_from_proxies() {
+        let proxy: IpAddr = [10, 0, 0, 1].into();
+        let peer: SocketAddr = (proxy, 5000).into();
+        let mut headers = HeaderMap::new();
+        assert_eq!(client_ip(&headers, Some(peer), &[proxy]), Some(proxy));
+        assert_eq!(client_ip(&headers, None, &[proxy]), None);
+
+        // 프록시가 아닌 접속은 헤더를 무시
+        headers.insert("x-forwarded-for", HeaderValue::from_static("203.0.113.7"));
+        let direct: SocketAddr = ([192, 168, 0, 9], 5000).into();
+        assert_eq!(client_ip(&headers, Some(direct), &[proxy]), Some(direct.ip()));
+        assert_eq!(client_ip(&headers, Some(peer), &[]), Some(proxy));
+        assert_eq!(client_ip(&headers, Some(peer), &[proxy]), Some([203, 0, 113, 7].into()));
+
+        // 클라이언트가 앞에 넣은 주소가 아니라 프록시가 덧붙인 마지막 주소
+        headers.insert("x-forwarded-for", HeaderValue::from_static("1.2.3.4, 203.0.113.7, 10.0.0.1"));
+        assert_eq!(client_ip(&headers, Some(peer), &[proxy]), Some([203, 0, 113, 7].into()));
+
+        headers.insert("x-forwarded-for", HeaderValue::from_static("garbage"));
+        assert_eq!(client_ip(&headers, Some(peer), &[proxy]), Some(proxy));
+    }
+}
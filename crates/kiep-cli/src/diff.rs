@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+/// health.json 항목 (ExportHealth의 camelCase, ExportSnapshot의 snake_case 모두 허용)
+#[derive(Deserialize)]
+struct ExportEntry {
+    #[serde(alias = "region_code")]
+    code: String,
+    name: Option<String>,
+    #[serde(rename = "healthScore", alias = "health_score")]
+    health_score: Option<f64>,
+    #[serde(rename = "companyCount", alias = "company_count")]
+    company_count: Option<i64>,
+    #[serde(rename = "employeeCount", alias = "employee_count")]
+    employee_count: Option<i64>,
+}
+
+/// 변화로 볼 최소 변동폭 (절댓값, 이상)
+#[derive(Clone, Copy, Debug)]
+pub struct DiffThresholds {
+    pub score: f64,
+    pub count: i64,
+}
+
+#[derive(Serialize)]
+pub struct RegionRef {
+    code: String,
+    name: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RegionChange {
+    code: String,
+    name: Option<String>,
+    old_score: Option<f64>,
+    new_score: Option<f64>,
+    /// 한쪽 점수가 없으면 null
+    score_delta: Option<f64>,
+    company_delta: i64,
+    employee_delta: i64,
+}
+
+#[derive(Serialize)]
+pub struct ExportDiff {
+    added: Vec<RegionRef>,
+    removed: Vec<RegionRef>,
+    /// 점수 변동 큰 순
+    changed: Vec<RegionChange>,
+    /// 양쪽에 있으나 임계값 미만으로 바뀐 지역 수
+    unchanged: usize,
+}
+
+fn load(path: &str) -> anyhow::Result<BTreeMap<String, ExportEntry>> {
+    let raw = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("failed to read {}: {}", path, e))?;
+    let entries: Vec<ExportEntry> = serde_json::from_str(&raw)
+        .map_err(|e| anyhow::anyhow!("{} is not a health export: {}", path, e))?;
+    Ok(entries.into_iter().map(|e| (e.code.clone(), e)).collect())
+}
+
+/// 지역 코드로 맞춰 추가/삭제 지역과 임계값 이상 바뀐 지역을 구한다
+fn diff_entries(
+    old: &BTreeMap<String, ExportEntry>,
+    new: &BTreeMap<String, ExportEntry>,
+    thresholds: DiffThresholds,
+) -> ExportDiff {
+    let to_ref = |e: &ExportEntry| RegionRef { code: e.code.clone(), name: e.name.clone() };
+    let added = new.values().filter(|e| !old.contains_key(&e.code)).map(to_ref).collect();
+    let removed = old.values().filter(|e| !new.contains_key(&e.code)).map(to_ref).collect();
+
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    for (code, before) in old {
+        let Some(after) = new.get(code) else { continue };
+        let score_delta = before.health_score.zip(after.health_score).map(|(b, a)| a - b);
+        let delta = |b: Option<i64>, a: Option<i64>| a.unwrap_or(0) - b.unwrap_or(0);
+        let company_delta = delta(before.company_count, after.company_count);
+        let employee_delta = delta(before.employee_count, after.employee_count);
+
+        // 점수가 한쪽에만 있는 것도 변화로 본다
+        let score_changed = match score_delta {
+            Some(d) => d.abs() >= thresholds.score,
+            None => before.health_score.is_some() != after.health_score.is_some(),
+        };
+        if score_changed
+            || company_delta.abs() >= thresholds.count
+            || employee_delta.abs() >= thresholds.count
+        {
+            changed.push(RegionChange {
+                code: code.clone(),
+                name: after.name.clone().or_else(|| before.name.clone()),
+                old_score: before.health_score,
+                new_score: after.health_score,
+                score_delta,
+                company_delta,
+                employee_delta,
+            });
+        } else {
+            unchanged += 1;
+        }
+    }
+    changed.sort_by(|a, b| {
+        let abs = |c: &RegionChange| c.score_delta.map_or(f64::INFINITY, f64::abs);
+        abs(b).total_cmp(&abs(a)).then_with(|| a.code.cmp(&b.code))
+    });
+
+    ExportDiff { added, removed, changed, unchanged }
+}
+
+/// 두 health export 파일 비교 후 출력 (DB 불필요)
+pub fn diff_exports(old: &str, new: &str, thresholds: DiffThresholds, json: bool) -> anyhow::Result<()> {
+    let diff = diff_entries(&load(old)?, &load(new)?, thresholds);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+        return Ok(());
+    }
+
+    let label = |code: &str, name: &Option<String>| match name {
+        Some(name) => format!("{} {}", code, name),
+        None => code.to_string(),
+    };
+    let score = |s: Option<f64>| s.map_or("-".to_string(), |s| format!("{:.1}", s));
+
+    println!("=== {} → {} ===", old, new);
+    println!(
+        "추가 {}  삭제 {}  변경 {}  유지 {}",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        diff.unchanged
+    );
+    for r in &diff.added {
+        println!("+ {}", label(&r.code, &r.name));
+    }
+    for r in &diff.removed {
+        println!("- {}", label(&r.code, &r.name));
+    }
+    for c in &diff.changed {
+        println!(
+            "~ {:<24} 점수 {:>6} → {:<6} 기업 {:+}  고용 {:+}",
+            label(&c.code, &c.name),
+            score(c.old_score),
+            score(c.new_score),
+            c.company_delta,
+            c.employee_delta
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(code: &str, score: Option<f64>, companies: i64) -> (String, ExportEntry) {
+        let entry = ExportEntry {
+            code: code.into(),
+            name: Some(format!("지역{}", code)),
+            health_score: score,
+            company_count: Some(companies),
+            employee_count: Some(companies),
+        };
+        (code.into(), entry)
+    }
+
+    const THRESHOLDS: DiffThresholds = DiffThresholds { score: 1.0, count: 10 };
+
+    #[test]
+    fn test_added_and_removed_regions() {
+        let old = BTreeMap::from([entry("43111", Some(50.0), 100), entry("43112", Some(60.0), 100)]);
+        let new = BTreeMap::from([entry("43112", Some(60.0), 100), entry("43113", Some(70.0), 100)]);
+
+        let diff = diff_entries(&old, &new, THRESHOLDS);
+        let codes = |refs: &[RegionRef]| refs.iter().map(|r| r.code.clone()).collect::<Vec<_>>();
+        assert_eq!(codes(&diff.added), ["43113"]);
+        assert_eq!(codes(&diff.removed), ["43111"]);
+        assert!(diff.changed.is_empty());
+        assert_eq!(diff.unchanged, 1);
+    }
+
+    #[test]
+    fn test_changed_regions_sorted_by_score_delta() {
+        let old = BTreeMap::from([
+            entry("43111", Some(50.0), 100),
+            entry("43112", Some(60.0), 100),
+            entry("43113", None, 100),
+        ]);
+        let new = BTreeMap::from([
+            entry("43111", Some(52.0), 100),
+            entry("43112", Some(55.0), 100),
+            entry("43113", Some(40.0), 100),
+        ]);
+
+        let diff = diff_entries(&old, &new, THRESHOLDS);
+        // 한쪽 점수가 없으면 변동폭 무한대로 보고 맨 앞
+        let order: Vec<_> = diff.changed.iter().map(|c| c.code.as_str()).collect();
+        assert_eq!(order, ["43113", "43112", "43111"]);
+        assert_eq!(diff.changed[0].score_delta, None);
+        assert_eq!(diff.changed[1].score_delta, Some(-5.0));
+        assert_eq!(diff.unchanged, 0);
+    }
+
+    #[test]
+    fn test_changes_below_threshold_count_as_unchanged() {
+        let old = BTreeMap::from([entry("43111", Some(50.0), 100), entry("43112", Some(50.0), 100)]);
+        // 점수 0.5 변동은 무시, 기업 수 10 변동은 임계값 이상이라 변경
+        let new = BTreeMap::from([entry("43111", Some(50.5), 105), entry("43112", Some(50.0), 110)]);
+
+        let diff = diff_entries(&old, &new, THRESHOLDS);
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].code, "43112");
+        assert_eq!(diff.changed[0].company_delta, 10);
+        assert_eq!(diff.changed[0].employee_delta, 10);
+    }
+}
@@ -0,0 +1,265 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::{Arc, LazyLock};
+
+use arrow_array::{ArrayRef, Int16Array, Int32Array, Int64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use clap::ValueEnum;
+use futures::TryStreamExt;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Parquet,
+}
+
+/// Parquet 행 그룹 크기, 이만큼 모일 때마다 파일에 기록해 메모리 사용량을 묶어 둔다
+const PARQUET_ROW_GROUP_SIZE: usize = 8192;
+
+/// 지역 기업 단위 비정규화 레코드 (최신 고용/재무 + 조달 합계)
+#[derive(Serialize, FromRow)]
+struct RegionCompanyRecord {
+    biz_no: String,
+    name: String,
+    corp_no: Option<String>,
+    biz_status: Option<String>,
+    industry_code: Option<String>,
+    bjd_code: Option<String>,
+    address: Option<String>,
+    stock_code: Option<String>,
+    market_type: Option<String>,
+    complex_id: Option<String>,
+    data_source: Option<String>,
+    latest_employment_month: Option<String>,
+    latest_employee_count: Option<i32>,
+    latest_fiscal_year: Option<i32>,
+    latest_fiscal_quarter: Option<i16>,
+    revenue: Option<i64>,
+    operating_income: Option<i64>,
+    net_income: Option<i64>,
+    total_assets: Option<i64>,
+    procurement_count: i64,
+    procurement_total: i64,
+}
+
+/// 지역(법정동코드 접두사) 기업 전체를 파일로 내보내기
+/// DB 커서를 스트리밍하며 기록하므로 메모리 사용량은 지역 크기와 무관 (Parquet은 행 그룹 단위로 모아서 기록)
+pub async fn export_region(
+    pool: &PgPool,
+    code: &str,
+    output: &str,
+    format: ExportFormat,
+) -> anyhow::Result<u64> {
+    let mut rows = sqlx::query_as::<_, RegionCompanyRecord>(
+        r#"
+        SELECT
+            c.biz_no, c.name, c.corp_no, c.biz_status, c.industry_code, c.bjd_code,
+            c.address, c.stock_code, c.market_type, c.complex_id, c.data_source,
+            emp.year_month as latest_employment_month,
+            emp.employee_count as latest_employee_count,
+            fin.fiscal_year as latest_fiscal_year,
+            fin.quarter as latest_fiscal_quarter,
+            fin.revenue, fin.operating_income, fin.net_income, fin.total_assets,
+            proc.contract_count as procurement_count,
+            proc.total_amount as procurement_total
+        FROM companies c
+        LEFT JOIN LATERAL (
+            SELECT es.year_month, es.employee_count
+            FROM employment_series es
+            WHERE es.biz_no = c.biz_no
+            ORDER BY es.year_month DESC
+            LIMIT 1
+        ) emp ON true
+        LEFT JOIN LATERAL (
+            SELECT f.fiscal_year, f.quarter, f.revenue, f.operating_income, f.net_income, f.total_assets
+            FROM financials f
+            WHERE f.biz_no = c.biz_no
+            ORDER BY f.fiscal_year DESC, f.quarter DESC, f.statement_type = 'consolidated' DESC
+            LIMIT 1
+        ) fin ON true
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) as contract_count, COALESCE(SUM(p.amount), 0)::bigint as total_amount
+            FROM procurement p
+            WHERE p.biz_no = c.biz_no
+        ) proc
+        WHERE c.bjd_code LIKE $1 || '%'
+        ORDER BY c.biz_no
+        "#,
+    )
+    .bind(code)
+    .fetch(pool);
+
+    let mut writer = BufWriter::new(File::create(output)?);
+    let mut count = 0u64;
+
+    match format {
+        ExportFormat::Json => {
+            writer.write_all(b"[")?;
+            while let Some(row) = rows.try_next().await? {
+                if count > 0 {
+                    writer.write_all(b",")?;
+                }
+                writer.write_all(b"\n  ")?;
+                serde_json::to_writer(&mut writer, &row)?;
+                count += 1;
+            }
+            writer.write_all(b"\n]\n")?;
+        }
+        ExportFormat::Parquet => {
+            let props = WriterProperties::builder()
+                .set_compression(Compression::SNAPPY)
+                .set_max_row_group_row_count(Some(PARQUET_ROW_GROUP_SIZE))
+                .build();
+            let mut parquet = ArrowWriter::try_new(writer, PARQUET_SCHEMA.clone(), Some(props))?;
+            let mut chunk = Vec::with_capacity(PARQUET_ROW_GROUP_SIZE);
+            while let Some(row) = rows.try_next().await? {
+                chunk.push(row);
+                count += 1;
+                if chunk.len() == PARQUET_ROW_GROUP_SIZE {
+                    parquet.write(&to_record_batch(&chunk)?)?;
+                    chunk.clear();
+                }
+            }
+            if !chunk.is_empty() {
+                parquet.write(&to_record_batch(&chunk)?)?;
+            }
+            writer = parquet.into_inner()?;
+        }
+        ExportFormat::Csv => {
+            let mut csv = csv::Writer::from_writer(writer);
+            while let Some(row) = rows.try_next().await? {
+                csv.serialize(&row)?;
+                count += 1;
+            }
+            writer = csv.into_inner().map_err(|e| anyhow::anyhow!("CSV flush error: {}", e))?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(count)
+}
+
+/// RegionCompanyRecord와 같은 순서/이름의 Parquet 스키마
+static PARQUET_SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
+    let text = |name, nullable| Field::new(name, DataType::Utf8, nullable);
+    Arc::new(Schema::new(vec![
+        text("biz_no", false),
+        text("name", false),
+        text("corp_no", true),
+        text("biz_status", true),
+        text("industry_code", true),
+        text("bjd_code", true),
+        text("address", true),
+        text("stock_code", true),
+        text("market_type", true),
+        text("complex_id", true),
+        text("data_source", true),
+        text("latest_employment_month", true),
+        Field::new("latest_employee_count", DataType::Int32, true),
+        Field::new("latest_fiscal_year", DataType::Int32, true),
+        Field::new("latest_fiscal_quarter", DataType::Int16, true),
+        Field::new("revenue", DataType::Int64, true),
+        Field::new("operating_income", DataType::Int64, true),
+        Field::new("net_income", DataType::Int64, true),
+        Field::new("total_assets", DataType::Int64, true),
+        Field::new("procurement_count", DataType::Int64, false),
+        Field::new("procurement_total", DataType::Int64, false),
+    ]))
+});
+
+/// 행 묶음을 열 단위 배치로 변환
+fn to_record_batch(rows: &[RegionCompanyRecord]) -> anyhow::Result<RecordBatch> {
+    let text = |f: fn(&RegionCompanyRecord) -> Option<&str>| -> ArrayRef {
+        Arc::new(rows.iter().map(f).collect::<StringArray>())
+    };
+    let int64 = |f: fn(&RegionCompanyRecord) -> Option<i64>| -> ArrayRef {
+        Arc::new(rows.iter().map(f).collect::<Int64Array>())
+    };
+    let columns = vec![
+        text(|r| Some(&r.biz_no)),
+        text(|r| Some(&r.name)),
+        text(|r| r.corp_no.as_deref()),
+        text(|r| r.biz_status.as_deref()),
+        text(|r| r.industry_code.as_deref()),
+        text(|r| r.bjd_code.as_deref()),
+        text(|r| r.address.as_deref()),
+        text(|r| r.stock_code.as_deref()),
+        text(|r| r.market_type.as_deref()),
+        text(|r| r.complex_id.as_deref()),
+        text(|r| r.data_source.as_deref()),
+        text(|r| r.latest_employment_month.as_deref()),
+        Arc::new(rows.iter().map(|r| r.latest_employee_count).collect::<Int32Array>()),
+        Arc::new(rows.iter().map(|r| r.latest_fiscal_year).collect::<Int32Array>()),
+        Arc::new(rows.iter().map(|r| r.latest_fiscal_quarter).collect::<Int16Array>()),
+        int64(|r| r.revenue),
+        int64(|r| r.operating_income),
+        int64(|r| r.net_income),
+        int64(|r| r.total_assets),
+        int64(|r| Some(r.procurement_count)),
+        int64(|r| Some(r.procurement_total)),
+    ];
+    Ok(RecordBatch::try_new(PARQUET_SCHEMA.clone(), columns)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use super::*;
+
+    fn record(biz_no: &str, revenue: Option<i64>) -> RegionCompanyRecord {
+        RegionCompanyRecord {
+            biz_no: biz_no.into(),
+            name: "청주정밀".into(),
+            corp_no: None,
+            biz_status: Some("active".into()),
+            industry_code: Some("C26110".into()),
+            bjd_code: Some("43111".into()),
+            address: None,
+            stock_code: None,
+            market_type: None,
+            complex_id: None,
+            data_source: Some("NPS".into()),
+            latest_employment_month: Some("2024-06".into()),
+            latest_employee_count: Some(42),
+            latest_fiscal_year: None,
+            latest_fiscal_quarter: None,
+            revenue,
+            operating_income: None,
+            net_income: None,
+            total_assets: None,
+            procurement_count: 2,
+            procurement_total: 3_000_000,
+        }
+    }
+
+    #[test]
+    fn test_parquet_round_trip() {
+        let path = std::env::temp_dir().join(format!("kiep-export-test-{}.parquet", std::process::id()));
+        let rows = [record("0000123456", Some(1_200)), record("0000654321", None)];
+        let mut writer = ArrowWriter::try_new(File::create(&path).unwrap(), PARQUET_SCHEMA.clone(), None).unwrap();
+        writer.write(&to_record_batch(&rows).unwrap()).unwrap();
+        writer.close().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap().build().unwrap();
+        let batches: Vec<RecordBatch> = reader.collect::<Result<_, _>>().unwrap();
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), *PARQUET_SCHEMA);
+
+        let biz_no = batch.column_by_name("biz_no").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(biz_no.value(1), "0000654321");
+        let revenue = batch.column_by_name("revenue").unwrap().as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(revenue.value(0), 1_200);
+        assert!(revenue.is_null(1));
+
+        let _ = std::fs::remove_file(path);
+    }
+}
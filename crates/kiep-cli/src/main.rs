@@ -1,14 +1,39 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use futures::{Stream, TryStreamExt};
 use sqlx::postgres::PgPoolOptions;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use kiep_core::models::BizStatus;
+use kiep_core::period::YearMonth;
 use kiep_core::Config;
+use kiep_etl::clients::nps::NpsWorkplace;
+use kiep_etl::clients::pps::{self, PpsCategory, PpsFilter};
+use kiep_etl::clients::common::is_quota_exceeded;
+use kiep_etl::clients::{ClientFactory, FileCache, KeyRing, RateLimiter};
+use kiep_etl::load::loader::{load_nps_workplaces, MemoryLoader, NdjsonLoader};
+use kiep_etl::load::jobs::{self, JobCounts};
+use kiep_etl::load::{batch, health, lock, postgres, source_status};
+use kiep_etl::transform::normalize;
+
+mod diff;
+mod export;
+mod snapshot;
+mod validate;
 
 #[derive(Parser)]
 #[command(name = "kiep", about = "KIEP CLI - Korea Industrial Ecosystem Platform")]
 struct Cli {
     #[command(subcommand)]
-    command: Commands,
+    command: Command,
+}
+
+/// DB가 필요한 명령과 파일만 다루는 명령을 나눠 `run`에는 DB 명령만 넘긴다
+#[derive(Subcommand)]
+enum Command {
+    #[command(flatten)]
+    Db(Commands),
+    #[command(flatten)]
+    Offline(OfflineCommand),
 }
 
 #[derive(Subcommand)]
@@ -25,6 +50,37 @@ enum Commands {
         /// 시군구코드 (선택)
         #[arg(short = 'g', long)]
         sigungu: Option<String>,
+
+        /// 같은 시도를 수집 중인 (시군구만 다른 실행 포함) 다른 실행이 있으면 종료 대신 대기
+        #[arg(long)]
+        wait_lock: bool,
+
+        /// 적재 대상 (memory/ndjson은 DB에 쓰지 않음)
+        #[arg(long, value_enum, default_value_t = Sink::Postgres)]
+        sink: Sink,
+    },
+
+    /// Backfill past months of NPS employment data for a region
+    BackfillNps {
+        /// 시도코드 (예: 43=충북)
+        #[arg(short, long)]
+        sido: String,
+
+        /// 시군구코드 (선택)
+        #[arg(short = 'g', long)]
+        sigungu: Option<String>,
+
+        /// 시작 월 (YYYY-MM)
+        #[arg(long)]
+        from: String,
+
+        /// 종료 월 (YYYY-MM, 포함)
+        #[arg(long)]
+        to: String,
+
+        /// 적재 대상 (memory/ndjson은 DB에 쓰지 않음)
+        #[arg(long, value_enum, default_value_t = Sink::Postgres)]
+        sink: Sink,
     },
 
     /// Check NTS business status
@@ -34,64 +90,573 @@ enum Commands {
         biz_no: String,
     },
 
+    /// Fetch KICOX industrial complexes (all, or one province)
+    FetchKicox {
+        /// 시도명 (예: 충북, 생략 시 전국)
+        #[arg(short, long)]
+        province: Option<String>,
+
+        /// 단지별 입주기업도 수집해 기업 산단 소속 갱신 (바뀌면 company_history에 기록)
+        #[arg(long)]
+        tenants: bool,
+    },
+
+    /// Fetch PPS procurement contracts for a date range
+    FetchPps {
+        /// 조회 시작일 (YYYYMMDD)
+        #[arg(long)]
+        from: String,
+
+        /// 조회 종료일 (YYYYMMDD, 포함)
+        #[arg(long)]
+        to: String,
+
+        /// 이 계약업체 사업자번호의 계약만 적재 (하이픈 허용)
+        #[arg(short, long)]
+        biz_no: Option<String>,
+
+        /// 업무 구분 (goods/construction/service/foreign)
+        #[arg(long, default_value_t = PpsCategory::Construction)]
+        category: PpsCategory,
+
+        /// 공고기관코드 (7자리, --demand-agency와 함께 쓸 수 없음)
+        #[arg(long)]
+        notice_agency: Option<String>,
+
+        /// 수요기관코드 (7자리)
+        #[arg(long)]
+        demand_agency: Option<String>,
+    },
+
+    /// Fetch FSC financial statements for listed companies (corp_no가 있는 기업)
+    FetchFinancials {
+        /// 회계연도
+        #[arg(short, long)]
+        year: i32,
+
+        /// 시장 구분 (KOSPI/KOSDAQ/KONEX, 생략 시 전체)
+        #[arg(short, long)]
+        market: Option<String>,
+
+        /// 최대 수집 기업 수 (시험 실행용)
+        #[arg(short, long)]
+        limit: Option<i64>,
+    },
+
+    /// Re-check business status against NTS for stale companies
+    RefreshStatuses {
+        /// 최대 확인 기업 수
+        #[arg(short, long, default_value_t = 1000)]
+        limit: i64,
+
+        /// 마지막 확인 후 경과 일수 (이보다 오래된 기업만)
+        #[arg(long, default_value_t = 30)]
+        older_than_days: i32,
+    },
+
+    /// Geocode company addresses that have no coordinates yet (VWorld)
+    GeocodeCompanies {
+        /// 이번 실행에서 검색할 최대 기업 수
+        #[arg(short, long, default_value_t = 1000)]
+        batch_size: i64,
+    },
+
+    /// List companies that have no employment records
+    MissingEmployment {
+        /// 최대 출력 건수
+        #[arg(short, long, default_value_t = 50)]
+        limit: i64,
+    },
+
+    /// Compute region health for one month and store it in region_health
+    ComputeHealth {
+        /// 기준 월 (YYYY-MM)
+        year_month: String,
+    },
+
+    /// Recompute region health for every month in a range
+    RecomputeHealthRange {
+        /// 시작 월 (YYYY-MM)
+        #[arg(long)]
+        from: String,
+
+        /// 종료 월 (YYYY-MM, 포함)
+        #[arg(long)]
+        to: String,
+    },
+
+    /// Revert rows introduced by a load batch
+    RollbackBatch {
+        /// 배치 ID (batches.id)
+        #[arg(long)]
+        id: uuid::Uuid,
+    },
+
     /// Export region health data as JSON (for frontend)
     ExportHealth {
         /// Output file path
         #[arg(short, long, default_value = "web/public/data/health.json")]
         output: String,
+
+        /// 시도별로 고르게 N개 지역만 내보냄 (로컬 개발용, 같은 N이면 항상 같은 지역)
+        #[arg(long, value_parser = clap::value_parser!(i64).range(1..))]
+        sample: Option<i64>,
+    },
+
+    /// Export every company in a region (latest employment/financials, procurement totals)
+    ExportRegion {
+        /// 법정동코드 접두사 (시도 2자리 또는 시군구 5자리)
+        #[arg(short, long)]
+        code: String,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: String,
+
+        #[arg(short, long, value_enum, default_value_t = export::ExportFormat::Json)]
+        format: export::ExportFormat,
+    },
+
+    /// Export a reproducible data release snapshot for one period (with manifest)
+    ExportSnapshot {
+        /// 기준 월 (YYYY-MM)
+        #[arg(short, long)]
+        period: String,
+
+        /// 출력 디렉터리
+        #[arg(short, long)]
+        output: String,
+    },
+
+    /// Run data integrity checks
+    Validate {
+        /// 검사 항목별 최대 샘플 건수
+        #[arg(short, long, default_value_t = 20)]
+        limit: i64,
+
+        /// 해당 문제가 하나라도 있으면 실패 종료 (여러 번 지정 가능)
+        #[arg(long, value_enum)]
+        fail_on: Vec<validate::IssueKind>,
+
+        /// JSON으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List recent fetch/recompute runs
+    Jobs {
+        /// 최대 출력 건수
+        #[arg(short, long, default_value_t = 20)]
+        limit: i64,
     },
 
+    /// Show each data source's last successful fetch and outcome
+    Sources,
+
     /// Show database stats
     Stats,
 }
 
+impl Commands {
+    /// jobs 테이블에 기록할 명령 이름 (수집/재계산 명령만)
+    fn job_name(&self) -> Option<&'static str> {
+        match self {
+            Self::FetchNps { .. } => Some("fetch-nps"),
+            Self::BackfillNps { .. } => Some("backfill-nps"),
+            Self::FetchKicox { .. } => Some("fetch-kicox"),
+            Self::FetchPps { .. } => Some("fetch-pps"),
+            Self::FetchFinancials { .. } => Some("fetch-financials"),
+            Self::RefreshStatuses { .. } => Some("refresh-statuses"),
+            Self::GeocodeCompanies { .. } => Some("geocode-companies"),
+            Self::ComputeHealth { .. } => Some("compute-health"),
+            Self::RecomputeHealthRange { .. } => Some("recompute-health-range"),
+            _ => None,
+        }
+    }
+
+    /// source_status에 기록할 데이터 소스 (DB에 적재하는 수집 명령만)
+    fn source(&self) -> Option<&'static str> {
+        match self {
+            Self::FetchNps { sink: Sink::Postgres, .. }
+            | Self::BackfillNps { sink: Sink::Postgres, .. } => Some("NPS"),
+            Self::FetchKicox { .. } => Some("KICOX"),
+            Self::FetchPps { .. } => Some("PPS"),
+            Self::FetchFinancials { .. } => Some("FSC"),
+            Self::RefreshStatuses { .. } => Some("NTS"),
+            _ => None,
+        }
+    }
+}
+
+/// DB 설정 없이 실행하는 명령
+#[derive(Subcommand)]
+enum OfflineCommand {
+    /// Compare two health export files (no database needed)
+    DiffExport {
+        /// 이전 health.json
+        old: String,
+
+        /// 새 health.json
+        new: String,
+
+        /// 변경으로 볼 최소 점수 변동
+        #[arg(long, default_value_t = 1.0)]
+        min_score_change: f64,
+
+        /// 변경으로 볼 최소 기업 수/고용인원 변동
+        #[arg(long, default_value_t = 10)]
+        min_count_change: i64,
+
+        /// JSON으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Write the API's OpenAPI document to a file (same bytes the server serves)
+    ExportOpenapi {
+        /// Output file path
+        #[arg(short, long, default_value = "web/openapi.json")]
+        output: String,
+    },
+}
+
+impl OfflineCommand {
+    fn run(self) -> anyhow::Result<()> {
+        match self {
+            Self::DiffExport { old, new, min_score_change, min_count_change, json } => {
+                let thresholds = diff::DiffThresholds { score: min_score_change, count: min_count_change };
+                diff::diff_exports(&old, &new, thresholds, json)
+            }
+            Self::ExportOpenapi { output } => {
+                std::fs::write(&output, kiep_api::routes::openapi::spec_json())?;
+                tracing::info!("Exported OpenAPI document to {}", output);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 수집 결과 적재 대상
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Sink {
+    /// companies/employment_series에 upsert (배치 기록)
+    Postgres,
+    /// 메모리에 모아 건수만 출력
+    Memory,
+    /// 표준출력에 NDJSON
+    Ndjson,
+}
+
+/// NPS 사업장을 선택한 sink에 적재, Postgres는 한 트랜잭션으로 적재해 실패 시 롤백하고 배치를 failed로 남긴다
+async fn load_workplaces(
+    pool: &sqlx::PgPool,
+    sink: Sink,
+    params: serde_json::Value,
+    workplaces: &[NpsWorkplace],
+) -> anyhow::Result<u32> {
+    match sink {
+        Sink::Postgres => {
+            let load_batch = batch::start_batch(pool, "NPS", params).await?;
+            let count = match postgres::upsert_nps_workplaces(pool, workplaces, load_batch.id).await {
+                Ok(count) => count,
+                Err(e) => {
+                    batch::fail_batch(pool, load_batch.id).await?;
+                    return Err(e.context(format!("NPS batch {} rolled back", load_batch.id)));
+                }
+            };
+            batch::complete_batch(pool, load_batch.id, workplaces.len(), count).await?;
+            tracing::info!("Upserted {} records to database (batch {})", count, load_batch.id);
+            Ok(count)
+        }
+        Sink::Memory => {
+            let memory = MemoryLoader::default();
+            let count = load_nps_workplaces(&memory, workplaces).await?;
+            println!(
+                "기업 {}건, 고용 시계열 {}건 (DB 미기록)",
+                memory.companies.lock().unwrap().len(),
+                memory.employment.lock().unwrap().len()
+            );
+            Ok(count)
+        }
+        Sink::Ndjson => load_nps_workplaces(&NdjsonLoader::new(std::io::stdout()), workplaces).await,
+    }
+}
+
+/// 페이지가 도착하는 대로 적재, Postgres는 실행 전체를 한 배치·한 트랜잭션으로 묶어 모든 페이지가 성공해야 반영
+/// counts는 Memory/Ndjson에서는 페이지마다, Postgres에서는 commit 후에 갱신
+async fn load_workplace_pages(
+    pool: &sqlx::PgPool,
+    sink: Sink,
+    params: serde_json::Value,
+    pages: impl Stream<Item = anyhow::Result<Vec<NpsWorkplace>>>,
+    counts: &mut JobCounts,
+) -> anyhow::Result<u32> {
+    let mut pages = std::pin::pin!(pages);
+    let mut fetched = 0u32;
+    let mut written = 0u32;
+
+    match sink {
+        Sink::Postgres => {
+            // 전체 페이지를 한 트랜잭션으로 적재: 중간에 실패하면 이 실행분은 모두 롤백
+            let load_batch = batch::start_batch(pool, "NPS", params).await?;
+            match postgres::load_region_atomically(pool, load_batch.id, pages).await {
+                Ok((f, w)) => {
+                    (fetched, written) = (f, w);
+                    (counts.fetched, counts.written) = (Some(fetched), Some(written));
+                }
+                Err(e) => {
+                    batch::fail_batch(pool, load_batch.id).await?;
+                    return Err(e.context(format!("NPS batch {} rolled back", load_batch.id)));
+                }
+            }
+            batch::complete_batch(pool, load_batch.id, fetched as usize, written).await?;
+            tracing::info!("Upserted {} records to database (batch {})", written, load_batch.id);
+        }
+        Sink::Memory => {
+            let memory = MemoryLoader::default();
+            while let Some(page) = pages.try_next().await? {
+                fetched += page.len() as u32;
+                written += load_nps_workplaces(&memory, &page).await?;
+                (counts.fetched, counts.written) = (Some(fetched), Some(written));
+            }
+            println!(
+                "기업 {}건, 고용 시계열 {}건 (DB 미기록)",
+                memory.companies.lock().unwrap().len(),
+                memory.employment.lock().unwrap().len()
+            );
+        }
+        Sink::Ndjson => {
+            let ndjson = NdjsonLoader::new(std::io::stdout());
+            while let Some(page) = pages.try_next().await? {
+                fetched += page.len() as u32;
+                written += load_nps_workplaces(&ndjson, &page).await?;
+                (counts.fetched, counts.written) = (Some(fetched), Some(written));
+            }
+        }
+    }
+
+    tracing::info!("Fetched {} workplaces", fetched);
+    Ok(written)
+}
+
+/// sql/ 디렉터리의 스키마 파일 (번호 순 적용)
+const MIGRATIONS: &[&str] = &[
+    include_str!("../../../sql/001_init.sql"),
+    include_str!("../../../sql/002_company_history.sql"),
+    include_str!("../../../sql/003_status_checked.sql"),
+    include_str!("../../../sql/004_data_version.sql"),
+    include_str!("../../../sql/005_batches.sql"),
+    include_str!("../../../sql/006_statement_type.sql"),
+    include_str!("../../../sql/007_score_version.sql"),
+    include_str!("../../../sql/008_year_quarter_format.sql"),
+    include_str!("../../../sql/009_jobs.sql"),
+    include_str!("../../../sql/010_health_insufficient_data.sql"),
+    include_str!("../../../sql/011_source_status.sql"),
+    include_str!("../../../sql/012_procurement_upsert.sql"),
+    include_str!("../../../sql/013_geocode_checked.sql"),
+    include_str!("../../../sql/014_health_score_weights.sql"),
+    include_str!("../../../sql/015_jobs_lock_contended.sql"),
+];
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::registry()
         .with(tracing_subscriber::EnvFilter::try_from_default_env()
             .unwrap_or_else(|_| "kiep=info".into()))
-        .with(tracing_subscriber::fmt::layer())
+        // 로그는 stderr로 (--sink ndjson 출력과 섞이지 않도록)
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
         .init();
 
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches)?;
+
+    // 파일만 다루는 명령은 DB 설정 없이 실행
+    let command = match cli.command {
+        Command::Offline(command) => return command.run(),
+        Command::Db(command) => command,
+    };
+
     let config = Config::from_env()?;
 
+    // pg_stat_activity에서 어떤 명령의 연결인지 구분
+    let app_name = format!("kiep-cli:{}", matches.subcommand_name().unwrap_or_default());
     let pool = PgPoolOptions::new()
         .max_connections(5)
-        .connect(&config.database_url)
+        .connect_with(config.pg_connect_options(&app_name)?)
         .await?;
 
-    match cli.command {
+    // 소스별 클라이언트가 HTTP 커넥션 풀과 호출 제한(API_RATE_LIMIT_PER_SEC)을 공유
+    let clients = ClientFactory::default()
+        .with_rate_limiter(RateLimiter::from_config(&config))
+        .with_file_cache(FileCache::from_config(&config));
+
+    // .env나 <변수>_FILE의 키를 바꾼 뒤 SIGHUP을 보내면 재시작 없이 교체 (다음 요청부터 적용)
+    let keys = KeyRing::from_config(&config);
+    #[cfg(unix)]
+    if let Err(e) = keys.reload_on_sighup() {
+        tracing::warn!("Failed to install SIGHUP handler for API key reload: {}", e);
+    }
+
+    // 수집/재계산 명령은 jobs 테이블에 실행 기록 (기록 실패는 명령을 막지 않음)
+    let job = match command.job_name() {
+        Some(name) => {
+            let args: Vec<String> = std::env::args().skip(1).collect();
+            jobs::start_job(&pool, name, &args)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to record job start: {:#}", e))
+                .ok()
+        }
+        None => None,
+    };
+
+    let source = command.source();
+    let mut counts = JobCounts::default();
+    let result = run(command, pool.clone(), config, clients, keys, &mut counts).await;
+
+    if let Some(job) = job {
+        let recorded = match &result {
+            Ok(()) => jobs::complete_job(&pool, job.id, counts).await,
+            Err(e) => jobs::fail_job(&pool, job.id, counts, &format!("{:#}", e)).await,
+        };
+        if let Err(e) = recorded {
+            tracing::warn!("Failed to record job {}: {:#}", job.id, e);
+        }
+    }
+    // 락 때문에 건너뛴 실행은 수집 성공으로 치지 않는다
+    if let Some(source) = source.filter(|_| !counts.skipped) {
+        let recorded = match &result {
+            Ok(()) => source_status::record_success(&pool, source, counts).await,
+            Err(e) => source_status::record_failure(&pool, source, &format!("{:#}", e)).await,
+        };
+        if let Err(e) = recorded {
+            tracing::warn!("Failed to record {} source status: {:#}", source, e);
+        }
+    }
+    result
+}
+
+/// NPS run lock: 시도 단위로 잡아 시도 전체 실행과 같은 시도의 시군구 실행이 겹치지 않게 한다
+/// 경합 여부를 counts에 남기고, 대기 없이 건너뛰면 skipped로 표시한 뒤 None
+async fn acquire_nps_lock(
+    pool: &sqlx::PgPool,
+    sido: &str,
+    wait: bool,
+    counts: &mut JobCounts,
+) -> anyhow::Result<Option<lock::RunLock>> {
+    let run_lock = lock::acquire_run_lock(pool, "NPS", sido, wait).await?;
+    counts.lock_contended = Some(run_lock.as_ref().is_none_or(|l| l.contended));
+    if run_lock.is_none() {
+        counts.skipped = true;
+        println!("NPS {} 수집이 이미 진행 중입니다. 종료합니다.", sido);
+    }
+    Ok(run_lock)
+}
+
+fn lock_report(contended: bool) -> &'static str {
+    if contended { "run lock: 경합 (다른 실행 종료 후 진행)" } else { "run lock: 경합 없음" }
+}
+
+/// 명령 실행, counts에는 jobs 기록용 건수를 진행하면서 갱신한다
+async fn run(
+    command: Commands,
+    pool: sqlx::PgPool,
+    config: Config,
+    clients: ClientFactory,
+    keys: KeyRing,
+    counts: &mut JobCounts,
+) -> anyhow::Result<()> {
+    match command {
         Commands::InitDb => {
             tracing::info!("Initializing database...");
-            let schema = include_str!("../../../sql/001_init.sql");
-            sqlx::raw_sql(schema).execute(&pool).await?;
+            for schema in MIGRATIONS {
+                sqlx::raw_sql(schema).execute(&pool).await?;
+            }
             tracing::info!("Database initialized successfully");
         }
 
-        Commands::FetchNps { sido, sigungu } => {
-            let api_key = config
-                .nps_api_key
+        Commands::FetchNps { sido, sigungu, wait_lock, sink } => {
+            let api_key = keys
+                .nps
+                .clone()
                 .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_NPS_KEY not set"))?;
 
-            let nps = kiep_etl::clients::nps::NpsClient::new(&api_key);
-            let workplaces = nps
-                .fetch_by_region(&sido, sigungu.as_deref())
-                .await?;
+            let Some(run_lock) = acquire_nps_lock(&pool, &sido, wait_lock, counts).await? else {
+                return Ok(());
+            };
+
+            let nps = clients.nps(api_key);
+            let pages = nps.stream_by_region(&sido, sigungu.as_deref());
 
-            tracing::info!("Fetched {} workplaces", workplaces.len());
+            let params = serde_json::json!({ "sido": sido, "sigungu": sigungu });
+            let count = load_workplace_pages(&pool, sink, params, pages, counts).await?;
+            tracing::info!("Loaded {} records into {:?}", count, sink);
+            // ndjson은 stdout이 적재 데이터라 요약은 stderr로
+            match sink {
+                Sink::Ndjson => eprintln!("{}", lock_report(run_lock.contended)),
+                _ => println!("{}", lock_report(run_lock.contended)),
+            }
+            run_lock.release().await?;
+        }
+
+        Commands::BackfillNps { sido, sigungu, from, to, sink } => {
+            let api_key = keys
+                .nps
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_NPS_KEY not set"))?;
+            let from = YearMonth::parse(&from)?;
+            let to = YearMonth::parse(&to)?;
+            let periods = YearMonth::range_inclusive(from, to);
+            anyhow::ensure!(!periods.is_empty(), "--from must not be after --to");
+
+            let scope = format!("{}:{}", sido, sigungu.as_deref().unwrap_or("*"));
+            let Some(run_lock) = acquire_nps_lock(&pool, &sido, false, counts).await? else {
+                return Ok(());
+            };
+
+            let nps = clients.nps(api_key);
+            let mut empty_months = Vec::new();
+            let mut fetched = 0u32;
+            let mut written = 0u32;
+
+            for period in &periods {
+                let workplaces = nps
+                    .fetch_by_region_for_month(&sido, sigungu.as_deref(), *period)
+                    .await?;
+                fetched += workplaces.len() as u32;
+                (counts.fetched, counts.written) = (Some(fetched), Some(written));
+                if workplaces.is_empty() {
+                    tracing::warn!("No NPS data for {} {}", scope, period);
+                    empty_months.push(*period);
+                    continue;
+                }
+
+                let params = serde_json::json!({
+                    "sido": sido, "sigungu": sigungu, "year_month": period.to_string(),
+                });
+                let count = load_workplaces(&pool, sink, params, &workplaces).await?;
+                tracing::info!("{}: loaded {} workplaces", period, count);
+                written += count;
+            }
+            let contended = run_lock.contended;
+            run_lock.release().await?;
+            (counts.fetched, counts.written) = (Some(fetched), Some(written));
 
-            let count =
-                kiep_etl::load::postgres::upsert_nps_workplaces(&pool, &workplaces).await?;
-            tracing::info!("Upserted {} records to database", count);
+            println!("백필 기간: {} ~ {} ({}개월)", from, to, periods.len());
+            println!("기록: {}건", written);
+            println!("자료 없음: {}개월", empty_months.len());
+            for period in &empty_months {
+                println!("  {}", period);
+            }
+            println!("{}", lock_report(contended));
         }
 
         Commands::CheckNts { biz_no } => {
-            let api_key = config
-                .nts_api_key
+            let api_key = keys
+                .nts
+                .clone()
                 .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_NTS_KEY not set"))?;
 
-            let nts = kiep_etl::clients::nts::NtsClient::new(&api_key);
+            let nts = clients.nts(api_key);
             match nts.check_status(&biz_no).await? {
                 Some(info) => {
                     println!("사업자번호: {}", info.biz_no);
@@ -99,29 +664,363 @@ async fn main() -> anyhow::Result<()> {
                     println!("대표자: {}", info.ceo_name);
                     println!("상태: {}", info.status);
                     println!("과세유형: {}", info.tax_type);
+
+                    if let Some(status) = normalize::nts_status_to_biz_status(&info.status) {
+                        let biz_no = normalize::normalize_biz_no(&info.biz_no);
+                        let changed = postgres::update_biz_status(
+                            &pool, &biz_no, &status, None, "NTS",
+                        )
+                        .await?;
+                        if changed {
+                            tracing::info!("biz_status updated to {}", status.as_str());
+                        }
+                    }
                 }
                 None => println!("해당 사업자번호를 찾을 수 없습니다."),
             }
         }
 
-        Commands::ExportHealth { output } => {
+        Commands::FetchKicox { province, tenants } => {
+            let api_key = keys
+                .kicox
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_KICOX_KEY not set"))?;
+
+            let kicox = clients.kicox(api_key);
+            let complexes = match &province {
+                Some(province) => kicox.fetch_by_province(province).await?,
+                None => kicox.fetch_all_complexes().await?,
+            };
+            counts.fetched = Some(complexes.len() as u32);
+
+            let count = postgres::upsert_industrial_complexes(&pool, &complexes).await?;
+            counts.written = Some(count);
+            println!("산업단지 {}건 수집, {}건 upsert", complexes.len(), count);
+
+            if tenants {
+                let mut fetched = Vec::new();
+                for complex in complexes.iter().filter(|c| !c.complex_code.trim().is_empty()) {
+                    fetched.extend(kicox.fetch_tenants(complex.complex_code.trim()).await?);
+                }
+
+                let params = serde_json::json!({ "province": province, "tenants": true });
+                let load_batch = batch::start_batch(&pool, "KICOX", params).await?;
+                let written = match postgres::upsert_complex_tenants(&pool, &fetched, load_batch.id).await {
+                    Ok(written) => written,
+                    Err(e) => {
+                        batch::fail_batch(&pool, load_batch.id).await?;
+                        return Err(e.context(format!("KICOX batch {} rolled back", load_batch.id)));
+                    }
+                };
+                batch::complete_batch(&pool, load_batch.id, fetched.len(), written).await?;
+                println!("입주기업 {}건 수집, {}건 기록 (배치 {})", fetched.len(), written, load_batch.id);
+            }
+        }
+
+        Commands::FetchPps { from, to, biz_no, category, notice_agency, demand_agency } => {
+            let api_key = keys
+                .pps
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_PPS_KEY not set"))?;
+            pps::validate_date_range(&from, &to)?;
+            let filter = PpsFilter { category, notice_agency, demand_agency };
+            filter.validate()?;
+
+            let pps = clients.pps(api_key);
+            let mut contracts = pps.fetch_contracts_filtered(&from, &to, &filter).await?;
+            tracing::info!("Fetched {} PPS contracts", contracts.len());
+            counts.fetched = Some(contracts.len() as u32);
+
+            if let Some(biz_no) = &biz_no {
+                let biz_no = normalize::normalize_biz_no(biz_no);
+                contracts.retain(|c| normalize::normalize_biz_no(&c.biz_no) == biz_no);
+                tracing::info!("{} contracts match contractor {}", contracts.len(), biz_no);
+            }
+
+            let count = postgres::upsert_procurements(&pool, &contracts).await?;
+            counts.written = Some(count);
+            println!("조달 계약 {}건 upsert ({} ~ {})", count, from, to);
+        }
+
+        Commands::FetchFinancials { year, market, limit } => {
+            let api_key = keys
+                .fsc
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_FSC_KEY not set"))?;
+
+            let targets: Vec<(String, String)> = sqlx::query_as(
+                r#"
+                SELECT corp_no, name FROM companies
+                WHERE corp_no IS NOT NULL AND corp_no <> ''
+                  AND ($1::text IS NULL OR market_type = $1)
+                ORDER BY biz_no
+                LIMIT $2
+                "#,
+            )
+            .bind(&market)
+            .bind(limit)
+            .fetch_all(&pool)
+            .await?;
+
+            tracing::info!("Fetching FSC {} financials for {} companies", year, targets.len());
+
+            // 호출 간격은 클라이언트의 호출 제한(API_RATE_LIMIT_PER_SEC)이 맞춘다
+            let fsc = clients.fsc(api_key);
+            let fiscal_year = year.to_string();
+            let mut fetched = 0u32;
+            let mut written = 0u32;
+            let mut failed = 0u32;
+
+            for (i, (corp_no, name)) in targets.iter().enumerate() {
+                let items = match fsc.fetch_financials(corp_no, &fiscal_year).await {
+                    Ok(items) => items,
+                    // 한도 초과면 남은 기업도 실패하므로 중단
+                    Err(e) if is_quota_exceeded(&e) => return Err(e),
+                    Err(e) => {
+                        tracing::warn!("FSC fetch failed for {} ({}): {:#}", name, corp_no, e);
+                        failed += 1;
+                        continue;
+                    }
+                };
+                fetched += items.len() as u32;
+                let count = postgres::upsert_financials(&pool, corp_no, year, &items).await?;
+                written += count;
+                (counts.fetched, counts.written) = (Some(fetched), Some(written));
+                tracing::info!(
+                    "[{}/{}] {} ({}): {} items, {} statements",
+                    i + 1,
+                    targets.len(),
+                    name,
+                    corp_no,
+                    items.len(),
+                    count
+                );
+            }
+
+            println!(
+                "기업 {}곳, 재무제표 {}건 upsert (실패 {}곳)",
+                targets.len(),
+                written,
+                failed
+            );
+        }
+
+        Commands::RefreshStatuses { limit, older_than_days } => {
+            let api_key = keys
+                .nts
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("DATA_GO_KR_NTS_KEY not set"))?;
+
+            let biz_nos: Vec<String> = sqlx::query_scalar(
+                r#"
+                SELECT biz_no FROM companies
+                WHERE status_checked_at IS NULL
+                   OR status_checked_at < NOW() - make_interval(days => $1)
+                ORDER BY status_checked_at NULLS FIRST, biz_no
+                LIMIT $2
+                "#,
+            )
+            .bind(older_than_days)
+            .bind(limit)
+            .fetch_all(&pool)
+            .await?;
+
+            tracing::info!("Refreshing NTS status for {} companies", biz_nos.len());
+
+            let nts = clients.nts(api_key);
+            counts.fetched = Some(biz_nos.len() as u32);
+            let mut changed = 0u32;
+            let mut closed = 0u32;
+
+            for chunk in biz_nos.chunks(100) {
+                let refs: Vec<&str> = chunk.iter().map(String::as_str).collect();
+                for info in nts.check_status_bulk(&refs).await? {
+                    let Some(status) = normalize::nts_status_to_biz_status(&info.status) else {
+                        continue;
+                    };
+                    let biz_no = normalize::normalize_biz_no(&info.biz_no);
+                    if postgres::update_biz_status(&pool, &biz_no, &status, None, "NTS").await? {
+                        changed += 1;
+                        if status == BizStatus::Closed {
+                            closed += 1;
+                        }
+                    }
+                }
+                postgres::mark_status_checked(&pool, chunk).await?;
+                counts.written = Some(changed);
+            }
+
+            println!("확인: {}건, 상태 변경: {}건 (폐업 전환: {}건)", biz_nos.len(), changed, closed);
+        }
+
+        Commands::GeocodeCompanies { batch_size } => {
+            let api_key = keys
+                .vworld
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("VWORLD_API_KEY not set"))?;
+
+            let vworld = clients.vworld(api_key);
+            let summary = postgres::enrich_coordinates(&pool, &vworld, batch_size).await?;
+            (counts.fetched, counts.written) = (Some(summary.attempted), Some(summary.located));
+            println!(
+                "주소 검색: {}건, 좌표 확인: {}건, 찾지 못함: {}건",
+                summary.attempted, summary.located, summary.unresolved
+            );
+        }
+
+        Commands::MissingEmployment { limit } => {
+            let by_source: Vec<(Option<String>, i64)> = sqlx::query_as(
+                r#"
+                SELECT c.data_source, COUNT(*)
+                FROM companies c
+                LEFT JOIN employment_series es ON es.biz_no = c.biz_no
+                WHERE es.biz_no IS NULL
+                GROUP BY c.data_source
+                ORDER BY COUNT(*) DESC
+                "#,
+            )
+            .fetch_all(&pool)
+            .await?;
+
+            let rows: Vec<(String, String, Option<String>)> = sqlx::query_as(
+                r#"
+                SELECT c.biz_no, c.name, c.data_source
+                FROM companies c
+                LEFT JOIN employment_series es ON es.biz_no = c.biz_no
+                WHERE es.biz_no IS NULL
+                ORDER BY c.biz_no
+                LIMIT $1
+                "#,
+            )
+            .bind(limit)
+            .fetch_all(&pool)
+            .await?;
+
+            println!("=== 고용 데이터 없는 기업 (출처별) ===");
+            for (source, count) in &by_source {
+                println!("{:<10} {}", source.as_deref().unwrap_or("-"), count);
+            }
+            println!();
+            for (biz_no, name, source) in &rows {
+                println!("{}  {}  [{}]", biz_no, name, source.as_deref().unwrap_or("-"));
+            }
+        }
+
+        Commands::ComputeHealth { year_month } => {
+            let period = YearMonth::parse(&year_month)?;
+            let report = health::recompute_period(
+                &pool,
+                period,
+                config.health_min_companies,
+                &config.health_weights,
+            )
+            .await?;
+            (counts.fetched, counts.written) = (Some(1), Some(report.regions_written as u32));
+
+            if report.is_empty() {
+                println!("{}: 고용 데이터가 없어 건강도를 기록하지 않았습니다.", period);
+            } else {
+                println!(
+                    "{}: {}/{} 지역 건강도 기록",
+                    period, report.regions_written, report.regions_total
+                );
+            }
+        }
+
+        Commands::RecomputeHealthRange { from, to } => {
+            let from = YearMonth::parse(&from)?;
+            let to = YearMonth::parse(&to)?;
+            let periods = YearMonth::range_inclusive(from, to);
+            anyhow::ensure!(!periods.is_empty(), "--from must not be after --to");
+
+            let mut skipped = Vec::new();
+            let mut partial = Vec::new();
+            let mut written = 0u32;
+
+            for (i, period) in periods.iter().enumerate() {
+                let report = health::recompute_period(
+                    &pool,
+                    *period,
+                    config.health_min_companies,
+                    &config.health_weights,
+                )
+                .await?;
+                tracing::info!(
+                    "[{}/{}] {}: {} regions written",
+                    i + 1,
+                    periods.len(),
+                    period,
+                    report.regions_written
+                );
+                written += report.regions_written as u32;
+                (counts.fetched, counts.written) = (Some(i as u32 + 1), Some(written));
+                if report.is_empty() {
+                    skipped.push(report);
+                } else if report.is_partial() {
+                    partial.push(report);
+                }
+            }
+
+            println!("재계산 기간: {} ~ {} ({}개월)", from, to, periods.len());
+            println!("데이터 없어 건너뜀: {}개월", skipped.len());
+            for r in &skipped {
+                println!("  {}", r.year_month);
+            }
+            println!("부분 커버리지: {}개월", partial.len());
+            for r in &partial {
+                println!("  {} ({}/{} 지역)", r.year_month, r.regions_written, r.regions_total);
+            }
+        }
+
+        Commands::RollbackBatch { id } => {
+            let Some(report) = batch::rollback_batch(&pool, id).await? else {
+                println!("배치 {}를 찾을 수 없습니다.", id);
+                return Ok(());
+            };
+
+            println!("배치 {} 롤백 완료", id);
+            println!("  삭제된 고용 시계열: {}건", report.employment_deleted);
+            println!("  삭제된 기업: {}건", report.companies_deleted);
+            if report.employment_kept > 0 || report.companies_kept > 0 {
+                println!(
+                    "  기존 행 갱신분 유지 (되돌릴 수 없음): 고용 {}건, 기업 {}건",
+                    report.employment_kept, report.companies_kept
+                );
+            }
+        }
+
+        Commands::ExportHealth { output, sample } => {
             let entries: Vec<serde_json::Value> = sqlx::query_scalar(
                 r#"
                 SELECT json_build_object(
                     'code', r.code,
                     'name', r.name,
                     'province', r.province,
-                    'healthScore', COALESCE(rh.health_score, 50),
+                    -- 공개하지 않는 점수(insufficient_data)와 데이터 없는 지역은 null
+                    'healthScore', CASE WHEN rh.insufficient_data THEN NULL ELSE rh.health_score END,
                     'companyCount', COALESCE(rh.company_count, 0),
                     'employeeCount', COALESCE(rh.employee_count, 0),
-                    'growthRate', COALESCE(rh.employment_growth, 0)
+                    'growthRate', COALESCE(rh.employment_growth, 0),
+                    'scoreVersion', rh.score_version,
+                    'insufficientData', COALESCE(rh.insufficient_data, false)
                 )
                 FROM regions r
                 LEFT JOIN region_health rh ON rh.region_code = r.code
                     AND rh.year_month = (SELECT MAX(year_month) FROM region_health)
+                -- 표본: 시도별 코드 순번으로 돌아가며 선택
+                WHERE $1::bigint IS NULL OR r.code IN (
+                    SELECT code FROM (
+                        SELECT code, province,
+                               ROW_NUMBER() OVER (PARTITION BY province ORDER BY code) as rn
+                        FROM regions
+                    ) ranked
+                    ORDER BY rn, province, code
+                    LIMIT $1
+                )
                 ORDER BY r.code
                 "#,
             )
+            .bind(sample)
             .fetch_all(&pool)
             .await?;
 
@@ -130,6 +1029,73 @@ async fn main() -> anyhow::Result<()> {
             tracing::info!("Exported {} regions to {}", entries.len(), output);
         }
 
+        Commands::ExportRegion { code, output, format } => {
+            let count = export::export_region(&pool, &code, &output, format).await?;
+            tracing::info!("Exported {} companies in {} to {}", count, code, output);
+        }
+
+        Commands::ExportSnapshot { period, output } => {
+            let period = YearMonth::parse(&period)?;
+            let manifest = snapshot::export_snapshot(&pool, period, &output).await?;
+            tracing::info!(
+                "Exported {} snapshot ({} rows) to {}",
+                period,
+                manifest.total_rows(),
+                output
+            );
+        }
+
+        Commands::Validate { limit, fail_on, json } => {
+            validate::validate(&pool, limit, &fail_on, json).await?;
+        }
+
+        Commands::Jobs { limit } => {
+            let recent = jobs::recent_jobs(&pool, limit).await?;
+            println!("=== 최근 실행 기록 ===");
+            for job in &recent {
+                let count = |n: Option<i32>| n.map_or("-".to_string(), |n| n.to_string());
+                println!(
+                    "{}  {:<24} {:<10} {:>8} {:>8}  {}",
+                    job.started_at.format("%Y-%m-%d %H:%M:%S"),
+                    job.command,
+                    job.status,
+                    count(job.fetched_count),
+                    count(job.written_count),
+                    job.finished_at
+                        .map(|f| format!("{}s", (f - job.started_at).num_seconds()))
+                        .unwrap_or_else(|| "-".into()),
+                );
+                if job.lock_contended == Some(true) && job.status != "skipped" {
+                    println!("    run lock 경합 (다른 실행 종료 후 진행)");
+                }
+                if let Some(error) = &job.error {
+                    println!("    {}", error);
+                }
+            }
+        }
+
+        Commands::Sources => {
+            let statuses = source_status::source_statuses(&pool).await?;
+            println!("=== 데이터 소스 상태 (stale 기준 {}시간) ===", config.source_max_age_hours);
+            for s in &statuses {
+                let stale = s.is_stale(config.source_max_age_hours);
+                println!(
+                    "{:<6} {:<10} {:>20}  {}",
+                    s.source,
+                    s.last_outcome(),
+                    s.last_success_at
+                        .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_else(|| "-".into()),
+                    if stale { "STALE" } else { "" },
+                );
+                if s.last_outcome() == "failed"
+                    && let Some(error) = &s.last_error
+                {
+                    println!("    {}", error);
+                }
+            }
+        }
+
         Commands::Stats => {
             let company_count: (i64,) =
                 sqlx::query_as("SELECT COUNT(*) FROM companies")
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::Path;
+
+use kiep_core::models::RegionHealth;
+use kiep_core::period::YearMonth;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+
+#[derive(Serialize, FromRow)]
+struct SnapshotRegion {
+    code: String,
+    name: String,
+    province: String,
+    center_lon: Option<f64>,
+    center_lat: Option<f64>,
+    area_km2: Option<f64>,
+}
+
+/// 기준 월에 고용 기록이 있는 기업 기준 지역 집계
+#[derive(Serialize, FromRow)]
+struct SnapshotRegionCompanies {
+    region_code: String,
+    company_count: i64,
+    employee_count: i64,
+    new_hires: i64,
+    departures: i64,
+}
+
+#[derive(Serialize, FromRow)]
+struct SnapshotHealth {
+    region_code: String,
+    company_count: Option<i32>,
+    employee_count: Option<i32>,
+    new_biz_count: Option<i32>,
+    closed_biz_count: Option<i32>,
+    employment_growth: Option<f64>,
+    new_biz_rate: Option<f64>,
+    closure_rate: Option<f64>,
+    avg_revenue_growth: Option<f64>,
+    complex_utilization: Option<f64>,
+    health_score: f64,
+    score_version: i32,
+    score_weights: Option<String>,
+    insufficient_data: bool,
+}
+
+#[derive(Serialize)]
+struct SnapshotFile {
+    name: &'static str,
+    rows: usize,
+}
+
+/// 스냅샷 출처 정보, 같은 기간·같은 DB면 동일하도록 생성 시각은 넣지 않는다
+#[derive(Serialize)]
+pub struct SnapshotManifest {
+    period: String,
+    /// health.json에 포함된 점수 모델 버전 (오름차순)
+    score_versions: Vec<i32>,
+    /// 내보낸 CLI가 사용하는 현재 점수 모델 버전
+    current_score_version: i32,
+    files: Vec<SnapshotFile>,
+}
+
+fn write_json<T: Serialize>(dir: &Path, name: &str, value: &T) -> anyhow::Result<()> {
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    fs::write(dir.join(name), json)?;
+    Ok(())
+}
+
+/// 기준 월 시점의 지역/기업 집계/건전성을 output 디렉터리에 기록
+/// 모든 쿼리는 기간으로만 매개변수화하고 키 순으로 정렬해 재실행 시 바이트 단위로 같게 유지
+pub async fn export_snapshot(
+    pool: &PgPool,
+    period: YearMonth,
+    output: &str,
+) -> anyhow::Result<SnapshotManifest> {
+    let period = period.to_string();
+    let dir = Path::new(output);
+    fs::create_dir_all(dir)?;
+
+    let regions = sqlx::query_as::<_, SnapshotRegion>(
+        r#"
+        SELECT code, name, province, center_lon, center_lat, area_km2
+        FROM regions
+        ORDER BY code
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let companies = sqlx::query_as::<_, SnapshotRegionCompanies>(
+        r#"
+        SELECT LEFT(c.bjd_code, 5) as region_code,
+               COUNT(DISTINCT c.biz_no) as company_count,
+               COALESCE(SUM(es.employee_count), 0)::bigint as employee_count,
+               COALESCE(SUM(es.new_hires), 0)::bigint as new_hires,
+               COALESCE(SUM(es.departures), 0)::bigint as departures
+        FROM employment_series es
+        JOIN companies c ON c.biz_no = es.biz_no
+        WHERE es.year_month = $1 AND c.bjd_code IS NOT NULL
+        GROUP BY LEFT(c.bjd_code, 5)
+        ORDER BY region_code
+        "#,
+    )
+    .bind(&period)
+    .fetch_all(pool)
+    .await?;
+
+    let health = sqlx::query_as::<_, SnapshotHealth>(
+        r#"
+        SELECT region_code, company_count, employee_count, new_biz_count, closed_biz_count,
+               employment_growth, new_biz_rate, closure_rate, avg_revenue_growth,
+               complex_utilization, health_score, score_version, score_weights, insufficient_data
+        FROM region_health
+        WHERE year_month = $1
+        ORDER BY region_code
+        "#,
+    )
+    .bind(&period)
+    .fetch_all(pool)
+    .await?;
+
+    write_json(dir, "regions.json", &regions)?;
+    write_json(dir, "region_companies.json", &companies)?;
+    write_json(dir, "health.json", &health)?;
+
+    let mut score_versions: Vec<i32> = health.iter().map(|h| h.score_version).collect();
+    score_versions.sort_unstable();
+    score_versions.dedup();
+
+    let manifest = SnapshotManifest {
+        period,
+        score_versions,
+        current_score_version: RegionHealth::SCORE_VERSION,
+        files: vec![
+            SnapshotFile { name: "regions.json", rows: regions.len() },
+            SnapshotFile { name: "region_companies.json", rows: companies.len() },
+            SnapshotFile { name: "health.json", rows: health.len() },
+        ],
+    };
+    write_json(dir, "manifest.json", &manifest)?;
+
+    Ok(manifest)
+}
+
+impl SnapshotManifest {
+    pub fn total_rows(&self) -> usize {
+        self.files.iter().map(|f| f.rows).sum()
+    }
+}
@@ -0,0 +1,181 @@
+use clap::ValueEnum;
+use kiep_core::models::RegionHealth;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+
+/// 정합성 검사 항목
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// 10자리 숫자가 아닌 사업자번호
+    InvalidBizNo,
+    /// active/suspended/closed 외의 영업상태
+    InvalidBizStatus,
+    /// regions에 없는 시군구 코드
+    UnknownRegion,
+    /// industrial_complexes에 없는 산단 ID
+    UnknownComplex,
+    /// 'YYYY-MM' 형식이 아닌 고용 시계열 월
+    InvalidYearMonth,
+    /// 음수 고용인원
+    NegativeEmployment,
+    /// 현재 모델과 다른 버전으로 계산된 건전성 점수
+    StaleScoreVersion,
+}
+
+impl IssueKind {
+    pub const ALL: [IssueKind; 7] = [
+        Self::InvalidBizNo,
+        Self::InvalidBizStatus,
+        Self::UnknownRegion,
+        Self::UnknownComplex,
+        Self::InvalidYearMonth,
+        Self::NegativeEmployment,
+        Self::StaleScoreVersion,
+    ];
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::InvalidBizNo => "사업자번호 형식 오류",
+            Self::InvalidBizStatus => "알 수 없는 영업상태",
+            Self::UnknownRegion => "지역 테이블에 없는 법정동코드",
+            Self::UnknownComplex => "산단 테이블에 없는 산단 ID",
+            Self::InvalidYearMonth => "고용 시계열 월 형식 오류",
+            Self::NegativeEmployment => "음수 고용인원",
+            Self::StaleScoreVersion => "이전 버전 건전성 점수",
+        }
+    }
+
+    /// (샘플 키, 상세, FROM/WHERE 절) — 모두 고정 문자열
+    fn query_parts(self) -> (&'static str, &'static str, String) {
+        match self {
+            Self::InvalidBizNo => (
+                "biz_no",
+                "name",
+                "FROM companies WHERE biz_no !~ '^[0-9]{10}$'".into(),
+            ),
+            Self::InvalidBizStatus => (
+                "biz_no",
+                "COALESCE(biz_status, 'NULL')",
+                "FROM companies WHERE biz_status IS NULL \
+                 OR biz_status NOT IN ('active', 'suspended', 'closed')"
+                    .into(),
+            ),
+            Self::UnknownRegion => (
+                "biz_no",
+                "bjd_code",
+                "FROM companies c WHERE c.bjd_code IS NOT NULL \
+                 AND NOT EXISTS (SELECT 1 FROM regions r WHERE r.code = LEFT(c.bjd_code, 5))"
+                    .into(),
+            ),
+            Self::UnknownComplex => (
+                "biz_no",
+                "complex_id",
+                "FROM companies c WHERE c.complex_id IS NOT NULL \
+                 AND NOT EXISTS (SELECT 1 FROM industrial_complexes ic WHERE ic.id = c.complex_id)"
+                    .into(),
+            ),
+            Self::InvalidYearMonth => (
+                "biz_no",
+                "year_month",
+                "FROM employment_series WHERE year_month !~ '^[0-9]{4}-(0[1-9]|1[0-2])$'".into(),
+            ),
+            Self::NegativeEmployment => (
+                "biz_no",
+                "year_month || ': ' || employee_count",
+                "FROM employment_series WHERE employee_count < 0".into(),
+            ),
+            Self::StaleScoreVersion => (
+                "region_code",
+                "year_month || ': v' || score_version",
+                format!(
+                    "FROM region_health WHERE score_version <> {}",
+                    RegionHealth::SCORE_VERSION
+                ),
+            ),
+        }
+    }
+}
+
+#[derive(Serialize, FromRow)]
+pub struct IssueSample {
+    key: String,
+    detail: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct CheckReport {
+    issue: IssueKind,
+    description: &'static str,
+    count: i64,
+    /// 키 순으로 최대 limit건
+    samples: Vec<IssueSample>,
+}
+
+async fn run_check(pool: &PgPool, kind: IssueKind, limit: i64) -> anyhow::Result<CheckReport> {
+    let (key, detail, from) = kind.query_parts();
+
+    let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) {}", from))
+        .fetch_one(pool)
+        .await?;
+
+    let samples = if count > 0 && limit > 0 {
+        let sql = format!(
+            "SELECT {key}::text as key, ({detail})::text as detail {from} ORDER BY 1, 2 LIMIT $1"
+        );
+        sqlx::query_as::<_, IssueSample>(&sql)
+            .bind(limit)
+            .fetch_all(pool)
+            .await?
+    } else {
+        vec![]
+    };
+
+    Ok(CheckReport {
+        issue: kind,
+        description: kind.description(),
+        count,
+        samples,
+    })
+}
+
+/// 전체 검사 실행 후 출력, fail_on에 해당하는 문제가 있으면 Err (종료 코드 1)
+pub async fn validate(
+    pool: &PgPool,
+    limit: i64,
+    fail_on: &[IssueKind],
+    json: bool,
+) -> anyhow::Result<()> {
+    let mut reports = Vec::with_capacity(IssueKind::ALL.len());
+    for kind in IssueKind::ALL {
+        reports.push(run_check(pool, kind, limit).await?);
+    }
+
+    let failed: Vec<IssueKind> = reports
+        .iter()
+        .filter(|r| r.count > 0 && fail_on.contains(&r.issue))
+        .map(|r| r.issue)
+        .collect();
+
+    if json {
+        let out = serde_json::json!({ "checks": reports, "failed": failed });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        println!("=== 데이터 정합성 검사 ===");
+        for r in &reports {
+            println!("{:<10} {}", r.count, r.description);
+            for s in &r.samples {
+                println!("    {}  {}", s.key, s.detail.as_deref().unwrap_or("-"));
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        let names: Vec<_> = failed
+            .iter()
+            .filter_map(|k| k.to_possible_value().map(|v| v.get_name().to_string()))
+            .collect();
+        anyhow::bail!("integrity check failed: {}", names.join(", "));
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::Error;
+
+/// 법정동코드 정규화: 숫자만 남기고 10자리로 (뒤 0 패딩)
+pub fn normalize(raw: &str) -> String {
+    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+    format!("{:0<10}", digits)
+}
+
+/// 시군구 코드(5자리)
+pub fn sigungu_code(bjd_code: &str) -> String {
+    normalize(bjd_code)[..5].to_string()
+}
+
+/// 시도 코드(2자리)
+pub fn sido_code(bjd_code: &str) -> String {
+    normalize(bjd_code)[..2].to_string()
+}
+
+/// API 입력 지역코드 검증: 숫자 5자리(시군구) 또는 10자리(법정동)
+/// 10자리는 시군구 코드로 축약해 반환
+pub fn validate_region_code(raw: &str) -> crate::Result<String> {
+    let raw = raw.trim();
+    if !raw.bytes().all(|b| b.is_ascii_digit()) || !matches!(raw.len(), 5 | 10) {
+        return Err(Error::Validation(format!(
+            "invalid region code '{}': expected 5-digit 시군구 or 10-digit 법정동 code",
+            raw
+        )));
+    }
+    Ok(sigungu_code(raw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_region_code() {
+        assert_eq!(validate_region_code("43110").unwrap(), "43110");
+        assert_eq!(validate_region_code("4311010100").unwrap(), "43110");
+        assert!(validate_region_code("431").is_err());
+        assert!(validate_region_code("4311a").is_err());
+        assert!(validate_region_code("43-110").is_err());
+        assert!(validate_region_code("").is_err());
+    }
+}
@@ -1,19 +1,267 @@
+use std::collections::HashMap;
 use std::env;
+use std::net::IpAddr;
+
+use sqlx::postgres::PgConnectOptions;
+
+use crate::models::HealthWeights;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
+    /// pg_stat_activity에 표시할 application_name (미설정 시 실행 구성요소 이름)
+    pub db_application_name: Option<String>,
     pub api_host: String,
     pub api_port: u16,
+    /// 응답 JSON 필드 이름을 camelCase로 변환, map 키는 그대로 (요청별 `?case=`로 재정의 가능)
+    pub camel_case_responses: bool,
+    /// /admin 라우트 `X-API-Key` 공유 비밀, 없으면 /admin 요청을 모두 거부
+    pub admin_api_key: Option<String>,
+    /// 클라이언트 IP별 분당 요청 한도, None이면 제한 없음 (`API_REQUESTS_PER_MINUTE=0`)
+    pub api_requests_per_minute: Option<u32>,
+    /// X-Forwarded-For를 믿을 리버스 프록시 주소, 비어 있으면 항상 접속 주소로 제한
+    pub trusted_proxies: Vec<IpAddr>,
 
     // data.go.kr API keys
     pub nps_api_key: Option<String>,
     pub nts_api_key: Option<String>,
     pub fsc_api_key: Option<String>,
     pub pps_api_key: Option<String>,
+    pub kicox_api_key: Option<String>,
 
     // VWorld
     pub vworld_api_key: Option<String>,
+
+    /// data.go.kr 초당 호출 한도 (모든 소스 합산), 없으면 제한 없음
+    pub api_rate_limit_per_sec: Option<f64>,
+    /// 한 번에 몰아 보낼 수 있는 호출 수, 없으면 초당 한도와 같게
+    pub api_rate_limit_burst: Option<u32>,
+
+    /// API 응답 디스크 캐시 디렉터리 (개발용), 없으면 캐시 안 함
+    pub api_cache_dir: Option<String>,
+    /// 캐시 응답 유효 시간 (초)
+    pub api_cache_ttl_secs: u64,
+
+    /// 건강도 점수를 공개할 최소 기업 수, 미만이면 insufficient_data로 표시
+    pub health_min_companies: i64,
+    /// 건강도 재계산(ComputeHealth/RecomputeHealthRange)에 쓰는 가중치, 기본값은 SCORE_VERSION 모델
+    pub health_weights: HealthWeights,
+
+    /// 마지막 성공 수집 후 이 시간이 지나면 소스를 stale로 표시
+    pub source_max_age_hours: i64,
+
+    /// regions 경계 컬럼 이름/SRID (choropleth)
+    pub region_geometry: RegionGeometry,
+
+    /// 목록 엔드포인트별 기본/최대 건수
+    pub limits: Limits,
+}
+
+/// 목록 건수 제한 (기본값, 최대값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListLimit {
+    pub default: i64,
+    pub max: i64,
+}
+
+impl ListLimit {
+    pub const fn new(default: i64, max: i64) -> Self {
+        Self { default, max }
+    }
+
+    /// 요청값(없으면 기본값)을 1..=max 범위로 보정
+    pub fn resolve(&self, requested: Option<i64>) -> i64 {
+        requested.unwrap_or(self.default).clamp(1, self.max.max(1))
+    }
+
+    /// "20,100" 형식의 환경변수 값, 잘못된 값이면 fallback
+    fn from_env(key: &str, fallback: ListLimit) -> Self {
+        env::var(key)
+            .ok()
+            .and_then(|raw| Self::parse(&raw))
+            .unwrap_or(fallback)
+    }
+
+    fn parse(raw: &str) -> Option<Self> {
+        let (default, max) = raw.split_once(',')?;
+        let default: i64 = default.trim().parse().ok()?;
+        let max: i64 = max.trim().parse().ok()?;
+        (default >= 1 && max >= default).then_some(Self { default, max })
+    }
+}
+
+/// regions 테이블 경계 컬럼, `REGIONS_GEOM_COLUMN`/`REGIONS_GEOM_SRID`로 재정의
+/// SRID가 4326이 아니면 응답 시 WGS84로 변환한다
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionGeometry {
+    /// SQL에 그대로 들어가므로 소문자/숫자/밑줄만 허용
+    pub column: String,
+    pub srid: i32,
+}
+
+impl Default for RegionGeometry {
+    fn default() -> Self {
+        Self { column: "geom".into(), srid: 4326 }
+    }
+}
+
+impl RegionGeometry {
+    fn from_env() -> crate::Result<Self> {
+        let d = Self::default();
+        let column = env::var("REGIONS_GEOM_COLUMN")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or(d.column);
+        if !is_plain_identifier(&column) {
+            return Err(crate::Error::Config(format!(
+                "REGIONS_GEOM_COLUMN must be a plain lowercase column name, got {:?}",
+                column
+            )));
+        }
+        let srid = match env::var("REGIONS_GEOM_SRID") {
+            Ok(v) => v.trim().parse().ok().filter(|srid| *srid > 0).ok_or_else(|| {
+                crate::Error::Config(format!("REGIONS_GEOM_SRID must be a positive integer, got {:?}", v))
+            })?,
+            Err(_) => d.srid,
+        };
+        Ok(Self { column, srid })
+    }
+}
+
+/// 따옴표 없이 SQL에 넣어도 되는 식별자 (소문자로 시작, 소문자/숫자/밑줄, 63자 이하)
+fn is_plain_identifier(name: &str) -> bool {
+    name.len() <= 63
+        && name.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && name.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
+
+/// 엔드포인트별 제한값, `LIMIT_<NAME>=default,max`로 재정의
+#[derive(Debug, Clone)]
+pub struct Limits {
+    pub company_search: ListLimit,
+    pub region_list: ListLimit,
+    pub company_employment: ListLimit,
+    pub company_financials: ListLimit,
+    pub company_procurements: ListLimit,
+    pub region_health: ListLimit,
+    /// 지역 고용 추이 개월 수
+    pub region_employment: ListLimit,
+    pub region_businesses: ListLimit,
+    pub region_compare: ListLimit,
+    /// 순위 상승/하락 목록 각각의 건수
+    pub region_movers: ListLimit,
+    pub region_ranking: ListLimit,
+    pub complex_series: ListLimit,
+    pub complex_companies: ListLimit,
+    /// 한 번에 비교할 산단 수 (max만 사용)
+    pub complex_compare: ListLimit,
+    pub industry_ranking: ListLimit,
+    pub admin: ListLimit,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            company_search: ListLimit::new(20, 100),
+            region_list: ListLimit::new(200, 200),
+            company_employment: ListLimit::new(36, 120),
+            company_financials: ListLimit::new(12, 40),
+            company_procurements: ListLimit::new(50, 500),
+            region_health: ListLimit::new(36, 120),
+            region_employment: ListLimit::new(24, 120),
+            region_businesses: ListLimit::new(100, 1000),
+            region_compare: ListLimit::new(10, 10),
+            region_movers: ListLimit::new(10, 50),
+            region_ranking: ListLimit::new(10, 100),
+            complex_series: ListLimit::new(12, 40),
+            complex_companies: ListLimit::new(20, 200),
+            complex_compare: ListLimit::new(5, 5),
+            industry_ranking: ListLimit::new(20, 100),
+            admin: ListLimit::new(50, 500),
+        }
+    }
+}
+
+impl Limits {
+    fn from_env() -> Self {
+        let d = Self::default();
+        Self {
+            company_search: ListLimit::from_env("LIMIT_COMPANY_SEARCH", d.company_search),
+            region_list: ListLimit::from_env("LIMIT_REGION_LIST", d.region_list),
+            company_employment: ListLimit::from_env("LIMIT_COMPANY_EMPLOYMENT", d.company_employment),
+            company_financials: ListLimit::from_env("LIMIT_COMPANY_FINANCIALS", d.company_financials),
+            company_procurements: ListLimit::from_env("LIMIT_COMPANY_PROCUREMENTS", d.company_procurements),
+            region_health: ListLimit::from_env("LIMIT_REGION_HEALTH", d.region_health),
+            region_employment: ListLimit::from_env("LIMIT_REGION_EMPLOYMENT", d.region_employment),
+            region_businesses: ListLimit::from_env("LIMIT_REGION_BUSINESSES", d.region_businesses),
+            region_compare: ListLimit::from_env("LIMIT_REGION_COMPARE", d.region_compare),
+            region_movers: ListLimit::from_env("LIMIT_REGION_MOVERS", d.region_movers),
+            region_ranking: ListLimit::from_env("LIMIT_REGION_RANKING", d.region_ranking),
+            complex_series: ListLimit::from_env("LIMIT_COMPLEX_SERIES", d.complex_series),
+            complex_companies: ListLimit::from_env("LIMIT_COMPLEX_COMPANIES", d.complex_companies),
+            complex_compare: ListLimit::from_env("LIMIT_COMPLEX_COMPARE", d.complex_compare),
+            industry_ranking: ListLimit::from_env("LIMIT_INDUSTRY_RANKING", d.industry_ranking),
+            admin: ListLimit::from_env("LIMIT_ADMIN", d.admin),
+        }
+    }
+}
+
+const DEFAULT_HEALTH_MIN_COMPANIES: i64 = 5;
+/// NPS는 월 단위 갱신이라 한 달 + 여유
+const DEFAULT_SOURCE_MAX_AGE_HOURS: i64 = 35 * 24;
+const DEFAULT_API_REQUESTS_PER_MINUTE: u32 = 300;
+const DEFAULT_API_CACHE_TTL_SECS: u64 = 24 * 60 * 60;
+
+pub const NPS_API_KEY_VAR: &str = "DATA_GO_KR_NPS_KEY";
+pub const NTS_API_KEY_VAR: &str = "DATA_GO_KR_NTS_KEY";
+pub const FSC_API_KEY_VAR: &str = "DATA_GO_KR_FSC_KEY";
+pub const PPS_API_KEY_VAR: &str = "DATA_GO_KR_PPS_KEY";
+pub const KICOX_API_KEY_VAR: &str = "DATA_GO_KR_KICOX_KEY";
+pub const VWORLD_API_KEY_VAR: &str = "VWORLD_API_KEY";
+pub const ADMIN_API_KEY_VAR: &str = "ADMIN_API_KEY";
+
+/// API 키 읽기: `<name>_FILE`이 있으면 그 파일 내용, 없으면 `<name>` 값
+/// 앞뒤 공백은 제거하고 빈 값은 None
+pub fn read_api_key(name: &str) -> Option<String> {
+    read_api_key_from(name, &HashMap::new())
+}
+
+/// read_api_key와 같되 vars에 있는 변수를 프로세스 환경 변수보다 우선 (실행 중 .env 재로드용)
+pub fn read_api_key_from(name: &str, vars: &HashMap<String, String>) -> Option<String> {
+    let var = |key: &str| vars.get(key).cloned().or_else(|| env::var(key).ok());
+    let raw = match var(&format!("{}_FILE", name)) {
+        Some(path) => match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::warn!("Failed to read {}_FILE ({}): {}", name, path, e);
+                return None;
+            }
+        },
+        None => var(name)?,
+    };
+    let key = raw.trim();
+    (!key.is_empty()).then(|| key.to_string())
+}
+
+/// 쉼표 구분 IP 목록 (빈 항목은 무시)
+fn parse_ip_list(raw: &str) -> Result<Vec<IpAddr>, std::net::AddrParseError> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// 실행 중 키 교체용으로 .env를 다시 읽어 변수 목록으로 반환, 프로세스 환경 변수는 바꾸지 않는다
+/// .env는 시작 시 dotenv()와 같은 방식으로 찾고, 없거나 읽을 수 없으면 빈 목록
+pub fn read_dotenv() -> HashMap<String, String> {
+    let Ok(iter) = dotenvy::dotenv_iter() else {
+        return HashMap::new();
+    };
+    iter.filter_map(|item| {
+        item.map_err(|e| tracing::warn!("Skipping unreadable .env line: {}", e)).ok()
+    })
+    .collect()
 }
 
 impl Config {
@@ -25,16 +273,162 @@ impl Config {
 
         Ok(Self {
             database_url,
+            db_application_name: env::var("DB_APPLICATION_NAME").ok().filter(|v| !v.is_empty()),
             api_host: env::var("API_HOST").unwrap_or_else(|_| "0.0.0.0".into()),
             api_port: env::var("API_PORT")
                 .unwrap_or_else(|_| "3100".into())
                 .parse()
                 .unwrap_or(3100),
-            nps_api_key: env::var("DATA_GO_KR_NPS_KEY").ok(),
-            nts_api_key: env::var("DATA_GO_KR_NTS_KEY").ok(),
-            fsc_api_key: env::var("DATA_GO_KR_FSC_KEY").ok(),
-            pps_api_key: env::var("DATA_GO_KR_PPS_KEY").ok(),
-            vworld_api_key: env::var("VWORLD_API_KEY").ok(),
+            camel_case_responses: env::var("API_CAMEL_CASE")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+            admin_api_key: read_api_key(ADMIN_API_KEY_VAR),
+            api_requests_per_minute: Some(
+                env::var("API_REQUESTS_PER_MINUTE")
+                    .ok()
+                    .and_then(|v| v.trim().parse().ok())
+                    .unwrap_or(DEFAULT_API_REQUESTS_PER_MINUTE),
+            )
+            .filter(|&n| n > 0),
+            trusted_proxies: match env::var("TRUSTED_PROXIES") {
+                Ok(v) => parse_ip_list(&v)
+                    .map_err(|e| crate::Error::Config(format!("TRUSTED_PROXIES: {}", e)))?,
+                Err(_) => Vec::new(),
+            },
+            nps_api_key: read_api_key(NPS_API_KEY_VAR),
+            nts_api_key: read_api_key(NTS_API_KEY_VAR),
+            fsc_api_key: read_api_key(FSC_API_KEY_VAR),
+            pps_api_key: read_api_key(PPS_API_KEY_VAR),
+            kicox_api_key: read_api_key(KICOX_API_KEY_VAR),
+            vworld_api_key: read_api_key(VWORLD_API_KEY_VAR),
+            api_rate_limit_per_sec: env::var("API_RATE_LIMIT_PER_SEC")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|rate: &f64| *rate > 0.0),
+            api_rate_limit_burst: env::var("API_RATE_LIMIT_BURST")
+                .ok()
+                .and_then(|v| v.trim().parse().ok()),
+            api_cache_dir: env::var("API_CACHE_DIR").ok().filter(|v| !v.is_empty()),
+            api_cache_ttl_secs: env::var("API_CACHE_TTL_SECS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(DEFAULT_API_CACHE_TTL_SECS),
+            health_min_companies: env::var("HEALTH_MIN_COMPANIES")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(DEFAULT_HEALTH_MIN_COMPANIES),
+            health_weights: match env::var("HEALTH_WEIGHTS") {
+                Ok(v) if !v.trim().is_empty() => v
+                    .parse()
+                    .map_err(|e| crate::Error::Config(format!("HEALTH_WEIGHTS: {}", e)))?,
+                _ => HealthWeights::default(),
+            },
+            source_max_age_hours: env::var("SOURCE_MAX_AGE_HOURS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(DEFAULT_SOURCE_MAX_AGE_HOURS),
+            region_geometry: RegionGeometry::from_env()?,
+            limits: Limits::from_env(),
         })
     }
 }
+
+impl Config {
+    /// DATABASE_URL 접속 옵션에 application_name 지정
+    /// 우선순위: DB_APPLICATION_NAME > URL의 application_name > component (예: "kiep-cli:fetch-nps")
+    pub fn pg_connect_options(&self, component: &str) -> crate::Result<PgConnectOptions> {
+        let options: PgConnectOptions = self
+            .database_url
+            .parse()
+            .map_err(|e| crate::Error::Config(format!("invalid DATABASE_URL: {}", e)))?;
+        let name = match (&self.db_application_name, options.get_application_name()) {
+            (Some(name), _) => name.clone(),
+            (None, Some(name)) => name.to_string(),
+            (None, None) => component.to_string(),
+        };
+        Ok(options.application_name(&name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_limit_resolve() {
+        let limit = ListLimit::new(20, 100);
+        assert_eq!(limit.resolve(None), 20);
+        assert_eq!(limit.resolve(Some(50)), 50);
+        assert_eq!(limit.resolve(Some(500)), 100);
+        assert_eq!(limit.resolve(Some(0)), 1);
+        assert_eq!(limit.resolve(Some(-5)), 1);
+    }
+
+    #[test]
+    fn test_list_limit_parse() {
+        assert_eq!(ListLimit::parse("20,100"), Some(ListLimit::new(20, 100)));
+        assert_eq!(ListLimit::parse(" 5 , 5 "), Some(ListLimit::new(5, 5)));
+        assert_eq!(ListLimit::parse("100,20"), None);
+        assert_eq!(ListLimit::parse("0,10"), None);
+        assert_eq!(ListLimit::parse("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_ip_list() {
+        assert_eq!(
+            parse_ip_list("10.0.0.1, ::1,").unwrap(),
+            [IpAddr::from([10, 0, 0, 1]), IpAddr::from(std::net::Ipv6Addr::LOCALHOST)]
+        );
+        assert!(parse_ip_list("").unwrap().is_empty());
+        assert!(parse_ip_list("10.0.0.0/8").is_err());
+    }
+
+    #[test]
+    fn test_plain_identifier() {
+        assert!(is_plain_identifier("geom"));
+        assert!(is_plain_identifier("boundary_5179"));
+        assert!(!is_plain_identifier("Geom"));
+        assert!(!is_plain_identifier("geom; DROP TABLE regions"));
+        assert!(!is_plain_identifier("1geom"));
+        assert!(!is_plain_identifier(""));
+    }
+
+    #[test]
+    fn test_pg_application_name_precedence() {
+        let mut config = Config {
+            database_url: "postgres://kiep@localhost:5432/kiep".into(),
+            db_application_name: None,
+            api_host: "0.0.0.0".into(),
+            api_port: 3100,
+            camel_case_responses: false,
+            admin_api_key: None,
+            api_requests_per_minute: Some(DEFAULT_API_REQUESTS_PER_MINUTE),
+            trusted_proxies: Vec::new(),
+            nps_api_key: None,
+            nts_api_key: None,
+            fsc_api_key: None,
+            pps_api_key: None,
+            kicox_api_key: None,
+            vworld_api_key: None,
+            api_rate_limit_per_sec: None,
+            api_rate_limit_burst: None,
+            api_cache_dir: None,
+            api_cache_ttl_secs: DEFAULT_API_CACHE_TTL_SECS,
+            health_min_companies: DEFAULT_HEALTH_MIN_COMPANIES,
+            health_weights: HealthWeights::default(),
+            source_max_age_hours: DEFAULT_SOURCE_MAX_AGE_HOURS,
+            region_geometry: RegionGeometry::default(),
+            limits: Limits::default(),
+        };
+        let name = |c: &Config| {
+            c.pg_connect_options("kiep-api").unwrap().get_application_name().map(String::from)
+        };
+        assert_eq!(name(&config).as_deref(), Some("kiep-api"));
+
+        config.database_url.push_str("?application_name=from-url");
+        assert_eq!(name(&config).as_deref(), Some("from-url"));
+
+        config.db_application_name = Some("override".into());
+        assert_eq!(name(&config).as_deref(), Some("override"));
+    }
+}
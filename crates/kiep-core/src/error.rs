@@ -13,9 +13,16 @@ pub enum Error {
     #[error("API error: {0}")]
     Api(String),
 
+    /// 일일/초당 호출 한도 초과 (더 요청해도 실패하므로 수집 중단)
+    #[error("API quota exceeded: {0}")]
+    QuotaExceeded(String),
+
     #[error("data processing error: {0}")]
     Processing(String),
 
+    #[error("validation error: {0}")]
+    Validation(String),
+
     #[error("not found: {0}")]
     NotFound(String),
 
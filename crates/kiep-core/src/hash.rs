@@ -0,0 +1,24 @@
+/// FNV-1a 64비트 해시, 각 조각 뒤에 0바이트를 넣어 ("ab", "c")와 ("a", "bc")를 구분
+/// std의 DefaultHasher와 달리 Rust 버전이 바뀌어도 같은 값이라 파일 이름/ETag에 쓴다
+pub fn fnv1a64<'a>(parts: impl IntoIterator<Item = &'a [u8]>) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for b in part.iter().chain(&[0]) {
+            hash ^= u64::from(*b);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a64_is_fixed_and_separates_parts() {
+        // 고정값: 구현이 바뀌면 기존 캐시 파일 이름과 ETag가 모두 바뀐다
+        assert_eq!(fnv1a64([b"a".as_slice()]), 0x089b_e207_b544_f1e4);
+        assert_ne!(fnv1a64([b"ab".as_slice(), b"c"]), fnv1a64([b"a".as_slice(), b"bc"]));
+    }
+}
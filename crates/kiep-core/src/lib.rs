@@ -1,6 +1,10 @@
+pub mod bjd;
 pub mod config;
 pub mod error;
+pub mod hash;
 pub mod models;
+pub mod period;
+pub mod units;
 
 pub use config::Config;
 pub use error::{Error, Result};
@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::period::YearQuarter;
+
 // ============================================================
 // 기업 (Company)
 // ============================================================
@@ -28,17 +30,23 @@ pub struct Company {
     pub complex_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum BizStatus {
+    #[default]
     Active,
     Suspended,
     Closed,
 }
 
-impl Default for BizStatus {
-    fn default() -> Self {
-        Self::Active
+impl BizStatus {
+    /// DB(companies.biz_status)에 저장되는 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Suspended => "suspended",
+            Self::Closed => "closed",
+        }
     }
 }
 
@@ -85,6 +93,14 @@ pub struct RegionHealth {
     pub complex_utilization: Option<f64>,
 
     pub health_score: f64,
+    /// 점수를 산출한 모델 버전 (RegionHealth::SCORE_VERSION)
+    pub score_version: i32,
+    /// 기본 가중치가 아닌 HEALTH_WEIGHTS로 산출했으면 그 가중치 (HealthWeights 표기), 기본이면 None
+    #[serde(default)]
+    pub score_weights: Option<String>,
+    /// 기업 수가 최소 기준 미만이라 점수를 공개하지 않음 (값은 분석용으로 저장)
+    #[serde(default)]
+    pub insufficient_data: bool,
 }
 
 // ============================================================
@@ -116,6 +132,38 @@ pub enum ComplexType {
     Agro,
 }
 
+/// KICOX 단지유형(cmplxTpCd) 코드("1"~"4"), 한글 유형명, 저장 코드값 모두 허용
+impl TryFrom<&str> for ComplexType {
+    type Error = crate::Error;
+
+    fn try_from(raw: &str) -> crate::Result<Self> {
+        let raw = raw.trim();
+        let parsed = match raw {
+            "1" | "national" => Some(Self::National),
+            "2" | "general" => Some(Self::General),
+            "3" | "urban_high_tech" => Some(Self::UrbanHighTech),
+            "4" | "agro" => Some(Self::Agro),
+            _ if raw.starts_with("국가") => Some(Self::National),
+            _ if raw.starts_with("일반") => Some(Self::General),
+            _ if raw.starts_with("도시첨단") => Some(Self::UrbanHighTech),
+            _ if raw.starts_with("농공") => Some(Self::Agro),
+            _ => None,
+        };
+        parsed.ok_or_else(|| crate::Error::Validation(format!("unknown complex type '{}'", raw)))
+    }
+}
+
+/// 산업단지 분기 시계열 (complex_series)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplexSeriesPoint {
+    pub complex_id: String,
+    pub year_quarter: YearQuarter,
+    pub production: Option<i64>,
+    pub export_amount: Option<i64>,
+    pub employment: Option<i32>,
+    pub operating_count: Option<i32>,
+}
+
 // ============================================================
 // 고용 시계열
 // ============================================================
@@ -144,6 +192,27 @@ pub struct Financial {
     pub total_assets: Option<i64>,
     pub total_equity: Option<i64>,
     pub total_debt: Option<i64>,
+    #[serde(default)]
+    pub statement_type: StatementType,
+}
+
+/// 재무제표 구분 (연결/별도), 한 기업이 둘 다 공시하는 경우가 있어 섞지 않는다
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum StatementType {
+    #[default]
+    Consolidated,
+    Separate,
+}
+
+impl StatementType {
+    /// DB(financials.statement_type)에 저장되는 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Consolidated => "consolidated",
+            Self::Separate => "separate",
+        }
+    }
 }
 
 // ============================================================
@@ -162,6 +231,98 @@ pub struct Procurement {
     pub agency: Option<String>,
 }
 
+// ============================================================
+// 코드값 / 라벨 (프론트엔드 드롭다운)
+// ============================================================
+
+/// DB 저장 코드값과 한/영 라벨, ALL은 선언 순서 그대로
+pub trait CodeLabel: Sized + 'static {
+    const ALL: &'static [Self];
+
+    fn code(&self) -> &'static str;
+    fn label_ko(&self) -> &'static str;
+    fn label_en(&self) -> &'static str;
+}
+
+impl CodeLabel for BizStatus {
+    const ALL: &'static [Self] = &[Self::Active, Self::Suspended, Self::Closed];
+
+    fn code(&self) -> &'static str {
+        self.as_str()
+    }
+
+    fn label_ko(&self) -> &'static str {
+        match self {
+            Self::Active => "계속사업자",
+            Self::Suspended => "휴업",
+            Self::Closed => "폐업",
+        }
+    }
+
+    fn label_en(&self) -> &'static str {
+        match self {
+            Self::Active => "Active",
+            Self::Suspended => "Suspended",
+            Self::Closed => "Closed",
+        }
+    }
+}
+
+impl CodeLabel for MarketType {
+    const ALL: &'static [Self] = &[Self::KOSPI, Self::KOSDAQ, Self::KONEX];
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::KOSPI => "KOSPI",
+            Self::KOSDAQ => "KOSDAQ",
+            Self::KONEX => "KONEX",
+        }
+    }
+
+    fn label_ko(&self) -> &'static str {
+        match self {
+            Self::KOSPI => "유가증권시장",
+            Self::KOSDAQ => "코스닥",
+            Self::KONEX => "코넥스",
+        }
+    }
+
+    fn label_en(&self) -> &'static str {
+        self.code()
+    }
+}
+
+impl CodeLabel for ComplexType {
+    const ALL: &'static [Self] = &[Self::National, Self::General, Self::UrbanHighTech, Self::Agro];
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::National => "national",
+            Self::General => "general",
+            Self::UrbanHighTech => "urban_high_tech",
+            Self::Agro => "agro",
+        }
+    }
+
+    fn label_ko(&self) -> &'static str {
+        match self {
+            Self::National => "국가산업단지",
+            Self::General => "일반산업단지",
+            Self::UrbanHighTech => "도시첨단산업단지",
+            Self::Agro => "농공단지",
+        }
+    }
+
+    fn label_en(&self) -> &'static str {
+        match self {
+            Self::National => "National industrial complex",
+            Self::General => "General industrial complex",
+            Self::UrbanHighTech => "Urban high-tech industrial complex",
+            Self::Agro => "Agro-industrial complex",
+        }
+    }
+}
+
 // ============================================================
 // API 응답 타입
 // ============================================================
@@ -171,10 +332,13 @@ pub struct RegionSummary {
     pub code: String,
     pub name: String,
     pub province: String,
-    pub health_score: f64,
+    /// 최신 region_health 점수, 기록이 없거나 insufficient_data이면 None
+    pub health_score: Option<f64>,
     pub company_count: i32,
     pub employee_count: i32,
-    pub growth_rate: f64,
+    /// 최신 월 고용증감률(%)
+    pub growth_rate: Option<f64>,
+    /// 업종코드별 기업 수 비중 (합 1.0, 업종 미상은 "unknown")
     pub industry_distribution: std::collections::HashMap<String, f64>,
 }
 
@@ -189,6 +353,7 @@ pub struct CompanyProfile {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RegionComparison {
     pub regions: Vec<RegionSummary>,
+    /// 레이더 차트 축으로 쓸 RegionSummary 지표 필드 이름
     pub axes: Vec<String>,
 }
 
@@ -197,6 +362,9 @@ pub struct RegionComparison {
 // ============================================================
 
 impl RegionHealth {
+    /// 건강도 스코어 모델 버전, calculate_score의 가중치·정규화 구간을 바꾸면 올린다
+    pub const SCORE_VERSION: i32 = 1;
+
     /// 건강도 스코어 산출
     /// health_score = (
     ///     0.30 × 고용증감률_정규화 +
@@ -212,17 +380,371 @@ impl RegionHealth {
         avg_revenue_growth: f64,
         complex_utilization: f64,
     ) -> f64 {
-        let eg = normalize(employment_growth, -10.0, 10.0);
-        let nb = normalize(new_biz_rate, 0.0, 20.0);
-        let cr = 1.0 - normalize(closure_rate, 0.0, 20.0);
-        let rg = normalize(avg_revenue_growth, -20.0, 30.0);
-        let cu = normalize(complex_utilization, 0.0, 100.0);
+        Self::score_components(
+            employment_growth,
+            new_biz_rate,
+            closure_rate,
+            avg_revenue_growth,
+            complex_utilization,
+        )
+        .score()
+    }
+
+    /// 임의 가중치로 건강도 산출 (저장하지 않는 시뮬레이션용)
+    pub fn calculate_score_with(
+        employment_growth: f64,
+        new_biz_rate: f64,
+        closure_rate: f64,
+        avg_revenue_growth: f64,
+        complex_utilization: f64,
+        weights: &HealthWeights,
+    ) -> f64 {
+        Self::score_components(
+            employment_growth,
+            new_biz_rate,
+            closure_rate,
+            avg_revenue_growth,
+            complex_utilization,
+        )
+        .score_with(weights)
+    }
+
+    /// 일부 지표가 없을 때의 건강도 산출 (기본 가중치)
+    /// 없는 지표(None)는 0으로 보지 않고 가중치에서 빼고, 남은 가중치를 합이 1.0이 되게 다시 맞춘다
+    /// (예: 산단이 없는 지역은 산단가동률 0%가 아니라 나머지 지표로만 평가)
+    /// 모든 지표가 없으면 None
+    pub fn calculate_score_partial(inputs: &HealthInputs) -> Option<f64> {
+        Self::partial_score(inputs, &HealthWeights::default())
+    }
+
+    /// 임의 가중치로 calculate_score_partial, 가중치 합이 1.0이 아니면 Error::Processing
+    pub fn calculate_score_weighted(
+        inputs: &HealthInputs,
+        weights: &HealthWeights,
+    ) -> crate::Result<Option<f64>> {
+        weights.validate()?;
+        Ok(Self::partial_score(inputs, weights))
+    }
+
+    fn partial_score(inputs: &HealthInputs, weights: &HealthWeights) -> Option<f64> {
+        let components = Self::partial_components(inputs);
+        let terms = [
+            (components.employment_growth, weights.employment_growth),
+            (components.new_biz_rate, weights.new_biz_rate),
+            (components.survival_rate, weights.survival_rate),
+            (components.avg_revenue_growth, weights.avg_revenue_growth),
+            (components.complex_utilization, weights.complex_utilization),
+        ];
+
+        let (weighted, total_weight) = terms
+            .iter()
+            .filter_map(|(value, weight)| value.map(|v| (v, *weight)))
+            .fold((0.0, 0.0), |(sum, total), (value, weight)| (sum + value * weight, total + weight));
+        (total_weight > 0.0).then(|| (weighted / total_weight * 100.0).clamp(0.0, 100.0))
+    }
+
+    /// 지표별 정규화 구성 요소, 없는 지표는 0이 아니라 None
+    pub fn partial_components(inputs: &HealthInputs) -> PartialComponents {
+        PartialComponents {
+            employment_growth: inputs.employment_growth.map(|v| normalize(v, -10.0, 10.0)),
+            new_biz_rate: inputs.new_biz_rate.map(|v| normalize(v, 0.0, 20.0)),
+            survival_rate: inputs.closure_rate.map(|v| 1.0 - normalize(v, 0.0, 20.0)),
+            avg_revenue_growth: inputs.avg_revenue_growth.map(|v| normalize(v, -20.0, 30.0)),
+            complex_utilization: inputs.complex_utilization.map(|v| normalize(v, 0.0, 100.0)),
+        }
+    }
+
+    /// 가중합 전 정규화(0~1) 구성 요소
+    pub fn score_components(
+        employment_growth: f64,
+        new_biz_rate: f64,
+        closure_rate: f64,
+        avg_revenue_growth: f64,
+        complex_utilization: f64,
+    ) -> ScoreComponents {
+        ScoreComponents {
+            employment_growth: normalize(employment_growth, -10.0, 10.0),
+            new_biz_rate: normalize(new_biz_rate, 0.0, 20.0),
+            survival_rate: 1.0 - normalize(closure_rate, 0.0, 20.0),
+            avg_revenue_growth: normalize(avg_revenue_growth, -20.0, 30.0),
+            complex_utilization: normalize(complex_utilization, 0.0, 100.0),
+        }
+    }
+}
+
+/// 정규화된 건강도 구성 요소 (SCORE_VERSION 기준)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ScoreComponents {
+    pub employment_growth: f64,
+    pub new_biz_rate: f64,
+    /// 1 - 폐업률_정규화
+    pub survival_rate: f64,
+    pub avg_revenue_growth: f64,
+    pub complex_utilization: f64,
+}
 
-        let score = (0.30 * eg + 0.25 * nb + 0.20 * cr + 0.15 * rg + 0.10 * cu) * 100.0;
+impl ScoreComponents {
+    pub fn score(&self) -> f64 {
+        self.score_with(&HealthWeights::default())
+    }
+
+    /// 임의 가중치로 가중합 (가중치 검증은 호출 측에서 HealthWeights::validate)
+    pub fn score_with(&self, weights: &HealthWeights) -> f64 {
+        let score = (weights.employment_growth * self.employment_growth
+            + weights.new_biz_rate * self.new_biz_rate
+            + weights.survival_rate * self.survival_rate
+            + weights.avg_revenue_growth * self.avg_revenue_growth
+            + weights.complex_utilization * self.complex_utilization)
+            * 100.0;
         score.clamp(0.0, 100.0)
     }
 }
 
+/// 일부 지표가 없을 수 있는 정규화 구성 요소 (SCORE_VERSION 기준)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct PartialComponents {
+    pub employment_growth: Option<f64>,
+    pub new_biz_rate: Option<f64>,
+    /// 1 - 폐업률_정규화
+    pub survival_rate: Option<f64>,
+    pub avg_revenue_growth: Option<f64>,
+    pub complex_utilization: Option<f64>,
+}
+
+/// 건강도 원천 지표 (단위: %), 집계되지 않은 지표는 None
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct HealthInputs {
+    pub employment_growth: Option<f64>,
+    pub new_biz_rate: Option<f64>,
+    pub closure_rate: Option<f64>,
+    pub avg_revenue_growth: Option<f64>,
+    pub complex_utilization: Option<f64>,
+}
+
+/// 건강도 구성 요소별 가중치, 기본값은 SCORE_VERSION 모델의 가중치
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct HealthWeights {
+    pub employment_growth: f64,
+    pub new_biz_rate: f64,
+    pub survival_rate: f64,
+    pub avg_revenue_growth: f64,
+    pub complex_utilization: f64,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self {
+            employment_growth: 0.30,
+            new_biz_rate: 0.25,
+            survival_rate: 0.20,
+            avg_revenue_growth: 0.15,
+            complex_utilization: 0.10,
+        }
+    }
+}
+
+impl HealthWeights {
+    /// 가중치 합 허용 오차
+    pub const SUM_EPSILON: f64 = 1e-6;
+
+    /// 각 가중치는 0 이상, 합은 1.0 (± SUM_EPSILON)
+    pub fn validate(&self) -> crate::Result<()> {
+        let weights = [
+            self.employment_growth,
+            self.new_biz_rate,
+            self.survival_rate,
+            self.avg_revenue_growth,
+            self.complex_utilization,
+        ];
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(crate::Error::Processing(
+                "health weights must be finite and non-negative".into(),
+            ));
+        }
+        let sum: f64 = weights.iter().sum();
+        if (sum - 1.0).abs() > Self::SUM_EPSILON {
+            return Err(crate::Error::Processing(format!(
+                "health weights must sum to 1.0, got {}",
+                sum
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// FromStr과 같은 쉼표 구분 표기
+impl std::fmt::Display for HealthWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.employment_growth,
+            self.new_biz_rate,
+            self.survival_rate,
+            self.avg_revenue_growth,
+            self.complex_utilization
+        )
+    }
+}
+
+/// 'employment,new_biz,survival,revenue,complex' 순서의 쉼표 구분 가중치 (예: "0.3,0.25,0.2,0.15,0.1")
+impl std::str::FromStr for HealthWeights {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| crate::Error::Processing(format!("invalid health weights {:?}: {}", s, e)))?;
+        let [employment_growth, new_biz_rate, survival_rate, avg_revenue_growth, complex_utilization] =
+            values[..]
+        else {
+            return Err(crate::Error::Processing(format!(
+                "health weights need 5 comma-separated values, got {:?}",
+                s
+            )));
+        };
+        let weights = Self {
+            employment_growth,
+            new_biz_rate,
+            survival_rate,
+            avg_revenue_growth,
+            complex_utilization,
+        };
+        weights.validate()?;
+        Ok(weights)
+    }
+}
+
 fn normalize(value: f64, min: f64, max: f64) -> f64 {
     ((value - min) / (max - min)).clamp(0.0, 1.0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 코드값이 serde 직렬화 값과 같고 중복이 없어야 API 입출력과 어긋나지 않는다
+    fn assert_codes_match_serde<T: CodeLabel + Serialize>() {
+        let mut seen = std::collections::HashSet::new();
+        for v in T::ALL {
+            assert_eq!(serde_json::to_value(v).unwrap(), v.code());
+            assert!(seen.insert(v.code()));
+        }
+    }
+
+    #[test]
+    fn test_code_labels_match_serde() {
+        assert_codes_match_serde::<BizStatus>();
+        assert_codes_match_serde::<MarketType>();
+        assert_codes_match_serde::<ComplexType>();
+        assert_eq!(ComplexType::UrbanHighTech.code(), "urban_high_tech");
+    }
+
+    #[test]
+    fn test_complex_type_try_from() {
+        assert_eq!(ComplexType::try_from("1").unwrap(), ComplexType::National);
+        assert_eq!(ComplexType::try_from("농공단지").unwrap(), ComplexType::Agro);
+        assert_eq!(ComplexType::try_from(" 도시첨단산업단지 ").unwrap(), ComplexType::UrbanHighTech);
+        for t in ComplexType::ALL {
+            assert_eq!(&ComplexType::try_from(t.code()).unwrap(), t);
+        }
+        assert!(ComplexType::try_from("").is_err());
+        assert!(ComplexType::try_from("9").is_err());
+    }
+
+    #[test]
+    fn test_health_weights() {
+        let default = HealthWeights::default();
+        assert!(default.validate().is_ok());
+        assert_eq!(
+            RegionHealth::calculate_score_with(5.0, 10.0, 2.0, 15.0, 95.0, &default),
+            RegionHealth::calculate_score(5.0, 10.0, 2.0, 15.0, 95.0)
+        );
+
+        let employment_only = HealthWeights {
+            employment_growth: 1.0,
+            new_biz_rate: 0.0,
+            survival_rate: 0.0,
+            avg_revenue_growth: 0.0,
+            complex_utilization: 0.0,
+        };
+        assert!(employment_only.validate().is_ok());
+        assert_eq!(RegionHealth::calculate_score_with(5.0, 0.0, 20.0, 0.0, 0.0, &employment_only), 75.0);
+
+        let unbalanced = HealthWeights { employment_growth: 0.5, ..default };
+        assert!(unbalanced.validate().is_err());
+        let negative = HealthWeights { employment_growth: -0.1, new_biz_rate: 0.65, ..default };
+        assert!(negative.validate().is_err());
+    }
+
+    #[test]
+    fn test_partial_score_redistributes_missing_weights() {
+        // 고용 +5%만 있는 지역: 고용 지표(0.75)만으로 평가, 나머지를 0으로 채우면 훨씬 낮아진다
+        let employment_only = HealthInputs { employment_growth: Some(5.0), ..Default::default() };
+        assert_eq!(RegionHealth::calculate_score_partial(&employment_only), Some(75.0));
+        assert!(RegionHealth::calculate_score(5.0, 0.0, 0.0, 0.0, 0.0) < 75.0);
+
+        // 산단 없음: 나머지 네 지표의 가중치(0.9)로 다시 맞춤
+        let no_complex = HealthInputs {
+            employment_growth: Some(5.0),
+            new_biz_rate: Some(10.0),
+            closure_rate: Some(2.0),
+            avg_revenue_growth: Some(15.0),
+            complex_utilization: None,
+        };
+        let components = RegionHealth::score_components(5.0, 10.0, 2.0, 15.0, 0.0);
+        let expected = (0.30 * components.employment_growth
+            + 0.25 * components.new_biz_rate
+            + 0.20 * components.survival_rate
+            + 0.15 * components.avg_revenue_growth)
+            / 0.90
+            * 100.0;
+        let score = RegionHealth::calculate_score_partial(&no_complex).unwrap();
+        assert!((score - expected).abs() < 1e-9);
+        assert!(score > RegionHealth::calculate_score(5.0, 10.0, 2.0, 15.0, 0.0));
+
+        // 모든 지표가 있으면 기존 계산과 같고, 하나도 없으면 None
+        let full = HealthInputs { complex_utilization: Some(95.0), ..no_complex };
+        let full_score = RegionHealth::calculate_score_partial(&full).unwrap();
+        assert!((full_score - RegionHealth::calculate_score(5.0, 10.0, 2.0, 15.0, 95.0)).abs() < 1e-9);
+        assert_eq!(RegionHealth::calculate_score_partial(&HealthInputs::default()), None);
+    }
+
+    #[test]
+    fn test_partial_components_leave_missing_none() {
+        let inputs = HealthInputs { employment_growth: Some(5.0), closure_rate: Some(2.0), ..Default::default() };
+        let components = RegionHealth::partial_components(&inputs);
+        let full = RegionHealth::score_components(5.0, 0.0, 2.0, 0.0, 0.0);
+        assert_eq!(components.employment_growth, Some(full.employment_growth));
+        assert_eq!(components.survival_rate, Some(full.survival_rate));
+        assert_eq!(components.new_biz_rate, None);
+        assert_eq!(components.avg_revenue_growth, None);
+        assert_eq!(components.complex_utilization, None);
+    }
+
+    #[test]
+    fn test_weighted_partial_score_and_parse() {
+        let inputs = HealthInputs { employment_growth: Some(5.0), closure_rate: Some(10.0), ..Default::default() };
+        let closures_only: HealthWeights = "0,0,1,0,0".parse().unwrap();
+        assert_eq!(RegionHealth::calculate_score_weighted(&inputs, &closures_only).unwrap(), Some(50.0));
+        assert_eq!(
+            RegionHealth::calculate_score_weighted(&inputs, &HealthWeights::default()).unwrap(),
+            RegionHealth::calculate_score_partial(&inputs)
+        );
+
+        let unbalanced = HealthWeights { employment_growth: 0.5, ..HealthWeights::default() };
+        assert!(matches!(
+            RegionHealth::calculate_score_weighted(&inputs, &unbalanced),
+            Err(crate::Error::Processing(_))
+        ));
+
+        assert_eq!(" 0.3, 0.25,0.2,0.15,0.1".parse::<HealthWeights>().unwrap(), HealthWeights::default());
+        assert_eq!(HealthWeights::default().to_string(), "0.3,0.25,0.2,0.15,0.1");
+        assert_eq!(closures_only.to_string().parse::<HealthWeights>().unwrap(), closures_only);
+        assert!("0.3,0.25,0.2,0.15".parse::<HealthWeights>().is_err());
+        assert!("0.3,0.25,0.2,0.15,x".parse::<HealthWeights>().is_err());
+        assert!("0.5,0.25,0.2,0.15,0.1".parse::<HealthWeights>().is_err());
+    }
+}
@@ -0,0 +1,339 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+use sqlx::{Decode, Encode, Postgres, Type};
+
+use crate::Error;
+
+/// 월 단위 기간 ('YYYY-MM')
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: i32,
+    month: u32,
+}
+
+impl YearMonth {
+    pub fn new(year: i32, month: u32) -> crate::Result<Self> {
+        if !(1000..=9999).contains(&year) || !(1..=12).contains(&month) {
+            return Err(Error::Validation(format!(
+                "invalid year_month {}-{:02}: expected YYYY-MM with month 01-12",
+                year, month
+            )));
+        }
+        Ok(Self { year, month })
+    }
+
+    /// "2024-01"
+    pub fn parse(raw: &str) -> crate::Result<Self> {
+        let invalid = || Error::Validation(format!("invalid year_month '{}': expected YYYY-MM", raw));
+        let (y, m) = raw.split_once('-').ok_or_else(invalid)?;
+        if y.len() != 4 || m.len() != 2 {
+            return Err(invalid());
+        }
+        let year = parse_digits(y).ok_or_else(invalid)? as i32;
+        let month = parse_digits(m).ok_or_else(invalid)?;
+        Self::new(year, month)
+    }
+
+    /// "202401" (data.go.kr 형식)
+    pub fn from_compact(raw: &str) -> crate::Result<Self> {
+        let invalid = || Error::Validation(format!("invalid compact year_month '{}': expected YYYYMM", raw));
+        if raw.len() != 6 {
+            return Err(invalid());
+        }
+        let year = parse_digits(&raw[..4]).ok_or_else(invalid)? as i32;
+        let month = parse_digits(&raw[4..]).ok_or_else(invalid)?;
+        Self::new(year, month)
+    }
+
+    /// 현재 월 (로컬 시간)
+    pub fn current() -> Self {
+        use chrono::Datelike;
+        let today = chrono::Local::now().date_naive();
+        Self { year: today.year(), month: today.month() }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// 다음 달
+    pub fn next(&self) -> Self {
+        if self.month == 12 {
+            Self { year: self.year + 1, month: 1 }
+        } else {
+            Self { year: self.year, month: self.month + 1 }
+        }
+    }
+
+    /// 이전 달
+    pub fn prev(&self) -> Self {
+        if self.month == 1 {
+            Self { year: self.year - 1, month: 12 }
+        } else {
+            Self { year: self.year, month: self.month - 1 }
+        }
+    }
+
+    /// from..=to 범위의 모든 월 (from > to이면 빈 목록)
+    pub fn range_inclusive(from: Self, to: Self) -> Vec<Self> {
+        let mut months = Vec::new();
+        let mut cur = from;
+        while cur <= to {
+            months.push(cur);
+            cur = cur.next();
+        }
+        months
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        Self::parse(s)
+    }
+}
+
+/// 분기 단위 기간 ('YYYY-Qn')
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearQuarter {
+    year: i32,
+    quarter: u32,
+}
+
+impl YearQuarter {
+    pub fn new(year: i32, quarter: u32) -> crate::Result<Self> {
+        if !(1000..=9999).contains(&year) || !(1..=4).contains(&quarter) {
+            return Err(Error::Validation(format!(
+                "invalid year_quarter {}-Q{}: expected YYYY-Qn with n 1-4",
+                year, quarter
+            )));
+        }
+        Ok(Self { year, quarter })
+    }
+
+    /// "2024-Q1"
+    pub fn parse(raw: &str) -> crate::Result<Self> {
+        let invalid = || Error::Validation(format!("invalid year_quarter '{}': expected YYYY-Qn", raw));
+        let (y, q) = raw.split_once("-Q").ok_or_else(invalid)?;
+        if y.len() != 4 || q.len() != 1 {
+            return Err(invalid());
+        }
+        let year = parse_digits(y).ok_or_else(invalid)? as i32;
+        let quarter = parse_digits(q).ok_or_else(invalid)?;
+        Self::new(year, quarter)
+    }
+
+    /// "20241" (YYYYQ, KICOX 등 data.go.kr 형식)
+    pub fn from_compact(raw: &str) -> crate::Result<Self> {
+        let invalid = || Error::Validation(format!("invalid compact year_quarter '{}': expected YYYYQ", raw));
+        if raw.len() != 5 {
+            return Err(invalid());
+        }
+        let year = parse_digits(&raw[..4]).ok_or_else(invalid)? as i32;
+        let quarter = parse_digits(&raw[4..]).ok_or_else(invalid)?;
+        Self::new(year, quarter)
+    }
+
+    /// 해당 월이 속한 분기
+    pub fn from_year_month(ym: YearMonth) -> Self {
+        Self { year: ym.year(), quarter: (ym.month() - 1) / 3 + 1 }
+    }
+
+    /// 수집 원천마다 다른 표기를 모두 허용
+    /// "2024-Q1" / "2024Q1" / "20241" / "2024-1" / "202403"(YYYYMM → 분기) / "2024-03"
+    pub fn parse_flexible(raw: &str) -> crate::Result<Self> {
+        let trimmed = raw.trim();
+        if let Ok(yq) = Self::parse(trimmed) {
+            return Ok(yq);
+        }
+        if let Some((y, q)) = trimmed.split_once('Q').or_else(|| trimmed.split_once('-'))
+            && y.len() == 4
+            && q.len() == 1
+        {
+            return Self::from_compact(&format!("{}{}", y, q));
+        }
+        match trimmed.len() {
+            5 => Self::from_compact(trimmed),
+            6 => YearMonth::from_compact(trimmed).map(Self::from_year_month),
+            7 => YearMonth::parse(trimmed).map(Self::from_year_month),
+            _ => Err(Error::Validation(format!(
+                "invalid year_quarter '{}': expected YYYY-Qn, YYYYQ or YYYYMM",
+                raw
+            ))),
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn quarter(&self) -> u32 {
+        self.quarter
+    }
+}
+
+impl fmt::Display for YearQuarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-Q{}", self.year, self.quarter)
+    }
+}
+
+impl FromStr for YearQuarter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        Self::parse(s)
+    }
+}
+
+macro_rules! impl_string_serde {
+    ($ty:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let raw = String::deserialize(deserializer)?;
+                raw.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+impl_string_serde!(YearMonth);
+impl_string_serde!(YearQuarter);
+
+/// DB에는 Display 형식 문자열로 저장 (VARCHAR 컬럼)
+macro_rules! impl_text_sqlx {
+    ($ty:ty, $decode:path) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                <String as Type<Postgres>>::type_info()
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                <String as Type<Postgres>>::compatible(ty)
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+                <String as Encode<Postgres>>::encode_by_ref(&self.to_string(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, Postgres> for $ty {
+            fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+                let raw = <&str as Decode<Postgres>>::decode(value)?;
+                Ok($decode(raw)?)
+            }
+        }
+    };
+}
+
+impl_text_sqlx!(YearMonth, YearMonth::parse);
+// 과거 적재분에 'YYYYQ' 표기가 섞여 있어 읽을 때는 관대하게 파싱
+impl_text_sqlx!(YearQuarter, YearQuarter::parse_flexible);
+
+fn parse_digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_year_month_parse() {
+        let ym = YearMonth::parse("2024-01").unwrap();
+        assert_eq!((ym.year(), ym.month()), (2024, 1));
+        assert_eq!(ym.to_string(), "2024-01");
+
+        assert!(YearMonth::parse("2024-13").is_err());
+        assert!(YearMonth::parse("2024-00").is_err());
+        assert!(YearMonth::parse("abc").is_err());
+        assert!(YearMonth::parse("2024-1").is_err());
+        assert!(YearMonth::parse("202401").is_err());
+    }
+
+    #[test]
+    fn test_year_month_from_compact() {
+        assert_eq!(YearMonth::from_compact("202401").unwrap().to_string(), "2024-01");
+        assert!(YearMonth::from_compact("20240").is_err());
+        assert!(YearMonth::from_compact("202413").is_err());
+    }
+
+    #[test]
+    fn test_year_month_navigation() {
+        let dec = YearMonth::parse("2023-12").unwrap();
+        assert_eq!(dec.next().to_string(), "2024-01");
+        assert_eq!(dec.next().prev(), dec);
+
+        let months = YearMonth::range_inclusive(
+            YearMonth::parse("2023-11").unwrap(),
+            YearMonth::parse("2024-02").unwrap(),
+        );
+        let labels: Vec<String> = months.iter().map(|m| m.to_string()).collect();
+        assert_eq!(labels, ["2023-11", "2023-12", "2024-01", "2024-02"]);
+        assert!(YearMonth::range_inclusive(dec.next(), dec).is_empty());
+    }
+
+    #[test]
+    fn test_year_quarter_parse() {
+        let yq = YearQuarter::parse("2024-Q3").unwrap();
+        assert_eq!((yq.year(), yq.quarter()), (2024, 3));
+        assert_eq!(yq.to_string(), "2024-Q3");
+
+        assert!(YearQuarter::parse("2024-Q5").is_err());
+        assert!(YearQuarter::parse("2024-Q0").is_err());
+        assert!(YearQuarter::parse("2024Q1").is_err());
+    }
+
+    #[test]
+    fn test_year_quarter_accepted_formats() {
+        let q1 = YearQuarter::new(2024, 1).unwrap();
+        for raw in ["2024-Q1", "2024Q1", "20241", "2024-1", " 2024-Q1 ", "202403", "2024-02"] {
+            assert_eq!(YearQuarter::parse_flexible(raw).unwrap(), q1, "{}", raw);
+        }
+        assert_eq!(YearQuarter::parse_flexible("202412").unwrap().to_string(), "2024-Q4");
+        assert_eq!(YearQuarter::from_compact("20233").unwrap().to_string(), "2023-Q3");
+
+        for raw in ["20245", "2024-Q5", "202413", "2024", "", "24-Q1", "2024-Q1x"] {
+            assert!(YearQuarter::parse_flexible(raw).is_err(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_year_quarter_from_year_month() {
+        let quarter = |m| YearQuarter::from_year_month(YearMonth::new(2024, m).unwrap()).quarter();
+        assert_eq!([1, 3, 4, 6, 7, 9, 10, 12].map(quarter), [1, 1, 2, 2, 3, 3, 4, 4]);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(YearMonth::parse("2023-12").unwrap() < YearMonth::parse("2024-01").unwrap());
+        assert!(YearQuarter::parse("2023-Q4").unwrap() < YearQuarter::parse("2024-Q1").unwrap());
+    }
+}
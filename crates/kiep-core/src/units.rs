@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+/// 금액 표시 단위
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MoneyUnit {
+    #[default]
+    Won,
+    /// 만원 (10^4)
+    Manwon,
+    /// 억원 (10^8)
+    Eokwon,
+}
+
+impl MoneyUnit {
+    pub fn divisor(&self) -> i64 {
+        match self {
+            Self::Won => 1,
+            Self::Manwon => 10_000,
+            Self::Eokwon => 100_000_000,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Won => "won",
+            Self::Manwon => "manwon",
+            Self::Eokwon => "eokwon",
+        }
+    }
+
+    /// 원 단위 금액을 (몫, 나머지 원)으로 분해 (정수 연산, 0 방향 절사)
+    pub fn split(&self, won: i64) -> (i64, i64) {
+        let d = self.divisor();
+        (won / d, won % d)
+    }
+
+    pub fn scale(&self, won: i64) -> Amount {
+        match self {
+            Self::Won => Amount::Won(won),
+            _ => {
+                let (value, remainder) = self.split(won);
+                Amount::Scaled { value, remainder }
+            }
+        }
+    }
+}
+
+/// 단위 환산된 금액
+/// 원 단위는 기존과 같은 숫자 그대로, 그 외 단위는 {value, remainder} 객체로 직렬화
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Amount {
+    Won(i64),
+    Scaled {
+        value: i64,
+        /// 절사된 나머지 (원)
+        remainder: i64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        assert_eq!(MoneyUnit::Manwon.split(123_456_789), (12_345, 6_789));
+        assert_eq!(MoneyUnit::Eokwon.split(123_456_789), (1, 23_456_789));
+        assert_eq!(MoneyUnit::Won.split(42), (42, 0));
+    }
+
+    #[test]
+    fn test_split_negative() {
+        // 적자도 부호를 유지하며 value * divisor + remainder == 원금
+        let (value, remainder) = MoneyUnit::Manwon.split(-15_000);
+        assert_eq!((value, remainder), (-1, -5_000));
+        assert_eq!(value * 10_000 + remainder, -15_000);
+    }
+
+    #[test]
+    fn test_amount_serialization() {
+        let won = serde_json::to_value(MoneyUnit::Won.scale(1_500)).unwrap();
+        assert_eq!(won, serde_json::json!(1_500));
+
+        let manwon = serde_json::to_value(MoneyUnit::Manwon.scale(15_000)).unwrap();
+        assert_eq!(manwon, serde_json::json!({ "value": 1, "remainder": 5_000 }));
+    }
+}
@@ -1,65 +1,461 @@
-use reqwest::Client;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use reqwest::header::{CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Client, StatusCode};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 use tracing::{info, warn};
 
-const MAX_RETRIES: u32 = 4;
-const BASE_BACKOFF_MS: u64 = 2000;
+use kiep_core::hash::fnv1a64;
+use kiep_core::Config;
+
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
+
+/// 요청 실패 시 재시도 정책 (지수 백오프, 대기 상한 있음, 기본 jitter)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 0이면 한 번만 시도
+    pub max_retries: u32,
+    /// 첫 재시도 전 대기, 이후 2배씩
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// 대기 시간을 [delay/2, delay]에서 무작위로 골라 여러 클라이언트의 재시도 시점을 분산
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 4,
+            base_backoff: Duration::from_millis(2000),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// attempt번째 재시도(1부터) 전 대기 시간
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// backoff에 jitter 적용
+    pub fn retry_delay(&self, attempt: u32, rng: &mut impl Rng) -> Duration {
+        let delay = self.backoff(attempt);
+        if !self.jitter {
+            return delay;
+        }
+        let max = delay.as_nanos().min(u64::MAX as u128) as u64;
+        Duration::from_nanos(rng.gen_range(max / 2..=max))
+    }
+}
+
+/// data.go.kr 공통 resultCode 설명
+fn result_code_description(code: &str) -> &'static str {
+    match code {
+        "01" => "application error",
+        "02" => "database error",
+        "04" => "HTTP error",
+        "05" => "service timeout",
+        "10" => "invalid request parameter",
+        "11" => "missing required request parameter",
+        "12" => "no such OpenAPI service",
+        "20" => "service access denied",
+        "21" => "service key temporarily disabled",
+        "22" => "daily request limit exceeded",
+        "23" => "per-second request limit exceeded",
+        "30" => "service key not registered",
+        "31" => "service key expired",
+        "32" => "unregistered IP",
+        "33" => "unsigned call",
+        _ => "unknown error",
+    }
+}
+
+/// 키 관련 오류에 붙이는 안내 (인코딩 키는 디코딩해 보내므로 어느 형태든 그대로 넣으면 된다)
+const SERVICE_KEY_HINT: &str = "; percent-encoded (Encoding) keys are decoded before sending, \
+so either key form from data.go.kr works as-is; check that the key is approved for this API";
+
+/// data.go.kr는 인코딩/디코딩 두 형태로 키를 발급한다
+/// 이미 인코딩된 키(`%2B` 등 포함)는 디코딩해 두고 reqwest가 한 번만 인코딩하게 한다 (`%252B` 방지)
+fn decode_service_key(key: &str) -> String {
+    let bytes = key.as_bytes();
+    let is_encoded = bytes
+        .windows(3)
+        .any(|w| w[0] == b'%' && w[1].is_ascii_hexdigit() && w[2].is_ascii_hexdigit());
+    if !is_encoded {
+        return key.to_string();
+    }
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let (Some(hi), Some(lo)) = (
+                bytes.get(i + 1).copied().and_then(hex),
+                bytes.get(i + 2).copied().and_then(hex),
+            )
+        {
+            decoded.push(hi << 4 | lo);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| key.to_string())
+}
+
+/// 키 오류 등은 요청한 형식과 무관하게 `<OpenAPI_ServiceResponse>` XML로 온다
+/// returnReasonCode가 있으면 resultCode처럼 확인한 결과
+fn openapi_service_error(body: &str) -> Option<anyhow::Error> {
+    let tag = |name: &str| {
+        let start = body.find(&format!("<{}>", name))? + name.len() + 2;
+        let end = body[start..].find("</")? + start;
+        Some(body[start..end].trim())
+    };
+    let code = tag("returnReasonCode")?;
+    check_result_code(code, tag("returnAuthMsg")).err()
+}
+
+/// 응답 header.resultCode 확인, 정상("00")과 데이터 없음("03")만 Ok
+/// 호출 한도 초과(22/23)는 kiep_core::Error::QuotaExceeded, 그 외는 Error::Api
+pub fn check_result_code(code: &str, msg: Option<&str>) -> anyhow::Result<()> {
+    let code = code.trim();
+    if matches!(code, "00" | "03") {
+        return Ok(());
+    }
+    let detail = format!(
+        "resultCode {} ({}){}",
+        code,
+        result_code_description(code),
+        msg.map(|m| format!(": {}", m.trim())).unwrap_or_default()
+    );
+    let detail = match code {
+        "20" | "30" | "31" => format!("{}{}", detail, SERVICE_KEY_HINT),
+        _ => detail,
+    };
+    Err(match code {
+        "22" | "23" => kiep_core::Error::QuotaExceeded(detail),
+        _ => kiep_core::Error::Api(detail),
+    }
+    .into())
+}
+
+/// 호출 한도 초과로 실패했는지 (페이지 수집을 중단할지 판단용)
+pub fn is_quota_exceeded(err: &anyhow::Error) -> bool {
+    matches!(err.downcast_ref::<kiep_core::Error>(), Some(kiep_core::Error::QuotaExceeded(_)))
+}
+
+/// Retry-After 헤더 값 (초 또는 HTTP-date) → 대기 시간, 지난 시각이면 0
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = httpdate::parse_http_date(value).ok()?;
+    Some(at.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+/// type=json을 무시하고 XML을 주는 엔드포인트용, JSON과 같은 응답 타입으로 역직렬화
+/// 루트 요소(`<response>`)를 JSON의 최상위 키처럼 다루도록 감싸서 파싱한다
+pub(crate) fn parse_xml<T: DeserializeOwned>(body: &str) -> Result<T, quick_xml::DeError> {
+    let mut body = body.trim_start_matches('\u{feff}').trim_start();
+    if body.starts_with("<?xml") {
+        body = body.find("?>").map_or(body, |end| &body[end + 2..]);
+    }
+    quick_xml::de::from_str(&format!("<root>{}</root>", body))
+}
+
+/// 재시도 대기 방식 (테스트에서는 실제로 자지 않고 대기 시간만 기록)
+#[derive(Clone)]
+enum Sleeper {
+    Tokio,
+    #[cfg(test)]
+    Record(Arc<Mutex<Vec<Duration>>>),
+}
+
+impl Sleeper {
+    async fn sleep(&self, delay: Duration) {
+        match self {
+            Self::Tokio => tokio::time::sleep(delay).await,
+            #[cfg(test)]
+            Self::Record(delays) => delays.lock().unwrap().push(delay),
+        }
+    }
+}
+
+/// 응답 본문 디스크 캐시 (개발 중 같은 요청 반복 시 호출 한도 절약), 기본은 꺼짐
+/// 키는 (base_url, path, 정렬한 params)의 해시이며 serviceKey는 제외해 파일명에 키가 남지 않는다
+#[derive(Clone, Debug, Default)]
+pub struct FileCache {
+    /// None이면 캐시 안 함
+    dir: Option<PathBuf>,
+    ttl: Duration,
+}
+
+impl FileCache {
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self { dir: Some(dir.into()), ttl }
+    }
+
+    /// `API_CACHE_DIR`가 없으면 꺼짐
+    pub fn from_config(config: &Config) -> Self {
+        match &config.api_cache_dir {
+            Some(dir) => Self::new(dir, Duration::from_secs(config.api_cache_ttl_secs)),
+            None => Self::disabled(),
+        }
+    }
+
+    /// 캐시 파일 이름 (FNV-1a 64비트, Rust 버전이 바뀌어도 같은 값)
+    fn key(url: &str, params: &[(&str, &str)]) -> String {
+        let mut params: Vec<_> = params.iter().filter(|(k, _)| *k != "serviceKey").collect();
+        params.sort();
+        let parts = params.iter().flat_map(|(k, v)| [k.as_bytes(), v.as_bytes()]);
+        let hash = fnv1a64(std::iter::once(url.as_bytes()).chain(parts));
+        format!("{:016x}.json", hash)
+    }
+
+    /// TTL 안에 저장된 본문
+    fn get(&self, key: &str) -> Option<String> {
+        let path = self.dir.as_ref()?.join(key);
+        let age = std::fs::metadata(&path).ok()?.modified().ok()?.elapsed().ok()?;
+        if age > self.ttl {
+            return None;
+        }
+        std::fs::read_to_string(path).ok()
+    }
+
+    /// 오류 응답(data.go.kr resultCode 00/03 외, VWorld status ERROR)은 저장하지 않는다
+    fn put(&self, key: &str, body: &str) {
+        let Some(dir) = &self.dir else { return };
+        if !is_cacheable(body) {
+            return;
+        }
+        if let Err(e) = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(dir.join(key), body)) {
+            warn!("Failed to write API cache {}: {}", dir.join(key).display(), e);
+        }
+    }
+}
+
+fn is_cacheable(body: &str) -> bool {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else { return false };
+    if let Some(code) = value.pointer("/response/header/resultCode").and_then(|c| c.as_str()) {
+        return matches!(code, "00" | "03");
+    }
+    // VWorld는 response.status (OK / NOT_FOUND / ERROR)
+    match value.pointer("/response/status").and_then(|c| c.as_str()) {
+        Some(status) => status != "ERROR",
+        None => true,
+    }
+}
+
+/// 기본 HTTP 클라이언트 (타임아웃 30초, gzip)
+/// reqwest::Client는 내부 Arc라 clone해도 같은 커넥션 풀을 공유한다
+pub fn build_http_client() -> Client {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .gzip(true)
+        .build()
+        .expect("Failed to create HTTP client")
+}
 
 /// 공통 API 클라이언트 (data.go.kr 등)
 #[derive(Clone)]
 pub struct ApiClient {
     http: Client,
     base_url: String,
-    api_key: String,
+    api_key: ApiKey,
+    /// 키를 싣는 쿼리 파라미터 이름 (data.go.kr은 serviceKey)
+    key_param: &'static str,
+    retry: RetryPolicy,
+    /// jitter 난수원, clone한 클라이언트끼리 공유
+    rng: Arc<Mutex<StdRng>>,
+    sleeper: Sleeper,
+    rate_limiter: RateLimiter,
+    cache: FileCache,
 }
 
 impl ApiClient {
-    pub fn new(base_url: &str, api_key: &str) -> Self {
-        let http = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .gzip(true)
-            .build()
-            .expect("Failed to create HTTP client");
+    pub fn new(base_url: &str, api_key: impl Into<ApiKey>) -> Self {
+        Self::with_http(build_http_client(), base_url, api_key)
+    }
 
+    pub fn with_retry_policy(base_url: &str, api_key: impl Into<ApiKey>, policy: RetryPolicy) -> Self {
+        Self::with_http(build_http_client(), base_url, api_key).retry_policy(policy)
+    }
+
+    /// 외부에서 만든 HTTP 클라이언트 사용 (여러 소스가 커넥션 풀 공유)
+    /// 키는 요청마다 읽으므로 KeyRing 핸들을 넘기면 교체된 키가 바로 반영된다
+    pub fn with_http(http: Client, base_url: &str, api_key: impl Into<ApiKey>) -> Self {
         Self {
             http,
             base_url: base_url.to_string(),
-            api_key: api_key.to_string(),
+            api_key: api_key.into(),
+            key_param: "serviceKey",
+            retry: RetryPolicy::default(),
+            rng: Arc::new(Mutex::new(StdRng::from_entropy())),
+            sleeper: Sleeper::Tokio,
+            rate_limiter: RateLimiter::unlimited(),
+            cache: FileCache::disabled(),
         }
     }
 
+    /// dir에 응답을 ttl 동안 캐시 (개발용), 기본은 캐시 안 함
+    pub fn with_cache(self, dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        self.with_file_cache(FileCache::new(dir, ttl))
+    }
+
+    /// 캐시 설정 공유 (ClientFactory가 모든 소스에 같은 캐시를 건다)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// 요청(재시도 포함)마다 limiter 허가를 받은 뒤 보냄, 기본은 제한 없음
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// jitter 난수 시드 고정 (재현 가능한 재시도 간격)
+    pub fn jitter_seed(mut self, seed: u64) -> Self {
+        self.rng = Arc::new(Mutex::new(StdRng::seed_from_u64(seed)));
+        self
+    }
+
+    /// 키 파라미터 이름 교체 (VWorld는 key)
+    pub fn key_param(mut self, name: &'static str) -> Self {
+        self.key_param = name;
+        self
+    }
+
+    /// 재시도 정책 교체 (공유 HTTP 클라이언트로 만든 뒤 소스별로 조정할 때)
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
     /// GET 요청 with exponential backoff retry
     pub async fn get_json<T: DeserializeOwned>(
         &self,
         path: &str,
         params: &[(&str, &str)],
+    ) -> anyhow::Result<T> {
+        self.request_json(path, params, None).await
+    }
+
+    /// JSON 본문 POST (재시도/호출 제한은 GET과 같고 캐시는 하지 않음)
+    pub async fn post_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        body: &serde_json::Value,
+    ) -> anyhow::Result<T> {
+        self.request_json(path, params, Some(body)).await
+    }
+
+    async fn request_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        json_body: Option<&serde_json::Value>,
     ) -> anyhow::Result<T> {
         let url = format!("{}{}", self.base_url, path);
 
-        let mut all_params: Vec<(&str, &str)> = vec![("serviceKey", &self.api_key)];
+        let cache = if json_body.is_none() { &self.cache } else { &FileCache::disabled() };
+        let cache_key = FileCache::key(&url, params);
+        if let Some(body) = cache.get(&cache_key) {
+            match serde_json::from_str(&body) {
+                Ok(data) => {
+                    info!("API cache hit for {}", path);
+                    return Ok(data);
+                }
+                Err(e) => warn!("Ignoring unreadable API cache for {}: {}", path, e),
+            }
+        }
+
+        let api_key = decode_service_key(&self.api_key.get());
+        let mut all_params: Vec<(&str, &str)> = vec![(self.key_param, &api_key)];
         all_params.extend_from_slice(params);
 
         let mut last_error = None;
+        // 429/503의 Retry-After가 있으면 다음 대기는 백오프 대신 그 값
+        let mut retry_after: Option<Duration> = None;
 
-        for attempt in 0..=MAX_RETRIES {
+        for attempt in 0..=self.retry.max_retries {
             if attempt > 0 {
-                let delay = BASE_BACKOFF_MS * 2u64.pow(attempt - 1);
-                warn!("Retry attempt {}/{} after {}ms", attempt, MAX_RETRIES, delay);
-                tokio::time::sleep(Duration::from_millis(delay)).await;
+                let delay = match retry_after.take() {
+                    Some(delay) => delay,
+                    None => self.retry.retry_delay(attempt, &mut *self.rng.lock().unwrap()),
+                };
+                warn!(
+                    "Retry attempt {}/{} after {}ms",
+                    attempt,
+                    self.retry.max_retries,
+                    delay.as_millis()
+                );
+                self.sleeper.sleep(delay).await;
             }
 
-            match self.http.get(&url).query(&all_params).send().await {
+            self.rate_limiter.acquire().await;
+            let request = match json_body {
+                Some(body) => self.http.post(&url).json(body),
+                None => self.http.get(&url),
+            };
+            match request.query(&all_params).send().await {
                 Ok(resp) => {
                     if resp.status().is_success() {
-                        match resp.json::<T>().await {
-                            Ok(data) => return Ok(data),
+                        let xml = resp
+                            .headers()
+                            .get(CONTENT_TYPE)
+                            .and_then(|v| v.to_str().ok())
+                            .is_some_and(|v| v.contains("xml"));
+                        match resp.text().await {
+                            Ok(body) if xml || body.trim_start().starts_with('<') => {
+                                // 키/권한 오류는 재시도해도 같으므로 바로 반환
+                                if let Some(e) = openapi_service_error(&body) {
+                                    return Err(e);
+                                }
+                                match parse_xml::<T>(&body) {
+                                    Ok(data) => return Ok(data),
+                                    Err(e) => {
+                                        last_error = Some(anyhow::anyhow!("XML parse error: {}", e));
+                                    }
+                                }
+                            }
+                            Ok(body) => match serde_json::from_str::<T>(&body) {
+                                Ok(data) => {
+                                    cache.put(&cache_key, &body);
+                                    return Ok(data);
+                                }
+                                Err(e) => {
+                                    last_error = Some(anyhow::anyhow!("JSON parse error: {}", e));
+                                }
+                            },
                             Err(e) => {
-                                last_error = Some(anyhow::anyhow!("JSON parse error: {}", e));
+                                last_error = Some(anyhow::anyhow!("Request error: {}", e));
                             }
                         }
                     } else {
                         let status = resp.status();
+                        if matches!(status, StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE) {
+                            retry_after = resp
+                                .headers()
+                                .get(RETRY_AFTER)
+                                .and_then(|v| v.to_str().ok())
+                                .and_then(|v| parse_retry_after(v, SystemTime::now()));
+                        }
                         let body = resp.text().await.unwrap_or_default();
                         last_error =
                             Some(anyhow::anyhow!("HTTP {} - {}", status, &body[..body.len().min(200)]));
@@ -74,6 +470,64 @@ impl ApiClient {
         Err(last_error.unwrap_or_else(|| anyhow::anyhow!("Unknown error")))
     }
 
+    /// pageNo 페이지 1건 요청
+    async fn get_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        base_params: &[(&str, String)],
+        page: u32,
+        page_size: u32,
+    ) -> anyhow::Result<T> {
+        let page_str = page.to_string();
+        let size_str = page_size.to_string();
+
+        let mut params: Vec<(&str, &str)> = base_params
+            .iter()
+            .map(|(k, v)| (*k, v.as_str()))
+            .collect();
+        params.push(("pageNo", &page_str));
+        params.push(("numOfRows", &size_str));
+        params.push(("type", "json"));
+
+        self.get_json(path, &params).await
+    }
+
+    /// 페이지 단위 스트림, 한 페이지씩 받는 대로 내보내므로 전체를 메모리에 모으지 않는다
+    /// total_count를 다 받았거나 빈 페이지가 오면 끝, 오류가 나면 그 오류를 내고 끝
+    pub fn fetch_pages_stream<'a, T, F, R>(
+        &'a self,
+        path: &'a str,
+        base_params: Vec<(&'a str, String)>,
+        page_size: u32,
+        extract_items: F,
+    ) -> impl Stream<Item = anyhow::Result<Vec<R>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        F: Fn(T) -> anyhow::Result<(Vec<R>, u32)> + 'a, // (items, total_count)
+        R: Send + 'a,
+    {
+        let extract_items = Arc::new(extract_items);
+        let base_params = Arc::new(base_params);
+        stream::try_unfold((1u32, u32::MAX), move |(page, total_count)| {
+            let extract_items = extract_items.clone();
+            let base_params = base_params.clone();
+            async move {
+                if u64::from(page - 1) * u64::from(page_size) >= u64::from(total_count) {
+                    return Ok(None);
+                }
+                let response: T = self.get_page(path, &base_params, page, page_size).await?;
+                let (items, total) = extract_items(response)?;
+
+                info!("Page {}: fetched {} items (total: {})", page, items.len(), total);
+
+                if items.is_empty() {
+                    return Ok(None);
+                }
+                Ok(Some((items, (page + 1, total))))
+            }
+        })
+    }
+
     /// 페이징 처리된 전량 수집
     pub async fn fetch_all_pages<T, F, R>(
         &self,
@@ -84,43 +538,318 @@ impl ApiClient {
     ) -> anyhow::Result<Vec<R>>
     where
         T: DeserializeOwned,
-        F: Fn(T) -> (Vec<R>, u32), // (items, total_count)
+        F: Fn(T) -> anyhow::Result<(Vec<R>, u32)>, // (items, total_count)
         R: Send,
     {
-        let mut all_items = Vec::new();
-        let mut page = 1u32;
-        let mut total_count = u32::MAX;
-
-        while (page - 1) * page_size < total_count {
-            let page_str = page.to_string();
-            let size_str = page_size.to_string();
-
-            let mut params: Vec<(&str, &str)> = base_params
-                .iter()
-                .map(|(k, v)| (*k, v.as_str()))
-                .collect();
-            params.push(("pageNo", &page_str));
-            params.push(("numOfRows", &size_str));
-            params.push(("type", "json"));
-
-            let response: T = self.get_json(path, &params).await?;
-            let (items, total) = extract_items(response);
-
-            total_count = total;
-            let count = items.len();
-            all_items.extend(items);
-
-            info!(
-                "Page {}: fetched {} items (total: {})",
-                page, count, total_count
-            );
-
-            if count == 0 {
-                break;
+        self.fetch_pages_stream(path, base_params.to_vec(), page_size, extract_items)
+            .try_concat()
+            .await
+    }
+
+    /// 첫 페이지로 total_count를 확인한 뒤 나머지 페이지를 최대 concurrency개씩 동시에 요청
+    /// 결과는 페이지 순서대로 합친다. 요청마다 호출 제한 토큰을 받으므로 동시 요청이 늘어도 초당 예산은 넘지 않는다
+    pub async fn fetch_all_pages_concurrent<T, F, R>(
+        &self,
+        path: &str,
+        base_params: &[(&str, String)],
+        page_size: u32,
+        concurrency: usize,
+        extract_items: F,
+    ) -> anyhow::Result<Vec<R>>
+    where
+        T: DeserializeOwned,
+        F: Fn(T) -> anyhow::Result<(Vec<R>, u32)>, // (items, total_count)
+    {
+        let first: T = self.get_page(path, base_params, 1, page_size).await?;
+        let (items, total) = extract_items(first)?;
+        info!("Page 1: fetched {} items (total: {})", items.len(), total);
+        if items.is_empty() {
+            return Ok(items);
+        }
+
+        let page_count = total.div_ceil(page_size.max(1)).max(1);
+        let mut pages: Vec<Option<Vec<R>>> = (1..page_count).map(|_| None).collect();
+        let mut rest = stream::iter(2..=page_count)
+            .map(|page| async move {
+                let response: T = self.get_page(path, base_params, page, page_size).await?;
+                anyhow::Ok((page, response))
+            })
+            .buffer_unordered(concurrency.max(1));
+
+        while let Some((page, response)) = rest.try_next().await? {
+            let (page_items, _) = extract_items(response)?;
+            info!("Page {}: fetched {} items (total: {})", page, page_items.len(), total);
+            pages[(page - 2) as usize] = Some(page_items);
+        }
+
+        let mut all = items;
+        all.extend(pages.into_iter().flatten().flatten());
+        Ok(all)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// 요청마다 responses를 차례로(마지막은 반복) 돌려주는 로컬 HTTP 서버, 반환: (base_url, 요청 수)
+    async fn serve(responses: Vec<String>) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let body = &responses[n.min(responses.len() - 1)];
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket.write_all(body.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+        (base_url, hits)
+    }
+
+    const SERVER_ERROR: &str =
+        "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    fn json_ok(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_clamps() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_backoff: Duration::from_secs(2),
+            max_backoff: Duration::from_secs(10),
+            jitter: false,
+        };
+        let delays: Vec<u64> = (1..=5).map(|a| policy.backoff(a).as_secs()).collect();
+        assert_eq!(delays, [2, 4, 8, 10, 10]);
+        assert_eq!(policy.backoff(40), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_check_result_code() {
+        assert!(check_result_code("00", Some("NORMAL SERVICE.")).is_ok());
+        assert!(check_result_code("03", Some("NODATA_ERROR")).is_ok());
+
+        let err = check_result_code("30", Some("SERVICE_KEY_IS_NOT_REGISTERED_ERROR")).unwrap_err();
+        assert!(!is_quota_exceeded(&err));
+        assert!(err.to_string().contains("service key not registered"));
+
+        let err = check_result_code("22", None).unwrap_err();
+        assert!(is_quota_exceeded(&err));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = httpdate::parse_http_date("Wed, 21 Oct 2026 07:28:00 GMT").unwrap();
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2026 07:00:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(parse_retry_after("-5", now), None);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_overrides_backoff() {
+        let (base_url, _) = serve(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 7\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".into(),
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: later\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".into(),
+            json_ok("{}"),
+        ])
+        .await;
+        let policy = RetryPolicy { jitter: false, ..RetryPolicy::default() };
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let mut client = ApiClient::with_retry_policy(&base_url, "key", policy);
+        client.sleeper = Sleeper::Record(delays.clone());
+
+        assert!(client.get_json::<serde_json::Value>("/", &[]).await.is_ok());
+        // 헤더 값 7초, 해석 불가한 헤더는 두 번째 백오프(4초)
+        assert_eq!(*delays.lock().unwrap(), [Duration::from_secs(7), policy.backoff(2)]);
+    }
+
+    #[tokio::test]
+    async fn test_zero_retries_means_single_attempt() {
+        let (base_url, hits) = serve(vec![SERVER_ERROR.into()]).await;
+        let policy = RetryPolicy { max_retries: 0, ..RetryPolicy::default() };
+        let client = ApiClient::with_retry_policy(&base_url, "key", policy);
+
+        let result = client.get_json::<serde_json::Value>("/", &[]).await;
+        assert!(result.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_jittered_delays_stay_within_bounds() {
+        let (base_url, hits) = serve(vec![SERVER_ERROR.into()]).await;
+        let policy = RetryPolicy {
+            max_retries: 100,
+            base_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+        };
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let mut client = ApiClient::with_retry_policy(&base_url, "key", policy).jitter_seed(42);
+        client.sleeper = Sleeper::Record(delays.clone());
+
+        assert!(client.get_json::<serde_json::Value>("/", &[]).await.is_err());
+        assert_eq!(hits.load(Ordering::SeqCst), 101);
+
+        let delays = delays.lock().unwrap();
+        assert_eq!(delays.len(), 100);
+        for (i, delay) in delays.iter().enumerate() {
+            let max = policy.backoff(i as u32 + 1);
+            assert!(*delay >= max / 2 && *delay <= max, "attempt {}: {:?} vs {:?}", i + 1, delay, max);
+        }
+        // 같은 시드면 같은 간격
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(delays[0], policy.retry_delay(1, &mut rng));
+    }
+
+    #[tokio::test]
+    async fn test_pages_stream_yields_each_page() {
+        let page = |items: &str| json_ok(&format!(r#"{{"items": [{}], "total": 5}}"#, items));
+        let (base_url, hits) = serve(vec![page("1, 2"), page("3, 4"), page("5")]).await;
+        let client = ApiClient::new(&base_url, "key");
+
+        #[derive(serde::Deserialize)]
+        struct Page {
+            items: Vec<u32>,
+            total: u32,
+        }
+        let pages: Vec<Vec<u32>> = client
+            .fetch_pages_stream("/", vec![], 2, |p: Page| Ok((p.items, p.total)))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages, [vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    /// pageNo를 그대로 item으로 돌려주는 동시 처리 서버 (응답 전 delay 대기), 반환: (base_url, 최대 동시 요청 수)
+    async fn serve_pages(total: u32, delay: Duration) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let max = max_in_flight.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let (in_flight, max) = (in_flight.clone(), max.clone());
+                tokio::spawn(async move {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(now, Ordering::SeqCst);
+                    let mut buf = [0u8; 4096];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let page = request
+                        .split(['?', '&', ' '])
+                        .find_map(|kv| kv.strip_prefix("pageNo="))
+                        .unwrap_or("0");
+                    tokio::time::sleep(delay).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let body = json_ok(&format!(r#"{{"items": [{}], "total": {}}}"#, page, total));
+                    let _ = socket.write_all(body.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
             }
-            page += 1;
+        });
+        (base_url, max_in_flight)
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_pages_bounded_and_ordered() {
+        #[derive(serde::Deserialize)]
+        struct Page {
+            items: Vec<u32>,
+            total: u32,
         }
+        let (base_url, max_in_flight) = serve_pages(12, Duration::from_millis(50)).await;
+        let client = ApiClient::new(&base_url, "key");
+
+        let items = client
+            .fetch_all_pages_concurrent("/", &[], 1, 3, |p: Page| Ok((p.items, p.total)))
+            .await
+            .unwrap();
+        assert_eq!(items, (1..=12).collect::<Vec<u32>>());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+
+        // burst 1짜리 호출 제한이면 동시 한도가 커도 요청이 겹치지 않는다
+        let (base_url, max_in_flight) = serve_pages(4, Duration::from_millis(5)).await;
+        let client = ApiClient::new(&base_url, "key").with_rate_limiter(RateLimiter::new(20.0, 1));
+        let items = client
+            .fetch_all_pages_concurrent("/", &[], 1, 4, |p: Page| Ok((p.items, p.total)))
+            .await
+            .unwrap();
+        assert_eq!(items, [1, 2, 3, 4]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_cache_key_ignores_service_key_and_order() {
+        let key = FileCache::key("http://x/a", &[("b", "2"), ("a", "1")]);
+        assert_eq!(key, FileCache::key("http://x/a", &[("a", "1"), ("serviceKey", "secret"), ("b", "2")]));
+        assert_ne!(key, FileCache::key("http://x/b", &[("a", "1"), ("b", "2")]));
+        assert!(!key.contains("secret"));
+        // 파일 이름이 바뀌면 기존 캐시를 못 쓰므로 값 고정
+        assert_eq!(key, "6a3703c1f51e7f13.json");
+    }
+
+    #[tokio::test]
+    async fn test_file_cache_skips_repeat_requests() {
+        let dir = std::env::temp_dir().join(format!("kiep-cache-test-{}", std::process::id()));
+        let quota = r#"{"response":{"header":{"resultCode":"22","resultMsg":"LIMITED"}}}"#;
+        let (base_url, hits) = serve(vec![json_ok(quota), json_ok(r#"{"n": 1}"#)]).await;
+        let client = ApiClient::new(&base_url, "key").with_cache(&dir, Duration::from_secs(60));
+
+        // 오류 응답은 저장하지 않으므로 두 번째 요청은 서버로 간다
+        client.get_json::<serde_json::Value>("/", &[("q", "1")]).await.unwrap();
+        let first: serde_json::Value = client.get_json("/", &[("q", "1")]).await.unwrap();
+        let cached: serde_json::Value = client.get_json("/", &[("q", "1")]).await.unwrap();
+        assert_eq!(first, cached);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_decode_service_key() {
+        assert_eq!(decode_service_key("abc%2Bdef%3D%3D"), "abc+def==");
+        assert_eq!(decode_service_key("abc+def=="), "abc+def==");
+        // %23처럼 보여도 뒤가 hex가 아니면 원문 그대로
+        assert_eq!(decode_service_key("100%zz"), "100%zz");
+    }
+
+    #[tokio::test]
+    async fn test_service_key_error_is_not_retried() {
+        let body = "<OpenAPI_ServiceResponse><cmmMsgHeader><errMsg>SERVICE ERROR</errMsg>\
+            <returnAuthMsg>SERVICE_KEY_IS_NOT_REGISTERED_ERROR</returnAuthMsg>\
+            <returnReasonCode>30</returnReasonCode></cmmMsgHeader></OpenAPI_ServiceResponse>";
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (base_url, hits) = serve(vec![response]).await;
+        let client = ApiClient::new(&base_url, "abc%2Bdef");
 
-        Ok(all_items)
+        let err = client.get_json::<serde_json::Value>("/", &[]).await.unwrap_err();
+        assert!(err.to_string().contains("service key not registered"));
+        assert!(err.to_string().contains("Encoding"));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
     }
 }
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use tracing::warn;
+
+/// 숫자 또는 "85.3%", "1,234.5", " 12 " 같은 문자열을 f64로
+/// 빈 문자열/"-"/null은 None, 해석할 수 없는 문자열은 경고 후 None
+/// `#[serde(default, deserialize_with = "super::de::lenient_f64")]`로 사용
+pub fn lenient_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientF64)
+}
+
+/// 문자열 숫자 정리: 공백, 천 단위 구분자, 끝의 '%' 제거
+pub fn parse_lenient_f64(raw: &str) -> Option<f64> {
+    let cleaned: String = raw
+        .trim()
+        .trim_end_matches('%')
+        .chars()
+        .filter(|c| *c != ',' && !c.is_whitespace())
+        .collect();
+    if cleaned.is_empty() || cleaned == "-" {
+        return None;
+    }
+    match cleaned.parse::<f64>() {
+        Ok(v) if v.is_finite() => Some(v),
+        _ => {
+            warn!("Unparseable numeric value '{}', treating as missing", raw);
+            None
+        }
+    }
+}
+
+struct LenientF64;
+
+impl<'de> Visitor<'de> for LenientF64 {
+    type Value = Option<f64>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or numeric string")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Some(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Some(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Some(v as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(parse_lenient_f64(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_any(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lenient_f64() {
+        assert_eq!(parse_lenient_f64("85.3%"), Some(85.3));
+        assert_eq!(parse_lenient_f64("85.3 %"), Some(85.3));
+        assert_eq!(parse_lenient_f64("1,234.5"), Some(1234.5));
+        assert_eq!(parse_lenient_f64(" 12 "), Some(12.0));
+        assert_eq!(parse_lenient_f64("-0.5"), Some(-0.5));
+        assert_eq!(parse_lenient_f64(""), None);
+        assert_eq!(parse_lenient_f64("-"), None);
+        assert_eq!(parse_lenient_f64("N/A"), None);
+    }
+}
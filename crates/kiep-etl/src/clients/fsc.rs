@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
-use super::common::ApiClient;
+use crate::transform::normalize;
+
+use super::common::{check_result_code, ApiClient, FileCache};
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
 
 const FSC_BASE_URL: &str = "https://apis.data.go.kr/1160100/service/GetFinaStatInfoService_V2";
 
@@ -25,6 +29,8 @@ pub struct FscResponseBody {
 pub struct FscHeader {
     #[serde(rename = "resultCode")]
     pub result_code: String,
+    #[serde(rename = "resultMsg", default)]
+    pub result_msg: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -42,7 +48,7 @@ pub struct FscItems {
 /// 재무제표 항목
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FscFinancial {
-    /// 법인등록번호
+    /// 법인등록번호 (수집 시 normalize_corp_no로 13자리 정규화)
     #[serde(rename = "crno", default)]
     pub corp_no: String,
     /// 회사명
@@ -51,6 +57,9 @@ pub struct FscFinancial {
     /// 결산기준일
     #[serde(rename = "fnlttSinglAcntDt", default)]
     pub account_date: String,
+    /// 재무제표구분명 (연결요약재무제표/별도요약재무제표)
+    #[serde(rename = "fnclDcdNm", default)]
+    pub statement_name: String,
     /// 계정과목명
     #[serde(rename = "fnlttSinglAcntNm", default)]
     pub account_name: String,
@@ -63,22 +72,44 @@ pub struct FscFinancial {
 }
 
 impl FscClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
         Self {
             client: ApiClient::new(FSC_BASE_URL, api_key),
         }
     }
 
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self {
+            client: ApiClient::with_http(http, FSC_BASE_URL, api_key),
+        }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
     /// 법인등록번호로 재무제표 조회
     pub async fn fetch_financials(
         &self,
         corp_no: &str,
         fiscal_year: &str,
     ) -> anyhow::Result<Vec<FscFinancial>> {
+        let corp_no = normalize::normalize_corp_no(corp_no);
+        if !normalize::validate_corp_no(&corp_no) {
+            warn!("corp_no {} fails checksum, querying FSC anyway", corp_no);
+        }
         info!("Fetching FSC financials for corp_no={} year={}", corp_no, fiscal_year);
 
-        let params = vec![
-            ("crno", corp_no.to_string()),
+        let params = [
+            ("crno", corp_no),
             ("bizYear", fiscal_year.to_string()),
         ];
         let base_params: Vec<(&str, String)> = params
@@ -92,12 +123,18 @@ impl FscClient {
                 &base_params,
                 100,
                 |resp: FscResponse| {
+                    let header = &resp.response.header;
+                    check_result_code(&header.result_code, header.result_msg.as_deref())?;
                     let total = resp.response.body.as_ref().map(|b| b.total_count).unwrap_or(0);
-                    let items = resp.response.body
+                    let mut items: Vec<FscFinancial> = resp.response.body
                         .and_then(|b| b.items)
                         .map(|i| i.item)
                         .unwrap_or_default();
-                    (items, total)
+                    // companies.corp_no와 같은 형식으로 맞춰 둔다
+                    for item in &mut items {
+                        item.corp_no = normalize::normalize_corp_no(&item.corp_no);
+                    }
+                    Ok((items, total))
                 },
             )
             .await
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use kiep_core::config::{self, Config};
+use tracing::{info, warn};
+
+/// 교체 가능한 API 키, clone한 핸들은 같은 값을 공유한다
+#[derive(Clone, Debug)]
+pub struct ApiKey(Arc<RwLock<String>>);
+
+impl ApiKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(Arc::new(RwLock::new(key.into())))
+    }
+
+    /// 현재 키 (요청마다 읽으므로 교체 후 다음 요청부터 반영)
+    pub fn get(&self) -> String {
+        self.0.read().unwrap().clone()
+    }
+
+    /// 키 교체, 반환값: 값이 바뀌었는지
+    pub fn replace(&self, key: String) -> bool {
+        let mut current = self.0.write().unwrap();
+        if *current == key {
+            return false;
+        }
+        *current = key;
+        true
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(key: &str) -> Self {
+        Self::new(key)
+    }
+}
+
+impl From<&String> for ApiKey {
+    fn from(key: &String) -> Self {
+        Self::new(key.as_str())
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(key: String) -> Self {
+        Self::new(key)
+    }
+}
+
+/// 소스별 API 키 핸들, 시작 시 설정된 키만 교체 대상
+/// 키 교체: .env 또는 `<변수>_FILE` 파일을 고친 뒤 프로세스에 SIGHUP (`reload_on_sighup`)
+#[derive(Clone, Debug, Default)]
+pub struct KeyRing {
+    pub nps: Option<ApiKey>,
+    pub nts: Option<ApiKey>,
+    pub fsc: Option<ApiKey>,
+    pub pps: Option<ApiKey>,
+    pub kicox: Option<ApiKey>,
+    pub vworld: Option<ApiKey>,
+}
+
+impl KeyRing {
+    pub fn from_config(config: &Config) -> Self {
+        let key = |k: &Option<String>| k.as_deref().map(ApiKey::from);
+        Self {
+            nps: key(&config.nps_api_key),
+            nts: key(&config.nts_api_key),
+            fsc: key(&config.fsc_api_key),
+            pps: key(&config.pps_api_key),
+            kicox: key(&config.kicox_api_key),
+            vworld: key(&config.vworld_api_key),
+        }
+    }
+
+    fn entries(&self) -> [(&'static str, Option<&ApiKey>); 6] {
+        [
+            (config::NPS_API_KEY_VAR, self.nps.as_ref()),
+            (config::NTS_API_KEY_VAR, self.nts.as_ref()),
+            (config::FSC_API_KEY_VAR, self.fsc.as_ref()),
+            (config::PPS_API_KEY_VAR, self.pps.as_ref()),
+            (config::KICOX_API_KEY_VAR, self.kicox.as_ref()),
+            (config::VWORLD_API_KEY_VAR, self.vworld.as_ref()),
+        ]
+    }
+
+    /// .env와 키 파일을 다시 읽어 키 교체, 반환값: 바뀐 변수 이름
+    /// 새 값이 비어 있으면 기존 키 유지, 프로세스 환경 변수는 바꾸지 않는다
+    pub fn reload(&self) -> Vec<&'static str> {
+        self.reload_from(&config::read_dotenv())
+    }
+
+    /// vars(.env 내용)를 환경 변수보다 우선해 키 교체
+    fn reload_from(&self, vars: &HashMap<String, String>) -> Vec<&'static str> {
+        self.entries()
+            .into_iter()
+            .filter_map(|(var, key)| {
+                let key = key?;
+                let new = config::read_api_key_from(var, vars)?;
+                key.replace(new).then_some(var)
+            })
+            .collect()
+    }
+
+    /// SIGHUP을 받을 때마다 reload (tokio 런타임 안에서 호출)
+    #[cfg(unix)]
+    pub fn reload_on_sighup(&self) -> std::io::Result<tokio::task::JoinHandle<()>> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut hangup = signal(SignalKind::hangup())?;
+        let keys = self.clone();
+        Ok(tokio::spawn(async move {
+            while hangup.recv().await.is_some() {
+                let changed = keys.reload();
+                if changed.is_empty() {
+                    warn!("SIGHUP received but no API key changed");
+                } else {
+                    info!("Reloaded API keys: {}", changed.join(", "));
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloned_key_sees_replacement() {
+        let key = ApiKey::from("old");
+        let held_by_client = key.clone();
+
+        assert!(key.replace("new".into()));
+        assert!(!key.replace("new".into()));
+        assert_eq!(held_by_client.get(), "new");
+    }
+
+    #[test]
+    fn test_reload_from_dotenv_vars_leaves_process_env() {
+        let keys = KeyRing { nps: Some(ApiKey::from("old")), ..Default::default() };
+        let vars = HashMap::from([
+            (config::NPS_API_KEY_VAR.to_string(), " rotated ".to_string()),
+            // 시작 시 설정되지 않은 키는 교체 대상이 아님
+            (config::FSC_API_KEY_VAR.to_string(), "fsc".to_string()),
+        ]);
+
+        assert_eq!(keys.reload_from(&vars), [config::NPS_API_KEY_VAR]);
+        assert_eq!(keys.nps.as_ref().unwrap().get(), "rotated");
+        assert!(keys.fsc.is_none());
+        assert_ne!(std::env::var(config::NPS_API_KEY_VAR).ok().as_deref(), Some(" rotated "));
+        assert!(keys.reload_from(&vars).is_empty());
+    }
+}
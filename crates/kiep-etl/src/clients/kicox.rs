@@ -1,42 +1,80 @@
+use kiep_core::models::ComplexSeriesPoint;
+use kiep_core::period::YearQuarter;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use super::common::ApiClient;
+use super::common::{check_result_code, ApiClient, FileCache};
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
+use super::de::lenient_f64;
 
 const KICOX_BASE_URL: &str = "https://apis.data.go.kr/B553804/IndustrialComplexService";
+/// 전체 목록 수집 시 동시 페이지 요청 수
+const KICOX_PAGE_CONCURRENCY: usize = 4;
 
 /// KICOX 산업단지 클라이언트
 pub struct KicoxClient {
     client: ApiClient,
 }
 
+/// 목록 응답, 항목은 산업단지(기본) 또는 입주기업
 #[derive(Debug, Deserialize)]
-pub struct KicoxResponse {
-    pub response: KicoxResponseBody,
+pub struct KicoxResponse<T = KicoxComplex> {
+    pub response: KicoxResponseBody<T>,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct KicoxResponseBody {
+pub struct KicoxResponseBody<T = KicoxComplex> {
     pub header: KicoxHeader,
-    pub body: Option<KicoxBody>,
+    pub body: Option<KicoxBody<T>>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct KicoxHeader {
     #[serde(rename = "resultCode")]
     pub result_code: String,
+    #[serde(rename = "resultMsg", default)]
+    pub result_msg: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct KicoxBody {
-    pub items: Option<KicoxItems>,
+pub struct KicoxBody<T = KicoxComplex> {
+    pub items: Option<KicoxItems<T>>,
     #[serde(rename = "totalCount")]
     pub total_count: u32,
 }
 
 #[derive(Debug, Deserialize)]
-pub struct KicoxItems {
-    pub item: Vec<KicoxComplex>,
+pub struct KicoxItems<T = KicoxComplex> {
+    pub item: Vec<T>,
+}
+
+impl<T> KicoxResponse<T> {
+    /// 한 페이지 항목과 전체 건수, 오류 코드면 오류
+    fn into_page(self) -> anyhow::Result<(Vec<T>, u32)> {
+        let header = &self.response.header;
+        check_result_code(&header.result_code, header.result_msg.as_deref())?;
+        let total = self.response.body.as_ref().map(|b| b.total_count).unwrap_or(0);
+        let items = self.response.body
+            .and_then(|b| b.items)
+            .map(|i| i.item)
+            .unwrap_or_default();
+        Ok((items, total))
+    }
+}
+
+/// 산업단지 입주기업
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KicoxTenant {
+    /// 단지코드
+    #[serde(rename = "cmplxCd", default)]
+    pub complex_code: String,
+    /// 사업자등록번호 (10자리, 하이픈 포함일 수 있음)
+    #[serde(rename = "bizrno", default)]
+    pub biz_no: String,
+    /// 업체명
+    #[serde(rename = "entrpsNm", default)]
+    pub name: String,
 }
 
 /// 산업단지 정보
@@ -58,10 +96,10 @@ pub struct KicoxComplex {
     #[serde(rename = "sggNm", default)]
     pub sigungu: String,
     /// 지정면적(천㎡)
-    #[serde(rename = "dsgAr", default)]
+    #[serde(rename = "dsgAr", default, deserialize_with = "lenient_f64")]
     pub designated_area: Option<f64>,
     /// 산업용지면적(천㎡)
-    #[serde(rename = "idstAr", default)]
+    #[serde(rename = "idstAr", default, deserialize_with = "lenient_f64")]
     pub industrial_area: Option<f64>,
     /// 입주업체수
     #[serde(rename = "mvnFrmCnt", default)]
@@ -70,7 +108,7 @@ pub struct KicoxComplex {
     #[serde(rename = "oprtFrmCnt", default)]
     pub operating_count: Option<u32>,
     /// 분양률(%)
-    #[serde(rename = "lttotRt", default)]
+    #[serde(rename = "lttotRt", default, deserialize_with = "lenient_f64")]
     pub occupancy_rate: Option<f64>,
     /// 생산액(백만원)
     #[serde(rename = "prdcAmt", default)]
@@ -81,15 +119,55 @@ pub struct KicoxComplex {
     /// 고용인원
     #[serde(rename = "emplCnt", default)]
     pub employment: Option<u32>,
+    /// 기준년월 또는 기준분기 ('YYYYMM' / 'YYYYQ')
+    #[serde(rename = "stdrYm", default)]
+    pub base_period: String,
+}
+
+impl KicoxComplex {
+    /// 통계 기준 분기, 기준 시점이 없거나 형식을 알 수 없으면 None
+    pub fn year_quarter(&self) -> Option<YearQuarter> {
+        YearQuarter::parse_flexible(&self.base_period).ok()
+    }
+
+    /// 분기 시계열 행으로 변환 (기준 분기가 없으면 None)
+    pub fn to_series_point(&self) -> Option<ComplexSeriesPoint> {
+        Some(ComplexSeriesPoint {
+            complex_id: self.complex_code.clone(),
+            year_quarter: self.year_quarter()?,
+            production: self.production,
+            export_amount: self.export_amount,
+            employment: self.employment.map(|v| v as i32),
+            operating_count: self.operating_count.map(|v| v as i32),
+        })
+    }
 }
 
 impl KicoxClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
         Self {
             client: ApiClient::new(KICOX_BASE_URL, api_key),
         }
     }
 
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self {
+            client: ApiClient::with_http(http, KICOX_BASE_URL, api_key),
+        }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
     /// 전체 산업단지 목록 조회
     pub async fn fetch_all_complexes(&self) -> anyhow::Result<Vec<KicoxComplex>> {
         info!("Fetching all KICOX industrial complexes");
@@ -97,18 +175,12 @@ impl KicoxClient {
         let base_params: Vec<(&str, String)> = vec![];
 
         self.client
-            .fetch_all_pages(
+            .fetch_all_pages_concurrent(
                 "/getIndustrialComplexList",
                 &base_params,
                 100,
-                |resp: KicoxResponse| {
-                    let total = resp.response.body.as_ref().map(|b| b.total_count).unwrap_or(0);
-                    let items = resp.response.body
-                        .and_then(|b| b.items)
-                        .map(|i| i.item)
-                        .unwrap_or_default();
-                    (items, total)
-                },
+                KICOX_PAGE_CONCURRENCY,
+                |resp: KicoxResponse| resp.into_page(),
             )
             .await
     }
@@ -124,15 +196,77 @@ impl KicoxClient {
                 "/getIndustrialComplexList",
                 &base_params,
                 100,
-                |resp: KicoxResponse| {
-                    let total = resp.response.body.as_ref().map(|b| b.total_count).unwrap_or(0);
-                    let items = resp.response.body
-                        .and_then(|b| b.items)
-                        .map(|i| i.item)
-                        .unwrap_or_default();
-                    (items, total)
-                },
+                |resp: KicoxResponse| resp.into_page(),
+            )
+            .await
+    }
+
+    /// 산업단지 입주기업 목록 조회
+    pub async fn fetch_tenants(&self, complex_code: &str) -> anyhow::Result<Vec<KicoxTenant>> {
+        info!("Fetching KICOX tenants for complex={}", complex_code);
+
+        let base_params: Vec<(&str, String)> = vec![("cmplxCd", complex_code.to_string())];
+
+        self.client
+            .fetch_all_pages(
+                "/getIndustrialComplexTenantList",
+                &base_params,
+                100,
+                |resp: KicoxResponse<KicoxTenant>| resp.into_page(),
             )
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_point_from_kicox_period() {
+        let raw = r#"{"cmplxCd": "A001", "cmplxNm": "오창과학", "stdrYm": "202406", "prdcAmt": 1200, "emplCnt": 300}"#;
+        let complex: KicoxComplex = serde_json::from_str(raw).unwrap();
+
+        let point = complex.to_series_point().unwrap();
+        assert_eq!(point.complex_id, "A001");
+        assert_eq!(point.year_quarter.to_string(), "2024-Q2");
+        assert_eq!(point.production, Some(1200));
+        assert_eq!(point.employment, Some(300));
+
+        let undated: KicoxComplex = serde_json::from_str(r#"{"cmplxCd": "A002"}"#).unwrap();
+        assert!(undated.to_series_point().is_none());
+    }
+
+    #[test]
+    fn test_tenant_page() {
+        let raw = r#"{"response": {
+            "header": {"resultCode": "00"},
+            "body": {"totalCount": 1, "items": {"item": [
+                {"cmplxCd": "A001", "bizrno": "220-81-62517", "entrpsNm": "오창정밀"}
+            ]}}
+        }}"#;
+        let resp: KicoxResponse<KicoxTenant> = serde_json::from_str(raw).unwrap();
+        let (tenants, total) = resp.into_page().unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(tenants[0].complex_code, "A001");
+        assert_eq!(tenants[0].biz_no, "220-81-62517");
+    }
+
+    #[test]
+    fn test_messy_rates_and_areas() {
+        let raw = r#"{
+            "cmplxCd": "A001", "dsgAr": "1,234.5", "idstAr": 987.1, "lttotRt": "85.3%"
+        }"#;
+        let complex: KicoxComplex = serde_json::from_str(raw).unwrap();
+        assert_eq!(complex.designated_area, Some(1234.5));
+        assert_eq!(complex.industrial_area, Some(987.1));
+        assert_eq!(complex.occupancy_rate, Some(85.3));
+
+        let blank: KicoxComplex =
+            serde_json::from_str(r#"{"cmplxCd": "A002", "dsgAr": "", "idstAr": null, "lttotRt": "-"}"#)
+                .unwrap();
+        assert_eq!(blank.designated_area, None);
+        assert_eq!(blank.industrial_area, None);
+        assert_eq!(blank.occupancy_rate, None);
+    }
+}
@@ -1,8 +1,83 @@
 pub mod common;
+pub mod de;
 pub mod fsc;
+pub mod keys;
 pub mod kicox;
 pub mod nps;
 pub mod nts;
 pub mod pps;
+pub mod rate_limit;
+pub mod vworld;
 
-pub use common::ApiClient;
+pub use common::{ApiClient, FileCache};
+pub use keys::{ApiKey, KeyRing};
+pub use rate_limit::RateLimiter;
+
+/// 모든 소스 클라이언트를 하나의 HTTP 클라이언트로 생성
+/// data.go.kr은 같은 호스트라 다중 소스 백필 시 keep-alive 커넥션을 재사용한다
+/// 호출 제한도 공유해 모든 소스가 하나의 예산 안에서 요청한다
+/// 응답 캐시는 소스마다 URL이 달라 한 디렉터리를 같이 써도 겹치지 않는다
+#[derive(Clone)]
+pub struct ClientFactory {
+    http: reqwest::Client,
+    rate_limiter: RateLimiter,
+    cache: FileCache,
+}
+
+impl Default for ClientFactory {
+    fn default() -> Self {
+        Self::from_http(common::build_http_client())
+    }
+}
+
+impl ClientFactory {
+    pub fn from_http(http: reqwest::Client) -> Self {
+        Self { http, rate_limiter: RateLimiter::unlimited(), cache: FileCache::disabled() }
+    }
+
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    pub fn nps(&self, api_key: impl Into<ApiKey>) -> nps::NpsClient {
+        nps::NpsClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+
+    pub fn nts(&self, api_key: impl Into<ApiKey>) -> nts::NtsClient {
+        nts::NtsClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+
+    pub fn fsc(&self, api_key: impl Into<ApiKey>) -> fsc::FscClient {
+        fsc::FscClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+
+    pub fn pps(&self, api_key: impl Into<ApiKey>) -> pps::PpsClient {
+        pps::PpsClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+
+    pub fn kicox(&self, api_key: impl Into<ApiKey>) -> kicox::KicoxClient {
+        kicox::KicoxClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+
+    pub fn vworld(&self, api_key: impl Into<ApiKey>) -> vworld::VworldClient {
+        vworld::VworldClient::with_http(self.http.clone(), api_key)
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_file_cache(self.cache.clone())
+    }
+}
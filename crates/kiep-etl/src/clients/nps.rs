@@ -1,7 +1,14 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use futures::{Stream, TryStreamExt};
+use kiep_core::period::YearMonth;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use tracing::info;
 
-use super::common::ApiClient;
+use super::common::{check_result_code, ApiClient, FileCache};
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
 
 const NPS_BASE_URL: &str = "https://apis.data.go.kr/B552015/NpsBplcInfoInqireService";
 
@@ -32,6 +39,8 @@ pub struct NpsHeader {
 
 #[derive(Debug, Deserialize)]
 pub struct NpsBody {
+    /// 데이터가 없는 달은 `"items": ""`로 오는 경우가 있어 None으로 처리
+    #[serde(default, deserialize_with = "empty_string_as_none")]
     pub items: Option<NpsItems>,
     #[serde(rename = "totalCount")]
     pub total_count: u32,
@@ -39,6 +48,8 @@ pub struct NpsBody {
 
 #[derive(Debug, Deserialize)]
 pub struct NpsItems {
+    /// XML의 빈 `<items/>`는 item 없이 온다
+    #[serde(default)]
     pub item: Vec<NpsWorkplace>,
 }
 
@@ -70,57 +81,214 @@ pub struct NpsWorkplace {
     pub sigungu_code: String,
     #[serde(rename = "ldongAddrMgplSgguEmdCd", default)]
     pub emd_code: String,
+    /// 사업장 도로명 상세주소 (지오코딩용)
+    #[serde(rename = "wkplRoadNmDtlAddr", default)]
+    pub road_address: String,
     /// 데이터 기준일
     #[serde(rename = "dataCrtYm", default)]
     pub data_year_month: String,
 }
 
+fn empty_string_as_none<'de, D>(deserializer: D) -> Result<Option<NpsItems>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // serde_json::Value를 거치지 않고 map을 바로 넘겨 XML의 문자열 숫자도 그대로 해석되게 한다
+    struct ItemsVisitor;
+
+    impl<'de> Visitor<'de> for ItemsVisitor {
+        type Value = Option<NpsItems>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an items object or an empty string")
+        }
+
+        fn visit_str<E: de::Error>(self, _: &str) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            d.deserialize_any(self)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            NpsItems::deserialize(de::value::MapAccessDeserializer::new(map)).map(Some)
+        }
+    }
+
+    deserializer.deserialize_any(ItemsVisitor)
+}
+
 impl NpsClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
         Self {
             client: ApiClient::new(NPS_BASE_URL, api_key),
         }
     }
 
-    /// 시도별 사업장 목록 조회
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self {
+            client: ApiClient::with_http(http, NPS_BASE_URL, api_key),
+        }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
+    /// 시도별 사업장 목록 조회 (최신 자료)
     pub async fn fetch_by_region(
         &self,
         sido_code: &str,
         sigungu_code: Option<&str>,
     ) -> anyhow::Result<Vec<NpsWorkplace>> {
         info!("Fetching NPS workplaces for sido={}", sido_code);
+        self.fetch_region(sido_code, sigungu_code, None).await
+    }
+
+    /// 과거 월 사업장 내역 조회 (자료생성년월 지정), 과거 고용 시계열 백필용
+    /// 해당 월 자료가 없으면 빈 목록
+    pub async fn fetch_by_region_for_month(
+        &self,
+        sido_code: &str,
+        sigungu_code: Option<&str>,
+        year_month: YearMonth,
+    ) -> anyhow::Result<Vec<NpsWorkplace>> {
+        info!("Fetching NPS workplaces for sido={} month={}", sido_code, year_month);
+
+        let mut workplaces = self.fetch_region(sido_code, sigungu_code, Some(year_month)).await?;
+        // 기준월이 비어 있는 행은 요청한 월 자료로 간주
+        let compact = compact_year_month(year_month);
+        for wp in workplaces.iter_mut().filter(|wp| wp.data_year_month.is_empty()) {
+            wp.data_year_month = compact.clone();
+        }
+        Ok(workplaces)
+    }
+
+    /// 시도별 사업장 목록을 페이지 단위로 (최신 자료), 받는 대로 적재할 때 사용
+    pub fn stream_by_region<'a>(
+        &'a self,
+        sido_code: &str,
+        sigungu_code: Option<&str>,
+    ) -> impl Stream<Item = anyhow::Result<Vec<NpsWorkplace>>> + 'a {
+        info!("Streaming NPS workplaces for sido={}", sido_code);
+        self.region_pages(sido_code, sigungu_code, None)
+    }
+
+    async fn fetch_region(
+        &self,
+        sido_code: &str,
+        sigungu_code: Option<&str>,
+        year_month: Option<YearMonth>,
+    ) -> anyhow::Result<Vec<NpsWorkplace>> {
+        self.region_pages(sido_code, sigungu_code, year_month).try_concat().await
+    }
 
-        let mut params = vec![("ldong_addr_mgpl_dg_cd".to_string(), sido_code.to_string())];
+    fn region_pages<'a>(
+        &'a self,
+        sido_code: &str,
+        sigungu_code: Option<&str>,
+        year_month: Option<YearMonth>,
+    ) -> impl Stream<Item = anyhow::Result<Vec<NpsWorkplace>>> + 'a {
+        let mut base_params: Vec<(&str, String)> =
+            vec![("ldong_addr_mgpl_dg_cd", sido_code.to_string())];
         if let Some(sg) = sigungu_code {
-            params.push(("ldong_addr_mgpl_sggu_cd".to_string(), sg.to_string()));
+            base_params.push(("ldong_addr_mgpl_sggu_cd", sg.to_string()));
         }
+        if let Some(ym) = year_month {
+            base_params.push(("data_crt_ym", compact_year_month(ym)));
+        }
+
+        self.client.fetch_pages_stream(
+            "/getDetailInfoSearch",
+            base_params,
+            100,
+            |resp: NpsResponse| {
+                let header = &resp.response.header;
+                check_result_code(&header.result_code, Some(&header.result_msg))?;
+                let total = resp
+                    .response
+                    .body
+                    .as_ref()
+                    .map(|b| b.total_count)
+                    .unwrap_or(0);
+                let items = resp
+                    .response
+                    .body
+                    .and_then(|b| b.items)
+                    .map(|i| i.item)
+                    .unwrap_or_default();
+                Ok((items, total))
+            },
+        )
+    }
+}
+
+/// YearMonth → NPS 'YYYYMM'
+fn compact_year_month(ym: YearMonth) -> String {
+    format!("{:04}{:02}", ym.year(), ym.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_without_data_parses_as_empty() {
+        let raw = r#"{"response": {"header": {"resultCode": "00", "resultMsg": "NORMAL SERVICE."},
+            "body": {"items": "", "totalCount": 0}}}"#;
+        let resp: NpsResponse = serde_json::from_str(raw).unwrap();
+        assert!(resp.response.body.unwrap().items.is_none());
+
+        let raw = r#"{"response": {"header": {"resultCode": "00", "resultMsg": "NORMAL SERVICE."},
+            "body": {"items": {"item": [{"wkplNm": "테스트", "bzowrRgstNo": "123456"}]}, "totalCount": 1}}}"#;
+        let resp: NpsResponse = serde_json::from_str(raw).unwrap();
+        assert_eq!(resp.response.body.unwrap().items.unwrap().item.len(), 1);
+    }
+
+    #[test]
+    fn test_xml_response_parses_into_same_types() {
+        let raw = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<response><header><resultCode>00</resultCode><resultMsg>NORMAL SERVICE.</resultMsg></header>
+<body><items>
+<item><wkplNm>(주)테스트</wkplNm><bzowrRgstNo>123456</bzowrRgstNo><jnngpCnt>42</jnngpCnt><crrmmNwAcqzrCnt>3</crrmmNwAcqzrCnt><crrmmLssJnngpCnt>1</crrmmLssJnngpCnt><ldongAddrMgplDgCd>43</ldongAddrMgplDgCd><dataCrtYm>202307</dataCrtYm></item>
+<item><wkplNm>두번째</wkplNm><bzowrRgstNo>654321</bzowrRgstNo><jnngpCnt>7</jnngpCnt></item>
+</items><numOfRows>100</numOfRows><pageNo>1</pageNo><totalCount>2</totalCount></body></response>"#;
+        let resp: NpsResponse = crate::clients::common::parse_xml(raw).unwrap();
+        assert_eq!(resp.response.header.result_code, "00");
+        let body = resp.response.body.unwrap();
+        assert_eq!(body.total_count, 2);
+        let items = body.items.unwrap().item;
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].name, "(주)테스트");
+        assert_eq!(items[0].subscriber_count, 42);
+        assert_eq!(items[0].sido_code, "43");
+        assert_eq!(items[1].biz_reg_no, "654321");
+
+        let raw = "<response><header><resultCode>03</resultCode><resultMsg>NODATA_ERROR</resultMsg></header>\
+            <body><items/><totalCount>0</totalCount></body></response>";
+        let resp: NpsResponse = crate::clients::common::parse_xml(raw).unwrap();
+        assert!(resp.response.body.unwrap().items.is_none_or(|i| i.item.is_empty()));
+    }
 
-        let base_params: Vec<(&str, String)> = params
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.clone()))
-            .collect();
-
-        self.client
-            .fetch_all_pages(
-                "/getDetailInfoSearch",
-                &base_params,
-                100,
-                |resp: NpsResponse| {
-                    let total = resp
-                        .response
-                        .body
-                        .as_ref()
-                        .map(|b| b.total_count)
-                        .unwrap_or(0);
-                    let items = resp
-                        .response
-                        .body
-                        .and_then(|b| b.items)
-                        .map(|i| i.item)
-                        .unwrap_or_default();
-                    (items, total)
-                },
-            )
-            .await
+    #[test]
+    fn test_compact_year_month() {
+        assert_eq!(compact_year_month(YearMonth::new(2023, 7).unwrap()), "202307");
     }
 }
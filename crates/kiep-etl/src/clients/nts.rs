@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use super::common::ApiClient;
+use super::common::{check_result_code, ApiClient, FileCache};
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
 
 const NTS_BASE_URL: &str = "https://apis.data.go.kr/1160100/service/GetBmanInfoService";
+/// getBmanInfo 일괄 조회 1건당 최대 사업자번호 수
+const NTS_BULK_MAX: usize = 100;
 
 /// NTS 사업자 상태 조회 클라이언트
 pub struct NtsClient {
@@ -25,6 +29,8 @@ pub struct NtsResponseBody {
 pub struct NtsHeader {
     #[serde(rename = "resultCode")]
     pub result_code: String,
+    #[serde(rename = "resultMsg", default)]
+    pub result_msg: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,6 +42,7 @@ pub struct NtsBody {
 
 #[derive(Debug, Deserialize)]
 pub struct NtsItems {
+    #[serde(default)]
     pub item: Vec<NtsBizInfo>,
 }
 
@@ -60,12 +67,30 @@ pub struct NtsBizInfo {
 }
 
 impl NtsClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
         Self {
             client: ApiClient::new(NTS_BASE_URL, api_key),
         }
     }
 
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self {
+            client: ApiClient::with_http(http, NTS_BASE_URL, api_key),
+        }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
     /// 사업자 상태 조회 (단건)
     pub async fn check_status(&self, biz_no: &str) -> anyhow::Result<Option<NtsBizInfo>> {
         info!("Checking NTS status for biz_no={}", biz_no);
@@ -73,6 +98,8 @@ impl NtsClient {
         let params = [("bno", biz_no), ("numOfRows", "1"), ("type", "json")];
 
         let resp: NtsResponse = self.client.get_json("/getBmanInfo", &params).await?;
+        let header = &resp.response.header;
+        check_result_code(&header.result_code, header.result_msg.as_deref())?;
 
         Ok(resp
             .response
@@ -80,4 +107,55 @@ impl NtsClient {
             .and_then(|b| b.items)
             .and_then(|i| i.item.into_iter().next()))
     }
+
+    /// 사업자 상태 일괄 조회, 요청 1건에 최대 100개씩 POST
+    /// 조회되지 않은 번호(응답에 없거나 상태가 빈 항목)는 결과에서 제외
+    pub async fn check_status_bulk(&self, biz_nos: &[&str]) -> anyhow::Result<Vec<NtsBizInfo>> {
+        let mut results = Vec::with_capacity(biz_nos.len());
+        for chunk in biz_nos.chunks(NTS_BULK_MAX) {
+            info!("Checking NTS status for {} business numbers", chunk.len());
+
+            let rows = chunk.len().to_string();
+            let params = [("numOfRows", rows.as_str()), ("type", "json")];
+            let body = serde_json::json!({ "bno": chunk });
+
+            let resp: NtsResponse = self.client.post_json("/getBmanInfo", &params, &body).await?;
+            results.extend(matched_items(resp)?);
+        }
+        Ok(results)
+    }
+}
+
+/// 일괄 조회 응답에서 조회된 사업자만 (미등록 번호는 상태가 빈 항목으로 올 수 있음)
+fn matched_items(resp: NtsResponse) -> anyhow::Result<Vec<NtsBizInfo>> {
+    let header = &resp.response.header;
+    check_result_code(&header.result_code, header.result_msg.as_deref())?;
+
+    let items = resp
+        .response
+        .body
+        .and_then(|b| b.items)
+        .map(|i| i.item)
+        .unwrap_or_default();
+    Ok(items.into_iter().filter(|info| !info.status.trim().is_empty()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_response_drops_unmatched_numbers() {
+        let raw = r#"{"response": {"header": {"resultCode": "00"}, "body": {"items": {"item": [
+            {"bno": "1234567890", "bnm": "테스트", "bstt": "계속사업자"},
+            {"bno": "9999999999", "bstt": ""}
+        ]}, "totalCount": 2}}}"#;
+        let items = matched_items(serde_json::from_str(raw).unwrap()).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].biz_no, "1234567890");
+
+        let raw = r#"{"response": {"header": {"resultCode": "03", "resultMsg": "NODATA_ERROR"},
+            "body": {"totalCount": 0}}}"#;
+        assert!(matched_items(serde_json::from_str(raw).unwrap()).unwrap().is_empty());
+    }
 }
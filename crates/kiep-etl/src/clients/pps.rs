@@ -1,7 +1,13 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use super::common::ApiClient;
+use super::common::{check_result_code, ApiClient, FileCache};
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
 
 const PPS_BASE_URL: &str = "https://apis.data.go.kr/1230000/BidPublicInfoService04";
 
@@ -25,6 +31,8 @@ pub struct PpsResponseBody {
 pub struct PpsHeader {
     #[serde(rename = "resultCode")]
     pub result_code: String,
+    #[serde(rename = "resultMsg", default)]
+    pub result_msg: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -67,42 +75,215 @@ pub struct PpsContract {
 }
 
 impl PpsClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
         Self {
             client: ApiClient::new(PPS_BASE_URL, api_key),
         }
     }
 
-    /// 날짜 범위로 계약 정보 조회
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self {
+            client: ApiClient::with_http(http, PPS_BASE_URL, api_key),
+        }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
+    /// 날짜 범위로 계약 정보 조회 (공사, 전체 기관)
     pub async fn fetch_contracts(
         &self,
         from_date: &str,
         to_date: &str,
     ) -> anyhow::Result<Vec<PpsContract>> {
-        info!("Fetching PPS contracts from {} to {}", from_date, to_date);
+        self.fetch_contracts_filtered(from_date, to_date, &PpsFilter::default()).await
+    }
+
+    /// 날짜 범위 + 업무 구분/기관 필터로 조회, 큰 범위를 구분·기관별로 나눠 수집할 때 사용
+    pub async fn fetch_contracts_filtered(
+        &self,
+        from_date: &str,
+        to_date: &str,
+        filter: &PpsFilter,
+    ) -> anyhow::Result<Vec<PpsContract>> {
+        validate_date_range(from_date, to_date)?;
+        filter.validate()?;
+        info!("Fetching PPS {} contracts from {} to {} ({:?})", filter.category, from_date, to_date, filter);
 
-        let params = vec![
+        let mut base_params: Vec<(&str, String)> = vec![
             ("inqryBgnDt", from_date.to_string()),
             ("inqryEndDt", to_date.to_string()),
         ];
-        let base_params: Vec<(&str, String)> = params
-            .iter()
-            .map(|(k, v)| (*k, v.clone()))
-            .collect();
+        base_params.extend(filter.params());
 
         self.client
             .fetch_all_pages(
-                "/getBidPblancListInfoCnstwkPPSSrch04",
+                &filter.category.operation(),
                 &base_params,
                 100,
                 |resp: PpsResponse| {
+                    let header = &resp.response.header;
+                    check_result_code(&header.result_code, header.result_msg.as_deref())?;
                     let total = resp.response.body.as_ref().map(|b| b.total_count).unwrap_or(0);
                     let items = resp.response.body
                         .and_then(|b| b.items)
                         .unwrap_or_default();
-                    (items, total)
+                    Ok((items, total))
                 },
             )
             .await
     }
 }
+
+/// 조회 기간 검사: 양 끝 모두 'YYYYMMDD'이고 시작일이 종료일보다 늦지 않아야 함
+pub fn validate_date_range(from_date: &str, to_date: &str) -> anyhow::Result<()> {
+    let parse = |raw: &str| {
+        NaiveDate::parse_from_str(raw, "%Y%m%d")
+            .ok()
+            .filter(|_| raw.len() == 8)
+            .ok_or_else(|| anyhow::anyhow!("invalid PPS date '{}': expected YYYYMMDD", raw))
+    };
+    let (from, to) = (parse(from_date)?, parse(to_date)?);
+    anyhow::ensure!(from <= to, "PPS date range start {} is after end {}", from_date, to_date);
+    Ok(())
+}
+
+/// 입찰공고 업무 구분, API 오퍼레이션이 구분별로 나뉘어 있어 요청 하나에 하나만 지정 가능
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PpsCategory {
+    /// 물품
+    Goods,
+    /// 공사
+    #[default]
+    Construction,
+    /// 용역
+    Service,
+    /// 외자
+    Foreign,
+}
+
+impl PpsCategory {
+    pub const ALL: [PpsCategory; 4] = [Self::Goods, Self::Construction, Self::Service, Self::Foreign];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Goods => "goods",
+            Self::Construction => "construction",
+            Self::Service => "service",
+            Self::Foreign => "foreign",
+        }
+    }
+
+    /// 조회 오퍼레이션 경로
+    fn operation(self) -> String {
+        let name = match self {
+            Self::Goods => "Thng",
+            Self::Construction => "Cnstwk",
+            Self::Service => "Servc",
+            Self::Foreign => "Frgcpt",
+        };
+        format!("/getBidPblancListInfo{}PPSSrch04", name)
+    }
+}
+
+impl fmt::Display for PpsCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PpsCategory {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("unknown PPS category '{}': expected goods/construction/service/foreign", s))
+    }
+}
+
+/// PPS 조회 필터
+/// - 공고기관(`ntceInsttCd`)과 수요기관(`dminsttCd`)은 동시에 지정할 수 없음 (둘 중 하나로만 분할)
+/// - 기관코드는 행정표준기관코드 7자리
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PpsFilter {
+    pub category: PpsCategory,
+    /// 공고기관코드
+    pub notice_agency: Option<String>,
+    /// 수요기관코드
+    pub demand_agency: Option<String>,
+}
+
+impl PpsFilter {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.notice_agency.is_some() && self.demand_agency.is_some() {
+            anyhow::bail!("notice agency and demand agency filters are mutually exclusive");
+        }
+        for code in [&self.notice_agency, &self.demand_agency].into_iter().flatten() {
+            if code.len() != 7 || !code.bytes().all(|b| b.is_ascii_digit()) {
+                anyhow::bail!("invalid agency code '{}': expected 7 digits", code);
+            }
+        }
+        Ok(())
+    }
+
+    /// 업무 구분은 경로로 전달되므로 기관 코드만
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(code) = &self.notice_agency {
+            params.push(("ntceInsttCd", code.clone()));
+        }
+        if let Some(code) = &self.demand_agency {
+            params.push(("dminsttCd", code.clone()));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_operation_and_parse() {
+        assert_eq!(PpsCategory::default().operation(), "/getBidPblancListInfoCnstwkPPSSrch04");
+        assert_eq!(PpsCategory::Service.operation(), "/getBidPblancListInfoServcPPSSrch04");
+        for c in PpsCategory::ALL {
+            assert_eq!(c.as_str().parse::<PpsCategory>().unwrap(), c);
+        }
+        assert!("cnstwk".parse::<PpsCategory>().is_err());
+    }
+
+    #[test]
+    fn test_filter_validation_and_params() {
+        let demand = PpsFilter { demand_agency: Some("1230000".into()), ..Default::default() };
+        assert!(demand.validate().is_ok());
+        assert_eq!(demand.params(), [("dminsttCd", "1230000".to_string())]);
+
+        let both = PpsFilter { notice_agency: Some("1230000".into()), ..demand.clone() };
+        assert!(both.validate().is_err());
+
+        let short = PpsFilter { notice_agency: Some("123".into()), ..Default::default() };
+        assert!(short.validate().is_err());
+        assert!(PpsFilter::default().params().is_empty());
+    }
+
+    #[test]
+    fn test_validate_date_range() {
+        assert!(validate_date_range("20240101", "20240131").is_ok());
+        assert!(validate_date_range("20240101", "20240101").is_ok());
+        assert!(validate_date_range("2024-01-01", "20240131").is_err());
+        assert!(validate_date_range("20240132", "20240201").is_err());
+        assert!(validate_date_range("20240201", "20240101").is_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use kiep_core::Config;
+
+/// 토큰 버킷 호출 제한, clone한 핸들은 같은 버킷을 공유한다
+/// (한 설정으로 만든 여러 클라이언트가 하나의 초당 예산을 나눠 씀)
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    /// None이면 제한 없음
+    bucket: Option<Arc<Mutex<Bucket>>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate_per_sec: f64,
+    burst: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl Bucket {
+    /// now 시점에 토큰 1개 사용, 부족하면 다음 토큰까지 남은 시간
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate_per_sec).min(self.burst);
+        self.last = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate_per_sec))
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// 초당 rate_per_sec개씩 채워지고 최대 burst개까지 쌓이는 버킷 (처음엔 가득 참)
+    pub fn new(rate_per_sec: f64, burst: u32) -> Self {
+        assert!(rate_per_sec > 0.0, "rate_per_sec must be positive");
+        let burst = f64::from(burst.max(1));
+        Self {
+            bucket: Some(Arc::new(Mutex::new(Bucket {
+                rate_per_sec,
+                burst,
+                tokens: burst,
+                last: Instant::now(),
+            }))),
+        }
+    }
+
+    /// `API_RATE_LIMIT_PER_SEC`가 없으면 제한 없음
+    pub fn from_config(config: &Config) -> Self {
+        match config.api_rate_limit_per_sec {
+            Some(rate) => {
+                let burst = config.api_rate_limit_burst.unwrap_or(rate.ceil() as u32);
+                Self::new(rate, burst)
+            }
+            None => Self::unlimited(),
+        }
+    }
+
+    /// 요청 1건 허가를 받을 때까지 대기
+    pub async fn acquire(&self) {
+        let Some(bucket) = &self.bucket else { return };
+        loop {
+            let wait = match bucket.lock().unwrap().take(Instant::now()) {
+                Ok(()) => return,
+                Err(wait) => wait,
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_burst_then_refill() {
+        let start = Instant::now();
+        let mut bucket = Bucket { rate_per_sec: 2.0, burst: 3.0, tokens: 3.0, last: start };
+
+        for _ in 0..3 {
+            assert!(bucket.take(start).is_ok());
+        }
+        assert_eq!(bucket.take(start), Err(Duration::from_millis(500)));
+
+        // 0.5초 뒤 1개 충전, 10초를 쉬어도 burst까지만 쌓인다
+        assert!(bucket.take(start + Duration::from_millis(500)).is_ok());
+        let later = start + Duration::from_secs(10);
+        for _ in 0..3 {
+            assert!(bucket.take(later).is_ok());
+        }
+        assert!(bucket.take(later).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shared_limiter_waits() {
+        let limiter = RateLimiter::new(50.0, 1);
+        let shared = limiter.clone();
+
+        let started = Instant::now();
+        limiter.acquire().await;
+        shared.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(15));
+
+        RateLimiter::unlimited().acquire().await;
+    }
+}
@@ -0,0 +1,258 @@
+use serde::Deserialize;
+use tracing::debug;
+
+use super::common::{ApiClient, FileCache};
+use super::de::lenient_f64;
+use super::keys::ApiKey;
+use super::rate_limit::RateLimiter;
+
+const VWORLD_BASE_URL: &str = "https://api.vworld.kr/req";
+
+/// VWorld 지오코더 클라이언트 (주소 → 좌표/법정동코드)
+pub struct VworldClient {
+    client: ApiClient,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VworldResponse {
+    pub response: VworldResponseBody,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VworldResponseBody {
+    /// OK / NOT_FOUND / ERROR
+    pub status: String,
+    #[serde(default)]
+    pub error: Option<VworldError>,
+    #[serde(default)]
+    pub refined: Option<VworldRefined>,
+    #[serde(default)]
+    pub result: Option<VworldResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VworldError {
+    #[serde(default)]
+    pub code: String,
+    #[serde(default)]
+    pub text: String,
+}
+
+/// 정제된 주소
+#[derive(Debug, Deserialize)]
+pub struct VworldRefined {
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub structure: Option<VworldStructure>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VworldStructure {
+    /// 법정동코드 (10자리)
+    #[serde(rename = "level4LC", default)]
+    pub legal_dong_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VworldResult {
+    pub point: Option<VworldPoint>,
+}
+
+/// EPSG:4326 좌표 (문자열로 온다)
+#[derive(Debug, Deserialize)]
+pub struct VworldPoint {
+    #[serde(default, deserialize_with = "lenient_f64")]
+    pub x: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_f64")]
+    pub y: Option<f64>,
+}
+
+/// 주소 검색 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct VworldLocation {
+    pub longitude: f64,
+    pub latitude: f64,
+    /// 법정동코드 10자리, 응답에 없으면 None
+    pub bjd_code: Option<String>,
+}
+
+impl VworldResponseBody {
+    /// NOT_FOUND와 좌표 없는 응답은 None, ERROR는 오류
+    fn into_location(self) -> anyhow::Result<Option<VworldLocation>> {
+        match self.status.as_str() {
+            "OK" => {}
+            "NOT_FOUND" => return Ok(None),
+            _ => {
+                let detail = self
+                    .error
+                    .map(|e| format!("{} {}", e.code, e.text))
+                    .unwrap_or_default();
+                return Err(kiep_core::Error::Api(format!(
+                    "VWorld status {}: {}",
+                    self.status,
+                    detail.trim()
+                ))
+                .into());
+            }
+        }
+        let Some(point) = self.result.and_then(|r| r.point) else { return Ok(None) };
+        let (Some(longitude), Some(latitude)) = (point.x, point.y) else { return Ok(None) };
+        let bjd_code = self
+            .refined
+            .and_then(|r| r.structure)
+            .and_then(|s| s.legal_dong_code)
+            .filter(|code| code.len() == 10 && code.bytes().all(|b| b.is_ascii_digit()));
+        Ok(Some(VworldLocation { longitude, latitude, bjd_code }))
+    }
+}
+
+impl VworldClient {
+    pub fn new(api_key: impl Into<ApiKey>) -> Self {
+        Self::from_client(ApiClient::new(VWORLD_BASE_URL, api_key))
+    }
+
+    pub fn with_http(http: reqwest::Client, api_key: impl Into<ApiKey>) -> Self {
+        Self::from_client(ApiClient::with_http(http, VWORLD_BASE_URL, api_key))
+    }
+
+    fn from_client(client: ApiClient) -> Self {
+        Self { client: client.key_param("key") }
+    }
+
+    /// 호출 제한 공유 (ClientFactory가 모든 소스에 같은 limiter를 건다)
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(limiter);
+        self
+    }
+
+    /// 응답 디스크 캐시 (개발용, 기본 꺼짐)
+    pub fn with_file_cache(mut self, cache: FileCache) -> Self {
+        self.client = self.client.with_file_cache(cache);
+        self
+    }
+
+    /// 주소 검색, 도로명으로 못 찾으면 지번으로 다시 찾는다
+    pub async fn locate(&self, address: &str) -> anyhow::Result<Option<VworldLocation>> {
+        let address = address.trim();
+        if address.is_empty() {
+            return Ok(None);
+        }
+        for address_type in ["road", "parcel"] {
+            let params = [
+                ("service", "address"),
+                ("request", "getcoord"),
+                ("version", "2.0"),
+                ("crs", "epsg:4326"),
+                ("refine", "true"),
+                ("simple", "false"),
+                ("format", "json"),
+                ("type", address_type),
+                ("address", address),
+            ];
+            let resp: VworldResponse = self.client.get_json("/address", &params).await?;
+            if let Some(location) = resp.response.into_location()? {
+                return Ok(Some(location));
+            }
+        }
+        debug!("VWorld could not locate address: {}", address);
+        Ok(None)
+    }
+
+    /// 주소 → (경도, 위도)
+    pub async fn geocode(&self, address: &str) -> anyhow::Result<Option<(f64, f64)>> {
+        Ok(self.locate(address).await?.map(|l| (l.longitude, l.latitude)))
+    }
+
+    /// 주소 → 법정동코드 10자리
+    pub async fn address_to_bjd(&self, address: &str) -> anyhow::Result<Option<String>> {
+        Ok(self.locate(address).await?.and_then(|l| l.bjd_code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+
+    fn body(raw: &str) -> VworldResponseBody {
+        serde_json::from_str::<VworldResponse>(raw).unwrap().response
+    }
+
+    const FOUND: &str = r#"{"response": {
+        "service": {"name": "address", "operation": "getcoord"},
+        "status": "OK",
+        "input": {"type": "road", "address": "세종특별자치시 한누리대로 2130"},
+        "refined": {
+            "text": "세종특별자치시 한누리대로 2130 (보람동)",
+            "structure": {"level1": "세종특별자치시", "level4L": "보람동", "level4LC": "3611011800"}
+        },
+        "result": {"crs": "EPSG:4326", "point": {"x": "127.2890", "y": "36.4800"}}
+    }}"#;
+
+    #[test]
+    fn test_found_address() {
+        let location = body(FOUND).into_location().unwrap().unwrap();
+        assert_eq!(location.longitude, 127.289);
+        assert_eq!(location.latitude, 36.48);
+        assert_eq!(location.bjd_code.as_deref(), Some("3611011800"));
+    }
+
+    #[test]
+    fn test_not_found_is_none() {
+        let raw = r#"{"response": {"service": {"name": "address"}, "status": "NOT_FOUND", "record": {"total": "0"}}}"#;
+        assert!(body(raw).into_location().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_error_status_is_error() {
+        let raw = r#"{"response": {"status": "ERROR", "error": {"level": "1", "code": "INVALID_KEY", "text": "등록되지 않은 인증키입니다."}}}"#;
+        let err = body(raw).into_location().unwrap_err();
+        assert!(err.to_string().contains("INVALID_KEY"));
+    }
+
+    /// 요청 줄을 기록하고 순서대로 응답하는 서버
+    async fn serve(bodies: Vec<&'static str>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let counter = AtomicUsize::new(0);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let line = String::from_utf8_lossy(&buf[..n]).lines().next().unwrap_or("").to_string();
+                seen.lock().unwrap().push(line);
+                let body = bodies[counter.fetch_add(1, Ordering::SeqCst).min(bodies.len() - 1)];
+                let resp = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(resp.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+        (base_url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_parcel_address() {
+        let not_found = r#"{"response": {"status": "NOT_FOUND"}}"#;
+        let (base_url, requests) = serve(vec![not_found, FOUND]).await;
+        let client = VworldClient::from_client(ApiClient::new(&base_url, "abc"));
+
+        let bjd = client.address_to_bjd("세종시 보람동 1").await.unwrap();
+        assert_eq!(bjd.as_deref(), Some("3611011800"));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("type=road") && requests[1].contains("type=parcel"));
+        assert!(requests[0].contains("key=abc") && !requests[0].contains("serviceKey"));
+    }
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use tracing::info;
+use uuid::Uuid;
+
+/// 수집 실행 1회 = 배치 1개, 적재된 행에 `load_batch_id`로 기록
+#[derive(Debug, Clone, FromRow)]
+pub struct LoadBatch {
+    pub id: Uuid,
+    pub source: String,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+}
+
+pub async fn start_batch(
+    pool: &PgPool,
+    source: &str,
+    params: serde_json::Value,
+) -> anyhow::Result<LoadBatch> {
+    let batch = sqlx::query_as::<_, LoadBatch>(
+        r#"
+        INSERT INTO batches (source, params)
+        VALUES ($1, $2)
+        RETURNING id, source, status, started_at
+        "#,
+    )
+    .bind(source)
+    .bind(params)
+    .fetch_one(pool)
+    .await?;
+
+    info!("Started {} batch {}", source, batch.id);
+    Ok(batch)
+}
+
+pub async fn complete_batch(
+    pool: &PgPool,
+    id: Uuid,
+    fetched: usize,
+    written: u32,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE batches SET status = 'completed', fetched_count = $2, written_count = $3,
+            finished_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(fetched as i32)
+    .bind(written as i32)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn fail_batch(pool: &PgPool, id: Uuid) -> anyhow::Result<()> {
+    sqlx::query("UPDATE batches SET status = 'failed', finished_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct RollbackReport {
+    pub employment_deleted: u64,
+    pub companies_deleted: u64,
+    /// 배치 이전부터 있던 행을 갱신한 경우 (이전 값이 없어 되돌릴 수 없음)
+    pub employment_kept: i64,
+    /// 배치가 생성했지만 다른 데이터가 참조 중이거나 배치 이전부터 있던 기업
+    pub companies_kept: i64,
+}
+
+/// 배치가 새로 만든 행만 삭제 (created_at >= 배치 시작 시각)
+/// 이후 다른 배치가 다시 기록한 행은 load_batch_id가 바뀌므로 건드리지 않는다
+pub async fn rollback_batch(pool: &PgPool, id: Uuid) -> anyhow::Result<Option<RollbackReport>> {
+    let mut tx = pool.begin().await?;
+
+    let Some(batch) = sqlx::query_as::<_, LoadBatch>(
+        "SELECT id, source, status, started_at FROM batches WHERE id = $1 FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    if batch.status == "rolled_back" {
+        anyhow::bail!("batch {} is already rolled back", id);
+    }
+
+    let employment_deleted = sqlx::query(
+        "DELETE FROM employment_series WHERE load_batch_id = $1 AND created_at >= $2",
+    )
+    .bind(id)
+    .bind(batch.started_at)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let companies_deleted = sqlx::query(
+        r#"
+        DELETE FROM companies c
+        WHERE c.load_batch_id = $1 AND c.created_at >= $2
+          AND NOT EXISTS (SELECT 1 FROM employment_series es WHERE es.biz_no = c.biz_no)
+          AND NOT EXISTS (SELECT 1 FROM financials f WHERE f.biz_no = c.biz_no)
+          AND NOT EXISTS (SELECT 1 FROM procurement p WHERE p.biz_no = c.biz_no)
+          AND NOT EXISTS (SELECT 1 FROM company_history h WHERE h.biz_no = c.biz_no)
+        "#,
+    )
+    .bind(id)
+    .bind(batch.started_at)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    let employment_kept: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM employment_series WHERE load_batch_id = $1")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+    let companies_kept: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM companies WHERE load_batch_id = $1")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+    sqlx::query("UPDATE batches SET status = 'rolled_back', rolled_back_at = NOW() WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    let report = RollbackReport {
+        employment_deleted,
+        companies_deleted,
+        employment_kept,
+        companies_kept,
+    };
+    info!(
+        "Rolled back batch {}: -{} employment, -{} companies",
+        id, report.employment_deleted, report.companies_deleted
+    );
+    Ok(Some(report))
+}
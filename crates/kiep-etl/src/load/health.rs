@@ -0,0 +1,400 @@
+use kiep_core::models::{HealthInputs, HealthWeights, RegionHealth};
+use kiep_core::period::YearMonth;
+use sqlx::{FromRow, PgPool};
+use tracing::{info, warn};
+
+use crate::transform::normalize::percentage_in_range;
+
+/// 지역·월 단위 건강도 원천 집계
+#[derive(Debug, Clone, FromRow)]
+pub struct RegionHealthInputs {
+    pub region_code: String,
+    pub company_count: i64,
+    pub employee_count: i64,
+    pub prev_employee_count: Option<i64>,
+    pub new_biz_count: i64,
+    pub closed_biz_count: i64,
+    pub avg_revenue_growth: Option<f64>,
+    pub complex_utilization: Option<f64>,
+}
+
+impl RegionHealthInputs {
+    /// 고용증감률(%) = (당월 - 전월) / 전월, 전월 데이터가 없으면 None
+    pub fn employment_growth(&self) -> Option<f64> {
+        self.prev_employee_count
+            .filter(|prev| *prev > 0)
+            .map(|prev| (self.employee_count - prev) as f64 / prev as f64 * 100.0)
+    }
+
+    fn rate(&self, count: i64) -> Option<f64> {
+        (self.company_count > 0).then(|| count as f64 / self.company_count as f64 * 100.0)
+    }
+
+    /// 점수 산출에 쓰는 지표 (범위를 벗어난 산단가동률은 없는 것으로 본다)
+    pub fn health_inputs(&self) -> HealthInputs {
+        HealthInputs {
+            employment_growth: self.employment_growth(),
+            new_biz_rate: self.rate(self.new_biz_count),
+            closure_rate: self.rate(self.closed_biz_count),
+            avg_revenue_growth: self.avg_revenue_growth,
+            complex_utilization: percentage_in_range(
+                self.complex_utilization,
+                "complex_utilization",
+                &self.region_code,
+            ),
+        }
+    }
+
+    /// 기업 수가 min_companies 미만이면 점수는 계산하되 insufficient_data로 표시
+    /// 없는 지표는 가중치에서 빼고 다시 맞추며, 지표가 하나도 없으면 None
+    pub fn to_region_health(&self, year_month: YearMonth, min_companies: i64) -> Option<RegionHealth> {
+        let inputs = self.health_inputs();
+        let health_score = RegionHealth::calculate_score_partial(&inputs)?;
+        Some(self.build(year_month, min_companies, &inputs, health_score, None))
+    }
+
+    /// 지정한 가중치로 to_region_health, 가중치 합이 1.0이 아니면 오류
+    /// 기본 가중치가 아니면 score_weights에 가중치를 남겨 기본 모델 점수와 구분한다
+    pub fn to_region_health_with(
+        &self,
+        year_month: YearMonth,
+        min_companies: i64,
+        weights: &HealthWeights,
+    ) -> kiep_core::Result<Option<RegionHealth>> {
+        let inputs = self.health_inputs();
+        let health_score = RegionHealth::calculate_score_weighted(&inputs, weights)?;
+        let score_weights = (*weights != HealthWeights::default()).then(|| weights.to_string());
+        Ok(health_score.map(|score| self.build(year_month, min_companies, &inputs, score, score_weights)))
+    }
+
+    fn build(
+        &self,
+        year_month: YearMonth,
+        min_companies: i64,
+        inputs: &HealthInputs,
+        health_score: f64,
+        score_weights: Option<String>,
+    ) -> RegionHealth {
+        RegionHealth {
+            region_code: self.region_code.clone(),
+            year_month: year_month.to_string(),
+            company_count: clamp_i32(self.company_count),
+            employee_count: clamp_i32(self.employee_count),
+            new_biz_count: clamp_i32(self.new_biz_count),
+            closed_biz_count: clamp_i32(self.closed_biz_count),
+            employment_growth: inputs.employment_growth,
+            new_biz_rate: inputs.new_biz_rate,
+            closure_rate: inputs.closure_rate,
+            avg_revenue_growth: inputs.avg_revenue_growth,
+            complex_utilization: inputs.complex_utilization,
+            health_score,
+            score_version: RegionHealth::SCORE_VERSION,
+            score_weights,
+            insufficient_data: self.company_count < min_companies,
+        }
+    }
+}
+
+fn clamp_i32(v: i64) -> i32 {
+    v.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// 해당 월 고용 데이터가 있는 지역만 집계
+/// - 신규 사업자: 최초 employment_series 월이 해당 월인 기업
+/// - 폐업 사업자: company_history에서 해당 월에 closed로 전환된 기업
+/// - 매출증가율: 상장사의 직전 회계연도 4분기 매출 전년 대비
+/// - 산단가동률: 가동업체수 / 입주업체수 (산단 시계열이 없어 현재 값 사용)
+///   가동업체수가 입주업체수보다 많은 산단은 상류 오류로 보고 평균에서 제외
+pub async fn fetch_region_inputs(
+    pool: &PgPool,
+    year_month: YearMonth,
+) -> anyhow::Result<Vec<RegionHealthInputs>> {
+    let inputs = sqlx::query_as::<_, RegionHealthInputs>(
+        r#"
+        WITH emp AS (
+            SELECT c.bjd_code as region_code,
+                   COUNT(*) as company_count,
+                   SUM(es.employee_count)::bigint as employee_count
+            FROM employment_series es
+            JOIN companies c ON c.biz_no = es.biz_no
+            WHERE es.year_month = $1
+            GROUP BY c.bjd_code
+        ),
+        prev_emp AS (
+            SELECT c.bjd_code as region_code,
+                   SUM(es.employee_count)::bigint as employee_count
+            FROM employment_series es
+            JOIN companies c ON c.biz_no = es.biz_no
+            WHERE es.year_month = $2
+            GROUP BY c.bjd_code
+        ),
+        new_biz AS (
+            SELECT c.bjd_code as region_code, COUNT(*) as new_biz_count
+            FROM companies c
+            JOIN (
+                SELECT biz_no, MIN(year_month) as first_month
+                FROM employment_series
+                GROUP BY biz_no
+            ) fs ON fs.biz_no = c.biz_no
+            WHERE fs.first_month = $1
+            GROUP BY c.bjd_code
+        ),
+        closed AS (
+            SELECT c.bjd_code as region_code, COUNT(DISTINCT h.biz_no) as closed_biz_count
+            FROM company_history h
+            JOIN companies c ON c.biz_no = h.biz_no
+            WHERE h.field = 'biz_status'
+              AND h.new_value = 'closed'
+              AND to_char(h.changed_at, 'YYYY-MM') = $1
+            GROUP BY c.bjd_code
+        ),
+        revenue AS (
+            SELECT c.bjd_code as region_code,
+                   AVG((cur.revenue - prev.revenue)::float8 / prev.revenue * 100) as avg_revenue_growth
+            FROM companies c
+            JOIN financials cur
+              ON cur.biz_no = c.biz_no AND cur.fiscal_year = $3 AND cur.quarter = 4
+            JOIN financials prev
+              ON prev.biz_no = c.biz_no AND prev.fiscal_year = $3 - 1 AND prev.quarter = 4
+             AND prev.statement_type = cur.statement_type
+            WHERE c.stock_code IS NOT NULL
+              AND (cur.statement_type = 'consolidated' OR NOT EXISTS (
+                  SELECT 1 FROM financials fc
+                  WHERE fc.biz_no = c.biz_no AND fc.statement_type = 'consolidated'
+              ))
+              AND cur.revenue IS NOT NULL
+              AND prev.revenue > 0
+            GROUP BY c.bjd_code
+        ),
+        complexes AS (
+            SELECT LEFT(ic.bjd_code, 5) as region_code,
+                   AVG(ic.operating_count::float8 / ic.tenant_count * 100) as complex_utilization
+            FROM industrial_complexes ic
+            WHERE ic.tenant_count > 0 AND ic.bjd_code IS NOT NULL
+              AND ic.operating_count BETWEEN 0 AND ic.tenant_count
+            GROUP BY LEFT(ic.bjd_code, 5)
+        )
+        SELECT
+            r.code as region_code,
+            emp.company_count,
+            emp.employee_count,
+            prev_emp.employee_count as prev_employee_count,
+            COALESCE(new_biz.new_biz_count, 0) as new_biz_count,
+            COALESCE(closed.closed_biz_count, 0) as closed_biz_count,
+            revenue.avg_revenue_growth,
+            complexes.complex_utilization
+        FROM regions r
+        JOIN emp ON emp.region_code = r.code
+        LEFT JOIN prev_emp ON prev_emp.region_code = r.code
+        LEFT JOIN new_biz ON new_biz.region_code = r.code
+        LEFT JOIN closed ON closed.region_code = r.code
+        LEFT JOIN revenue ON revenue.region_code = r.code
+        LEFT JOIN complexes ON complexes.region_code = r.code
+        ORDER BY r.code
+        "#,
+    )
+    .bind(year_month.to_string())
+    .bind(year_month.prev().to_string())
+    .bind(year_month.year() - 1)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(inputs)
+}
+
+/// region_health 일괄 upsert (UNNEST 한 번)
+pub async fn upsert_region_health(pool: &PgPool, rows: &[RegionHealth]) -> anyhow::Result<u64> {
+    if rows.is_empty() {
+        return Ok(0);
+    }
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO region_health (
+            region_code, year_month, company_count, employee_count,
+            new_biz_count, closed_biz_count, employment_growth, new_biz_rate,
+            closure_rate, avg_revenue_growth, complex_utilization, health_score,
+            score_version, score_weights, insufficient_data
+        )
+        SELECT * FROM UNNEST(
+            $1::text[], $2::text[], $3::int[], $4::int[],
+            $5::int[], $6::int[], $7::float8[], $8::float8[],
+            $9::float8[], $10::float8[], $11::float8[], $12::float8[],
+            $13::int[], $14::text[], $15::bool[]
+        )
+        ON CONFLICT (region_code, year_month) DO UPDATE SET
+            company_count = EXCLUDED.company_count,
+            employee_count = EXCLUDED.employee_count,
+            new_biz_count = EXCLUDED.new_biz_count,
+            closed_biz_count = EXCLUDED.closed_biz_count,
+            employment_growth = EXCLUDED.employment_growth,
+            new_biz_rate = EXCLUDED.new_biz_rate,
+            closure_rate = EXCLUDED.closure_rate,
+            avg_revenue_growth = EXCLUDED.avg_revenue_growth,
+            complex_utilization = EXCLUDED.complex_utilization,
+            health_score = EXCLUDED.health_score,
+            score_version = EXCLUDED.score_version,
+            score_weights = EXCLUDED.score_weights,
+            insufficient_data = EXCLUDED.insufficient_data
+        "#,
+    )
+    .bind(rows.iter().map(|r| r.region_code.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.year_month.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.company_count).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.employee_count).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.new_biz_count).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.closed_biz_count).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.employment_growth).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.new_biz_rate).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.closure_rate).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.avg_revenue_growth).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.complex_utilization).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.health_score).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.score_version).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.score_weights.clone()).collect::<Vec<_>>())
+    .bind(rows.iter().map(|r| r.insufficient_data).collect::<Vec<_>>())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// 한 기간 재계산 결과
+#[derive(Debug, Clone)]
+pub struct PeriodReport {
+    pub year_month: YearMonth,
+    pub regions_total: i64,
+    pub regions_written: u64,
+}
+
+impl PeriodReport {
+    /// 데이터가 없어 건너뛴 기간
+    pub fn is_empty(&self) -> bool {
+        self.regions_written == 0
+    }
+
+    /// 일부 지역만 데이터가 있는 기간
+    pub fn is_partial(&self) -> bool {
+        self.regions_written > 0 && (self.regions_written as i64) < self.regions_total
+    }
+}
+
+/// 한 달치 지역 건강도 재계산 후 저장 (데이터 없는 지역/기간은 기록하지 않음)
+/// 기업 수가 min_companies 미만인 지역은 insufficient_data로 기록
+/// weights가 기본값과 다르면 행마다 score_weights에 가중치를 기록한다 (실험용)
+pub async fn recompute_period(
+    pool: &PgPool,
+    year_month: YearMonth,
+    min_companies: i64,
+    weights: &HealthWeights,
+) -> anyhow::Result<PeriodReport> {
+    if *weights != HealthWeights::default() {
+        warn!("Recomputing {} health with non-default weights: {}", year_month, weights);
+    }
+
+    let regions_total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM regions")
+        .fetch_one(pool)
+        .await?;
+
+    let mut rows: Vec<RegionHealth> = Vec::new();
+    for inputs in fetch_region_inputs(pool, year_month).await? {
+        if let Some(health) = inputs.to_region_health_with(year_month, min_companies, weights)? {
+            rows.push(health);
+        }
+    }
+
+    let regions_written = upsert_region_health(pool, &rows).await?;
+    info!(
+        "Recomputed health for {}: {}/{} regions",
+        year_month, regions_written, regions_total
+    );
+
+    Ok(PeriodReport { year_month, regions_total, regions_written })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(employee_count: i64, prev: Option<i64>) -> RegionHealthInputs {
+        RegionHealthInputs {
+            region_code: "43110".into(),
+            company_count: 50,
+            employee_count,
+            prev_employee_count: prev,
+            new_biz_count: 5,
+            closed_biz_count: 1,
+            avg_revenue_growth: None,
+            complex_utilization: None,
+        }
+    }
+
+    #[test]
+    fn test_employment_growth() {
+        assert_eq!(inputs(110, Some(100)).employment_growth(), Some(10.0));
+        assert_eq!(inputs(110, None).employment_growth(), None);
+        assert_eq!(inputs(110, Some(0)).employment_growth(), None);
+    }
+
+    #[test]
+    fn test_to_region_health_rates() {
+        let ym = YearMonth::parse("2024-03").unwrap();
+        let health = inputs(110, Some(100)).to_region_health(ym, 5).unwrap();
+        assert_eq!(health.year_month, "2024-03");
+        assert_eq!(health.new_biz_rate, Some(10.0));
+        assert_eq!(health.closure_rate, Some(2.0));
+        assert!((0.0..=100.0).contains(&health.health_score));
+        assert_eq!(health.score_version, RegionHealth::SCORE_VERSION);
+        assert_eq!(health.score_weights, None);
+    }
+
+    #[test]
+    fn test_out_of_range_utilization_is_treated_as_missing() {
+        let ym = YearMonth::parse("2024-03").unwrap();
+        let missing = inputs(110, Some(100)).to_region_health(ym, 5).unwrap();
+        let bad = RegionHealthInputs { complex_utilization: Some(250.0), ..inputs(110, Some(100)) };
+        let bad = bad.to_region_health(ym, 5).unwrap();
+
+        assert_eq!(bad.complex_utilization, None);
+        assert_eq!(bad.health_score, missing.health_score);
+    }
+
+    #[test]
+    fn test_region_without_complexes_or_financials_still_scores() {
+        let ym = YearMonth::parse("2024-03").unwrap();
+        let health = inputs(110, Some(100)).to_region_health(ym, 5).unwrap();
+
+        assert_eq!(health.complex_utilization, None);
+        assert_eq!(health.avg_revenue_growth, None);
+        assert_eq!(health.employment_growth, Some(10.0));
+        // 매출·산단 지표를 0으로 채우지 않고 고용·신규·폐업 가중치(0.75)로 다시 맞춤
+        // (0.30 × 1.0 + 0.25 × 0.5 + 0.20 × 0.9) / 0.75 × 100
+        let expected = (0.30 * 1.0 + 0.25 * 0.5 + 0.20 * 0.9) / 0.75 * 100.0;
+        assert!((health.health_score - expected).abs() < 1e-9);
+        assert!(health.health_score > 66.5);
+    }
+
+    #[test]
+    fn test_few_companies_flagged_insufficient() {
+        let ym = YearMonth::parse("2024-03").unwrap();
+        let few = RegionHealthInputs { company_count: 3, ..inputs(110, Some(100)) };
+        assert!(few.to_region_health(ym, 5).unwrap().insufficient_data);
+        assert!(!few.to_region_health(ym, 3).unwrap().insufficient_data);
+        assert!(!inputs(110, Some(100)).to_region_health(ym, 5).unwrap().insufficient_data);
+    }
+
+    #[test]
+    fn test_custom_weights() {
+        let ym = YearMonth::parse("2024-03").unwrap();
+        let employment_only: HealthWeights = "1,0,0,0,0".parse().unwrap();
+        let health = inputs(105, Some(100)).to_region_health_with(ym, 5, &employment_only).unwrap();
+        let health = health.unwrap();
+        assert_eq!(health.health_score, 75.0);
+        assert_eq!(health.score_weights.as_deref(), Some("1,0,0,0,0"));
+        let default = inputs(105, Some(100)).to_region_health_with(ym, 5, &HealthWeights::default());
+        assert_eq!(default.unwrap().unwrap().score_weights, None);
+
+        let unbalanced = HealthWeights { employment_growth: 0.5, ..HealthWeights::default() };
+        assert!(inputs(105, Some(100)).to_region_health_with(ym, 5, &unbalanced).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use tracing::info;
+use uuid::Uuid;
+
+/// CLI 명령 실행 1회
+#[derive(Debug, Clone, FromRow)]
+pub struct Job {
+    pub id: Uuid,
+    pub command: String,
+    pub args: serde_json::Value,
+    pub status: String,
+    pub fetched_count: Option<i32>,
+    pub written_count: Option<i32>,
+    pub error: Option<String>,
+    pub lock_contended: Option<bool>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// 명령이 완료 시 남기는 건수와 run lock 결과 (해당 없는 항목은 None)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobCounts {
+    pub fetched: Option<u32>,
+    pub written: Option<u32>,
+    /// run lock을 쓰는 명령만: 다른 실행이 락을 잡고 있었는지
+    pub lock_contended: Option<bool>,
+    /// 다른 실행이 락을 점유 중이라 아무것도 하지 않고 종료 (status = 'skipped')
+    pub skipped: bool,
+}
+
+const JOB_COLUMNS: &str = "id, command, args, status, fetched_count, written_count, error, lock_contended, \
+                           started_at, finished_at";
+
+pub async fn start_job(pool: &PgPool, command: &str, args: &[String]) -> anyhow::Result<Job> {
+    let job = sqlx::query_as::<_, Job>(&format!(
+        "INSERT INTO jobs (command, args) VALUES ($1, $2) RETURNING {}",
+        JOB_COLUMNS
+    ))
+    .bind(command)
+    .bind(serde_json::json!(args))
+    .fetch_one(pool)
+    .await?;
+
+    info!("Started job {} ({})", job.id, command);
+    Ok(job)
+}
+
+pub async fn complete_job(pool: &PgPool, id: Uuid, counts: JobCounts) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE jobs SET status = CASE WHEN $5 THEN 'skipped' ELSE 'completed' END,
+            fetched_count = $2, written_count = $3, lock_contended = $4, finished_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(counts.fetched.map(|n| n as i32))
+    .bind(counts.written.map(|n| n as i32))
+    .bind(counts.lock_contended)
+    .bind(counts.skipped)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 실패 기록, 실패 전까지의 건수도 함께 남긴다
+pub async fn fail_job(
+    pool: &PgPool,
+    id: Uuid,
+    counts: JobCounts,
+    error: &str,
+) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE jobs SET status = 'failed', fetched_count = $2, written_count = $3,
+            error = $4, lock_contended = $5, finished_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(counts.fetched.map(|n| n as i32))
+    .bind(counts.written.map(|n| n as i32))
+    .bind(error)
+    .bind(counts.lock_contended)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 최근 실행 순
+pub async fn recent_jobs(pool: &PgPool, limit: i64) -> anyhow::Result<Vec<Job>> {
+    let jobs = sqlx::query_as::<_, Job>(&format!(
+        "SELECT {} FROM jobs ORDER BY started_at DESC, id LIMIT $1",
+        JOB_COLUMNS
+    ))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(jobs)
+}
@@ -0,0 +1,283 @@
+use std::future::Future;
+use std::io::Write;
+use std::sync::Mutex;
+
+use kiep_core::models::EmploymentPoint;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::clients::kicox::KicoxTenant;
+use crate::clients::nps::NpsWorkplace;
+use crate::transform::normalize::{self, BizNoForm};
+
+use super::postgres::format_year_month;
+
+/// 기업 기본 정보 upsert 단위
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CompanyUpsert {
+    pub biz_no: String,
+    pub name: String,
+    pub industry_code: Option<String>,
+    /// 시군구 코드, 없으면 기존 값 유지
+    pub bjd_code: Option<String>,
+    /// 주소, 없으면 기존 값 유지 (바뀌면 좌표를 지우고 다시 지오코딩)
+    pub address: Option<String>,
+    /// 산단 ID, 없으면 기존 소속 유지 (바뀌면 company_history에 기록)
+    pub complex_id: Option<String>,
+    pub data_source: String,
+}
+
+/// 적재 대상 (Postgres, 메모리, NDJSON 등)
+/// 기업을 먼저 기록한 뒤 고용 시계열을 기록한다 (FK 순서)
+pub trait Loader {
+    /// 반환값: 기록한 행 수
+    fn upsert_companies(
+        &self,
+        companies: &[CompanyUpsert],
+    ) -> impl Future<Output = anyhow::Result<u32>> + Send;
+
+    fn upsert_employment(
+        &self,
+        points: &[EmploymentPoint],
+    ) -> impl Future<Output = anyhow::Result<u32>> + Send;
+}
+
+/// NPS 사업장 1건 → 기업 + (기준월이 있으면) 고용 시계열
+/// 사업자번호가 없거나 6자리 형식이 아니면, 또는 사업장명이 없으면 None
+pub fn nps_records(wp: &NpsWorkplace) -> Option<(CompanyUpsert, Option<EmploymentPoint>)> {
+    if wp.biz_reg_no.is_empty() || wp.name.is_empty() {
+        return None;
+    }
+
+    // 사업자번호 정규화 (NPS는 앞 6자리만 제공, 형식이 틀리면 건너뜀)
+    let biz_no = match normalize::normalize_and_validate(&wp.biz_reg_no, BizNoForm::Prefix) {
+        Ok(biz_no) => biz_no,
+        Err(e) => {
+            warn!("Skipping NPS workplace {}: {}", wp.name, e);
+            return None;
+        }
+    };
+
+    // 법정동코드 조합 (시도/시군구 코드가 없으면 기존 값 유지)
+    let bjd_code = (!wp.sido_code.is_empty() && !wp.sigungu_code.is_empty()).then(|| {
+        let bjd_code = format!("{}{}{}", wp.sido_code, wp.sigungu_code, wp.emd_code);
+        let bjd_normalized = normalize::normalize_bjd_code(&bjd_code);
+        normalize::extract_sigungu_code(&bjd_normalized)
+    });
+
+    // 기준월이 없거나 형식이 잘못되면 고용 시계열은 건너뛰고 기업 정보만 기록
+    let employment = match format_year_month(&wp.data_year_month) {
+        Ok(year_month) => Some(EmploymentPoint {
+            biz_no: biz_no.clone(),
+            year_month,
+            employee_count: wp.subscriber_count as i32,
+            new_hires: wp.new_subscribers as i32,
+            departures: wp.lost_subscribers as i32,
+        }),
+        Err(e) => {
+            if !wp.data_year_month.is_empty() {
+                warn!("Skipping employment for {}: {}", biz_no, e);
+            }
+            None
+        }
+    };
+
+    let address = wp.road_address.trim();
+    let company = CompanyUpsert {
+        biz_no,
+        name: wp.name.clone(),
+        industry_code: Some(wp.industry_name.clone()),
+        bjd_code,
+        address: (!address.is_empty()).then(|| address.to_string()),
+        complex_id: None,
+        data_source: "NPS".into(),
+    };
+    Some((company, employment))
+}
+
+/// NPS 사업장 목록을 변환해 loader에 기록, 반환값: 기록한 사업장 수
+pub async fn load_nps_workplaces<L: Loader>(
+    loader: &L,
+    workplaces: &[NpsWorkplace],
+) -> anyhow::Result<u32> {
+    let (companies, employment): (Vec<_>, Vec<_>) =
+        workplaces.iter().filter_map(nps_records).unzip();
+    let employment: Vec<EmploymentPoint> = employment.into_iter().flatten().collect();
+
+    let count = loader.upsert_companies(&companies).await?;
+    loader.upsert_employment(&employment).await?;
+
+    info!("Upserted {} NPS workplaces", count);
+    Ok(count)
+}
+
+/// KICOX 입주기업 1건 → 산단 소속이 있는 기업
+/// 사업자번호가 10자리 형식이 아니거나 업체명/단지코드가 없으면 None
+pub fn kicox_tenant_record(tenant: &KicoxTenant) -> Option<CompanyUpsert> {
+    let complex_code = tenant.complex_code.trim();
+    let name = tenant.name.trim();
+    if complex_code.is_empty() || name.is_empty() {
+        return None;
+    }
+    let biz_no = match normalize::normalize_and_validate(&tenant.biz_no, BizNoForm::Full) {
+        Ok(biz_no) => biz_no,
+        Err(e) => {
+            warn!("Skipping KICOX tenant {}: {}", name, e);
+            return None;
+        }
+    };
+    Some(CompanyUpsert {
+        biz_no,
+        name: name.to_string(),
+        industry_code: None,
+        bjd_code: None,
+        address: None,
+        complex_id: Some(complex_code.to_string()),
+        data_source: "KICOX".into(),
+    })
+}
+
+/// KICOX 입주기업 목록을 loader에 기록 (산단 소속이 바뀌면 이력이 남는다), 반환값: 기록한 기업 수
+pub async fn load_kicox_tenants<L: Loader>(loader: &L, tenants: &[KicoxTenant]) -> anyhow::Result<u32> {
+    let companies: Vec<CompanyUpsert> = tenants.iter().filter_map(kicox_tenant_record).collect();
+    let count = loader.upsert_companies(&companies).await?;
+    info!("Upserted {} KICOX tenants", count);
+    Ok(count)
+}
+
+/// 기록 내용을 메모리에 모으는 loader (테스트용)
+#[derive(Default)]
+pub struct MemoryLoader {
+    pub companies: Mutex<Vec<CompanyUpsert>>,
+    pub employment: Mutex<Vec<EmploymentPoint>>,
+}
+
+impl Loader for MemoryLoader {
+    async fn upsert_companies(&self, companies: &[CompanyUpsert]) -> anyhow::Result<u32> {
+        self.companies.lock().unwrap().extend_from_slice(companies);
+        Ok(companies.len() as u32)
+    }
+
+    async fn upsert_employment(&self, points: &[EmploymentPoint]) -> anyhow::Result<u32> {
+        self.employment.lock().unwrap().extend_from_slice(points);
+        Ok(points.len() as u32)
+    }
+}
+
+/// 한 줄에 레코드 하나씩 JSON으로 쓰는 loader (`{"kind": "company", ...}`)
+pub struct NdjsonLoader<W> {
+    writer: Mutex<W>,
+}
+
+impl<W: Write + Send> NdjsonLoader<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer: Mutex::new(writer) }
+    }
+
+    fn write_all<T: Serialize>(&self, kind: &str, records: &[T]) -> anyhow::Result<u32> {
+        let mut writer = self.writer.lock().unwrap();
+        for record in records {
+            #[derive(Serialize)]
+            struct Line<'a, T> {
+                kind: &'a str,
+                #[serde(flatten)]
+                record: &'a T,
+            }
+            serde_json::to_writer(&mut *writer, &Line { kind, record })?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(records.len() as u32)
+    }
+}
+
+impl<W: Write + Send> Loader for NdjsonLoader<W> {
+    async fn upsert_companies(&self, companies: &[CompanyUpsert]) -> anyhow::Result<u32> {
+        self.write_all("company", companies)
+    }
+
+    async fn upsert_employment(&self, points: &[EmploymentPoint]) -> anyhow::Result<u32> {
+        self.write_all("employment", points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workplace(biz_reg_no: &str, name: &str, ym: &str) -> NpsWorkplace {
+        serde_json::from_value(serde_json::json!({
+            "wkplNm": name,
+            "bzowrRgstNo": biz_reg_no,
+            "jnngpCnt": 12,
+            "ldongAddrMgplDgCd": "43",
+            "ldongAddrMgplSgguCd": "111",
+            "wkplRoadNmDtlAddr": " 충청북도 청주시 상당구 상당로 155 ",
+            "dataCrtYm": ym,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_load_into_memory_without_database() {
+        let workplaces = [
+            workplace("123456", "청주정밀", "202401"),
+            workplace("", "이름만", "202401"),
+            workplace("12345", "번호오류", "202401"),
+            workplace("654321", "기준월없음", ""),
+            workplace("777777", "기준월오류", "20241"),
+        ];
+
+        let loader = MemoryLoader::default();
+        let count = load_nps_workplaces(&loader, &workplaces).await.unwrap();
+        assert_eq!(count, 3);
+
+        let companies = loader.companies.lock().unwrap();
+        assert_eq!(companies[0].biz_no, "0000123456");
+        assert_eq!(companies[0].bjd_code.as_deref(), Some("43111"));
+        assert_eq!(companies[0].address.as_deref(), Some("충청북도 청주시 상당구 상당로 155"));
+
+        let employment = loader.employment.lock().unwrap();
+        assert_eq!(employment.len(), 1);
+        assert_eq!(employment[0].year_month, "2024-01");
+        assert_eq!(employment[0].employee_count, 12);
+    }
+
+    #[tokio::test]
+    async fn test_kicox_tenants_carry_complex_id() {
+        let tenant = |complex_code: &str, biz_no: &str, name: &str| KicoxTenant {
+            complex_code: complex_code.into(),
+            biz_no: biz_no.into(),
+            name: name.into(),
+        };
+        let tenants = [
+            tenant("A001", "220-81-62517", "오창정밀"),
+            tenant("A001", "123456", "앞자리만"),
+            tenant("", "2208162517", "단지없음"),
+        ];
+
+        let loader = MemoryLoader::default();
+        assert_eq!(load_kicox_tenants(&loader, &tenants).await.unwrap(), 1);
+
+        let companies = loader.companies.lock().unwrap();
+        assert_eq!(companies[0].biz_no, "2208162517");
+        assert_eq!(companies[0].complex_id.as_deref(), Some("A001"));
+        assert_eq!(companies[0].data_source, "KICOX");
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_writes_one_line_per_record() {
+        let loader = NdjsonLoader::new(Vec::new());
+        load_nps_workplaces(&loader, &[workplace("123456", "청주정밀", "202401")])
+            .await
+            .unwrap();
+
+        let out = String::from_utf8(loader.writer.into_inner().unwrap()).unwrap();
+        let lines: Vec<serde_json::Value> =
+            out.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["kind"], "company");
+        assert_eq!(lines[1]["kind"], "employment");
+        assert_eq!(lines[1]["year_month"], "2024-01");
+    }
+}
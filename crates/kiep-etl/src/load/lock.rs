@@ -0,0 +1,68 @@
+use sqlx::pool::PoolConnection;
+use sqlx::{PgPool, Postgres};
+use tracing::{info, warn};
+
+/// fetch+upsert 실행 단위 advisory lock
+/// 세션 단위 락이므로 전용 커넥션을 락 해제 시점까지 붙잡아 둔다
+pub struct RunLock {
+    conn: Option<PoolConnection<Postgres>>,
+    key: String,
+    /// 획득 시점에 다른 실행이 이미 락을 잡고 있었는지
+    pub contended: bool,
+}
+
+/// `source:scope` 키로 advisory lock 획득
+/// 다른 실행이 점유 중이면 `wait=true`일 때 해제될 때까지 대기, 아니면 `None` 반환
+pub async fn acquire_run_lock(
+    pool: &PgPool,
+    source: &str,
+    scope: &str,
+    wait: bool,
+) -> anyhow::Result<Option<RunLock>> {
+    let key = format!("{}:{}", source, scope);
+    let mut conn = pool.acquire().await?;
+
+    let acquired: bool =
+        sqlx::query_scalar("SELECT pg_try_advisory_lock(hashtext('kiep_etl'), hashtext($1))")
+            .bind(&key)
+            .fetch_one(&mut *conn)
+            .await?;
+
+    if acquired {
+        return Ok(Some(RunLock { conn: Some(conn), key, contended: false }));
+    }
+
+    if !wait {
+        warn!("Run lock {} is held by another process", key);
+        return Ok(None);
+    }
+
+    info!("Waiting for run lock {}", key);
+    sqlx::query("SELECT pg_advisory_lock(hashtext('kiep_etl'), hashtext($1))")
+        .bind(&key)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(Some(RunLock { conn: Some(conn), key, contended: true }))
+}
+
+impl RunLock {
+    pub async fn release(mut self) -> anyhow::Result<()> {
+        if let Some(mut conn) = self.conn.take() {
+            sqlx::query("SELECT pg_advisory_unlock(hashtext('kiep_etl'), hashtext($1))")
+                .bind(&self.key)
+                .execute(&mut *conn)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for RunLock {
+    fn drop(&mut self) {
+        // release() 없이 drop되면 커넥션을 풀에서 분리해 세션 종료와 함께 락이 풀리도록 함
+        if let Some(conn) = self.conn.take() {
+            drop(conn.detach());
+        }
+    }
+}
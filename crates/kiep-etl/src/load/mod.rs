@@ -1 +1,8 @@
+pub mod batch;
+pub mod health;
+pub mod jobs;
+pub mod loader;
+pub mod lock;
 pub mod postgres;
+pub mod retry;
+pub mod source_status;
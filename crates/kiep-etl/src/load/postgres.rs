@@ -1,81 +1,860 @@
-use sqlx::PgPool;
-use tracing::info;
+use std::collections::HashMap;
+use std::time::Instant;
 
+use futures::{Stream, TryStreamExt};
+use kiep_core::models::{BizStatus, CodeLabel, ComplexSeriesPoint, ComplexType, EmploymentPoint};
+use kiep_core::period::{YearMonth, YearQuarter};
+use sqlx::postgres::{PgArguments, PgQueryResult};
+use sqlx::query::Query;
+use sqlx::{PgPool, Postgres, Transaction};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::clients::fsc::FscFinancial;
+use crate::clients::kicox::{KicoxComplex, KicoxTenant};
 use crate::clients::nps::NpsWorkplace;
+use crate::clients::pps::PpsContract;
+use crate::clients::vworld::VworldClient;
+use crate::transform::complexes::dedupe_complexes;
+use crate::transform::financials::fsc_to_financials;
 use crate::transform::normalize;
+use crate::transform::procurement::pps_to_procurement;
+
+use super::loader::{load_kicox_tenants, load_nps_workplaces, CompanyUpsert, Loader};
+use super::retry::DbRetry;
+
+/// Postgres 적재, 기록한 행에는 `batch_id`를 남김 (RollbackBatch용)
+/// 풀에 바로 쓰면 쿼리마다 일시적 오류에 한해 `DbRetry` 설정대로 재시도
+/// `begin`으로 만들면 모든 쓰기가 한 트랜잭션 안에서 이뤄지고 `commit` 전에 실패/종료하면 전부 롤백
+/// (트랜잭션 안에서는 오류 후 재시도할 수 없으므로 재시도하지 않는다)
+pub struct PgLoader<'a> {
+    pool: &'a PgPool,
+    batch_id: Uuid,
+    retry: DbRetry,
+    tx: Option<Mutex<Transaction<'a, Postgres>>>,
+}
+
+impl<'a> PgLoader<'a> {
+    pub fn new(pool: &'a PgPool, batch_id: Uuid) -> Self {
+        Self { pool, batch_id, retry: DbRetry::shared(), tx: None }
+    }
+
+    /// 트랜잭션 적재 시작
+    pub async fn begin(pool: &'a PgPool, batch_id: Uuid) -> anyhow::Result<Self> {
+        let tx = pool.begin().await?;
+        Ok(Self { tx: Some(Mutex::new(tx)), ..Self::new(pool, batch_id) })
+    }
+
+    /// 트랜잭션 적재 확정 (풀 적재면 아무 일도 하지 않음)
+    pub async fn commit(self) -> anyhow::Result<()> {
+        if let Some(tx) = self.tx {
+            tx.into_inner().commit().await?;
+        }
+        Ok(())
+    }
+
+    pub fn with_retry(mut self, retry: DbRetry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    async fn execute<'q, F>(&self, query: F) -> Result<PgQueryResult, sqlx::Error>
+    where
+        F: Fn() -> Query<'q, Postgres, PgArguments>,
+    {
+        match &self.tx {
+            Some(tx) => query().execute(&mut **tx.lock().await).await,
+            None => self.retry.run(|| query().execute(self.pool)).await,
+        }
+    }
+}
+
+/// UNNEST 한 번에 넣는 행 수
+const PG_BATCH_SIZE: usize = 1000;
+
+/// 같은 batch 안에 같은 키가 두 번 있으면 ON CONFLICT가 실패하므로 먼저 합친다
+/// 행 단위로 차례로 upsert했을 때와 같은 규칙 (이름은 비었거나 더 짧으면 유지, 법정동코드/주소/산단은 없으면 유지,
+/// 업종/출처는 처음 값)
+fn dedupe_company_upserts(companies: &[CompanyUpsert]) -> Vec<CompanyUpsert> {
+    let mut out: Vec<CompanyUpsert> = Vec::with_capacity(companies.len());
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for company in companies {
+        let Some(&i) = index.get(company.biz_no.as_str()) else {
+            index.insert(&company.biz_no, out.len());
+            out.push(company.clone());
+            continue;
+        };
+        let existing = &mut out[i];
+        let new_name = company.name.trim();
+        if !new_name.is_empty() && new_name.chars().count() >= existing.name.chars().count() {
+            existing.name = company.name.clone();
+        }
+        if company.bjd_code.as_deref().is_some_and(|c| !c.is_empty()) {
+            existing.bjd_code = company.bjd_code.clone();
+        }
+        if company.address.as_deref().is_some_and(|a| !a.trim().is_empty()) {
+            existing.address = company.address.clone();
+        }
+        if company.complex_id.is_some() {
+            existing.complex_id = company.complex_id.clone();
+        }
+    }
+    out
+}
+
+/// (biz_no, year_month)가 겹치면 나중 값
+fn dedupe_employment(points: &[EmploymentPoint]) -> Vec<EmploymentPoint> {
+    let mut out: Vec<EmploymentPoint> = Vec::with_capacity(points.len());
+    let mut index: HashMap<(&str, &str), usize> = HashMap::new();
+    for point in points {
+        match index.get(&(point.biz_no.as_str(), point.year_month.as_str())) {
+            Some(&i) => out[i] = point.clone(),
+            None => {
+                index.insert((&point.biz_no, &point.year_month), out.len());
+                out.push(point.clone());
+            }
+        }
+    }
+    out
+}
+
+impl Loader for PgLoader<'_> {
+    /// PG_BATCH_SIZE개씩 UNNEST로 한 번에 upsert, 반환값은 입력 행 수 (중복 포함)
+    async fn upsert_companies(&self, companies: &[CompanyUpsert]) -> anyhow::Result<u32> {
+        let started = Instant::now();
+        let deduped = dedupe_company_upserts(companies);
+        for chunk in deduped.chunks(PG_BATCH_SIZE) {
+            let biz_nos: Vec<&str> = chunk.iter().map(|c| c.biz_no.as_str()).collect();
+            let names: Vec<&str> = chunk.iter().map(|c| c.name.as_str()).collect();
+            let industry_codes: Vec<Option<&str>> = chunk.iter().map(|c| c.industry_code.as_deref()).collect();
+            let bjd_codes: Vec<Option<&str>> = chunk.iter().map(|c| c.bjd_code.as_deref()).collect();
+            let addresses: Vec<Option<&str>> = chunk.iter().map(|c| c.address.as_deref()).collect();
+            let sources: Vec<&str> = chunk.iter().map(|c| c.data_source.as_str()).collect();
+
+            // 재수집 시 기존의 더 나은 값을 덮어쓰지 않음:
+            // - 이름: 새 값이 비었거나, 기존 이름이 더 길면(NPS 이름 잘림) 유지
+            // - 법정동코드/주소: 새 값이 없으면 유지, 주소가 바뀌면 좌표를 지워 다시 지오코딩
+            self.execute(|| sqlx::query(
+                r#"
+                INSERT INTO companies (biz_no, name, industry_code, bjd_code, address, data_source, load_batch_id)
+                SELECT t.biz_no, t.name, t.industry_code, t.bjd_code, NULLIF(BTRIM(t.address), ''), t.data_source, $7
+                FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[])
+                    AS t(biz_no, name, industry_code, bjd_code, address, data_source)
+                ON CONFLICT (biz_no) DO UPDATE SET
+                    name = CASE
+                        WHEN NULLIF(BTRIM(EXCLUDED.name), '') IS NULL THEN companies.name
+                        WHEN char_length(EXCLUDED.name) < char_length(companies.name) THEN companies.name
+                        ELSE EXCLUDED.name
+                    END,
+                    bjd_code = COALESCE(NULLIF(EXCLUDED.bjd_code, ''), companies.bjd_code),
+                    address = COALESCE(EXCLUDED.address, companies.address),
+                    coordinates = CASE
+                        WHEN EXCLUDED.address IS DISTINCT FROM companies.address AND EXCLUDED.address IS NOT NULL
+                        THEN NULL ELSE companies.coordinates
+                    END,
+                    geocode_checked_at = CASE
+                        WHEN EXCLUDED.address IS DISTINCT FROM companies.address AND EXCLUDED.address IS NOT NULL
+                        THEN NULL ELSE companies.geocode_checked_at
+                    END,
+                    load_batch_id = EXCLUDED.load_batch_id,
+                    updated_at = NOW()
+                "#,
+            )
+            .bind(&biz_nos)
+            .bind(&names)
+            .bind(&industry_codes)
+            .bind(&bjd_codes)
+            .bind(&addresses)
+            .bind(&sources)
+            .bind(self.batch_id))
+            .await?;
+        }
+        // 산단 소속 변경은 이력을 남겨야 해서 행 단위 (산단 정보가 있는 행만)
+        for company in &deduped {
+            if let Some(complex_id) = &company.complex_id {
+                self.execute(|| complex_id_update(&company.biz_no, Some(complex_id), &company.data_source))
+                    .await?;
+            }
+        }
+        info!(
+            "Upserted {} companies in {} statements, {}ms",
+            deduped.len(),
+            deduped.len().div_ceil(PG_BATCH_SIZE),
+            started.elapsed().as_millis()
+        );
+        Ok(companies.len() as u32)
+    }
+
+    /// PG_BATCH_SIZE개씩 UNNEST로 한 번에 upsert, 반환값은 입력 행 수 (중복 포함)
+    async fn upsert_employment(&self, points: &[EmploymentPoint]) -> anyhow::Result<u32> {
+        let started = Instant::now();
+        let deduped = dedupe_employment(points);
+        for chunk in deduped.chunks(PG_BATCH_SIZE) {
+            let biz_nos: Vec<&str> = chunk.iter().map(|p| p.biz_no.as_str()).collect();
+            let year_months: Vec<&str> = chunk.iter().map(|p| p.year_month.as_str()).collect();
+            let employee_counts: Vec<i32> = chunk.iter().map(|p| p.employee_count).collect();
+            let new_hires: Vec<i32> = chunk.iter().map(|p| p.new_hires).collect();
+            let departures: Vec<i32> = chunk.iter().map(|p| p.departures).collect();
+
+            self.execute(|| sqlx::query(
+                r#"
+                INSERT INTO employment_series (biz_no, year_month, employee_count, new_hires, departures, load_batch_id)
+                SELECT t.biz_no, t.year_month, t.employee_count, t.new_hires, t.departures, $6
+                FROM UNNEST($1::text[], $2::text[], $3::int[], $4::int[], $5::int[])
+                    AS t(biz_no, year_month, employee_count, new_hires, departures)
+                ON CONFLICT (biz_no, year_month) DO UPDATE SET
+                    employee_count = EXCLUDED.employee_count,
+                    new_hires = EXCLUDED.new_hires,
+                    departures = EXCLUDED.departures,
+                    load_batch_id = EXCLUDED.load_batch_id
+                "#,
+            )
+            .bind(&biz_nos)
+            .bind(&year_months)
+            .bind(&employee_counts)
+            .bind(&new_hires)
+            .bind(&departures)
+            .bind(self.batch_id))
+            .await?;
+        }
+        info!(
+            "Upserted {} employment points in {} statements, {}ms",
+            deduped.len(),
+            deduped.len().div_ceil(PG_BATCH_SIZE),
+            started.elapsed().as_millis()
+        );
+        Ok(points.len() as u32)
+    }
+}
 
 /// NPS 사업장 데이터를 companies + employment_series에 upsert
+/// 기록한 행에는 `batch_id`를 남김 (RollbackBatch용)
+/// 한 트랜잭션으로 적재하므로 실패하면 이 호출분은 아무것도 남지 않는다
 pub async fn upsert_nps_workplaces(
     pool: &PgPool,
     workplaces: &[NpsWorkplace],
+    batch_id: Uuid,
+) -> anyhow::Result<u32> {
+    let loader = PgLoader::begin(pool, batch_id).await?;
+    let written = load_nps_workplaces(&loader, workplaces).await?;
+    loader.commit().await?;
+    Ok(written)
+}
+
+/// KICOX 입주기업으로 기업 산단 소속 갱신, 소속이 바뀐 기업은 company_history에 기록
+/// 한 트랜잭션으로 적재하므로 실패하면 이 호출분은 아무것도 남지 않는다
+pub async fn upsert_complex_tenants(
+    pool: &PgPool,
+    tenants: &[KicoxTenant],
+    batch_id: Uuid,
+) -> anyhow::Result<u32> {
+    let loader = PgLoader::begin(pool, batch_id).await?;
+    let written = load_kicox_tenants(&loader, tenants).await?;
+    loader.commit().await?;
+    Ok(written)
+}
+
+/// 지역 수집 페이지 전체를 한 트랜잭션으로 적재, 모든 페이지가 성공해야 commit
+/// 페이지 수집이나 적재 중 하나라도 실패하면 롤백되어 employment_series가 반쯤 채워진 채 남지 않는다
+/// 반환값: (수집 건수, 기록 건수)
+pub async fn load_region_atomically(
+    pool: &PgPool,
+    batch_id: Uuid,
+    pages: impl Stream<Item = anyhow::Result<Vec<NpsWorkplace>>>,
+) -> anyhow::Result<(u32, u32)> {
+    let mut pages = std::pin::pin!(pages);
+    let loader = PgLoader::begin(pool, batch_id).await?;
+    let mut fetched = 0u32;
+    let mut written = 0u32;
+
+    while let Some(page) = pages.try_next().await? {
+        fetched += page.len() as u32;
+        written += load_nps_workplaces(&loader, &page).await?;
+    }
+    loader.commit().await?;
+    Ok((fetched, written))
+}
+
+/// KICOX 산업단지를 industrial_complexes에 upsert하고 생산/수출/고용은 complex_series에 기록
+/// 같은 단지코드는 `dedupe_complexes`로 먼저 병합, 단지코드가 없거나 유형을 알 수 없는 행은 건너뜀
+/// 시계열 분기는 기준 시점(stdrYm), 없으면 적재 시점의 분기. 반환값: upsert한 단지 수
+pub async fn upsert_industrial_complexes(
+    pool: &PgPool,
+    complexes: &[KicoxComplex],
 ) -> anyhow::Result<u32> {
+    let (complexes, _) = dedupe_complexes(complexes.to_vec());
+    let retry = DbRetry::shared();
+    let current_quarter = YearQuarter::from_year_month(YearMonth::current());
     let mut count = 0u32;
 
-    for wp in workplaces {
-        if wp.biz_reg_no.is_empty() || wp.name.is_empty() {
+    for complex in &complexes {
+        if complex.complex_code.is_empty() {
+            warn!("Skipping KICOX complex without code: {}", complex.name);
             continue;
         }
+        let complex_type = match ComplexType::try_from(complex.complex_type.as_str()) {
+            Ok(t) => t,
+            Err(e) => {
+                warn!("Skipping KICOX complex {}: {}", complex.complex_code, e);
+                continue;
+            }
+        };
+        let as_i32 = |v: Option<u32>| v.map(|v| v as i32);
 
-        // 사업자번호 정규화 (NPS는 앞 6자리만 제공)
-        let biz_no_prefix = normalize::normalize_biz_no(&wp.biz_reg_no);
-
-        // 법정동코드 조합
-        let bjd_code = format!("{}{}{}", wp.sido_code, wp.sigungu_code, wp.emd_code);
-        let bjd_normalized = normalize::normalize_bjd_code(&bjd_code);
-        let sigungu_code = normalize::extract_sigungu_code(&bjd_normalized);
-
-        // companies upsert
-        sqlx::query(
+        retry.run(|| sqlx::query(
             r#"
-            INSERT INTO companies (biz_no, name, industry_code, bjd_code, data_source)
-            VALUES ($1, $2, $3, $4, 'NPS')
-            ON CONFLICT (biz_no) DO UPDATE SET
+            INSERT INTO industrial_complexes (id, name, complex_type, province, sigungu,
+                designated_area, industrial_area, tenant_count, operating_count, occupancy_rate)
+            VALUES ($1, $2, $3, $4, NULLIF($5, ''), $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
                 name = EXCLUDED.name,
-                bjd_code = EXCLUDED.bjd_code,
+                complex_type = EXCLUDED.complex_type,
+                province = EXCLUDED.province,
+                sigungu = COALESCE(EXCLUDED.sigungu, industrial_complexes.sigungu),
+                designated_area = EXCLUDED.designated_area,
+                industrial_area = EXCLUDED.industrial_area,
+                tenant_count = EXCLUDED.tenant_count,
+                operating_count = EXCLUDED.operating_count,
+                occupancy_rate = EXCLUDED.occupancy_rate,
                 updated_at = NOW()
             "#,
         )
-        .bind(&biz_no_prefix)
-        .bind(&wp.name)
-        .bind(&wp.industry_name)
-        .bind(&sigungu_code)
-        .execute(pool)
+        .bind(&complex.complex_code)
+        .bind(&complex.name)
+        .bind(complex_type.code())
+        .bind(&complex.province)
+        .bind(&complex.sigungu)
+        .bind(complex.designated_area)
+        .bind(complex.industrial_area)
+        .bind(as_i32(complex.tenant_count))
+        .bind(as_i32(complex.operating_count))
+        .bind(complex.occupancy_rate)
+        .execute(pool))
         .await?;
 
-        // employment_series upsert
-        if !wp.data_year_month.is_empty() {
-            let year_month = format_year_month(&wp.data_year_month);
-            sqlx::query(
+        let series = complex.to_series_point().unwrap_or_else(|| ComplexSeriesPoint {
+            complex_id: complex.complex_code.clone(),
+            year_quarter: current_quarter,
+            production: complex.production,
+            export_amount: complex.export_amount,
+            employment: as_i32(complex.employment),
+            operating_count: as_i32(complex.operating_count),
+        });
+        if series.production.is_some() || series.export_amount.is_some() || series.employment.is_some() {
+            retry.run(|| sqlx::query(
                 r#"
-                INSERT INTO employment_series (biz_no, year_month, employee_count, new_hires, departures)
-                VALUES ($1, $2, $3, $4, $5)
-                ON CONFLICT (biz_no, year_month) DO UPDATE SET
-                    employee_count = EXCLUDED.employee_count,
-                    new_hires = EXCLUDED.new_hires,
-                    departures = EXCLUDED.departures
+                INSERT INTO complex_series (complex_id, year_quarter, production, export_amount,
+                    employment, operating_count)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (complex_id, year_quarter) DO UPDATE SET
+                    production = EXCLUDED.production,
+                    export_amount = EXCLUDED.export_amount,
+                    employment = EXCLUDED.employment,
+                    operating_count = EXCLUDED.operating_count
                 "#,
             )
-            .bind(&biz_no_prefix)
-            .bind(&year_month)
-            .bind(wp.subscriber_count as i32)
-            .bind(wp.new_subscribers as i32)
-            .bind(wp.lost_subscribers as i32)
-            .execute(pool)
+            .bind(&series.complex_id)
+            .bind(series.year_quarter)
+            .bind(series.production)
+            .bind(series.export_amount)
+            .bind(series.employment)
+            .bind(series.operating_count)
+            .execute(pool))
             .await?;
         }
-
         count += 1;
     }
+    Ok(count)
+}
 
-    info!("Upserted {} NPS workplaces", count);
+/// FSC 계정과목 행을 (구분, 회계연도, 분기)별로 묶어 financials에 upsert
+/// biz_no는 companies.corp_no로 찾고, 없으면 경고 후 건너뜀 (고아 행 방지)
+/// fiscal_year와 다른 연도의 행(결산일 기준)은 버린다. 반환값: upsert한 행 수
+pub async fn upsert_financials(
+    pool: &PgPool,
+    corp_no: &str,
+    fiscal_year: i32,
+    items: &[FscFinancial],
+) -> anyhow::Result<u32> {
+    let corp_no = normalize::normalize_corp_no(corp_no);
+    let retry = DbRetry::shared();
+    let biz_no: Option<String> = retry.run(|| sqlx::query_scalar(
+        r#"
+        SELECT biz_no FROM companies
+        WHERE REPLACE(corp_no, '-', '') = $1
+        ORDER BY biz_no
+        LIMIT 1
+        "#,
+    )
+    .bind(&corp_no)
+    .fetch_optional(pool))
+    .await?;
+    let Some(biz_no) = biz_no else {
+        warn!("No company with corp_no {}, skipping {} FSC rows", corp_no, items.len());
+        return Ok(0);
+    };
+
+    let mut count = 0u32;
+    for f in fsc_to_financials(&biz_no, items) {
+        if f.fiscal_year != fiscal_year {
+            continue;
+        }
+        retry.run(|| sqlx::query(
+            r#"
+            INSERT INTO financials (biz_no, fiscal_year, quarter, statement_type, revenue,
+                operating_income, net_income, total_assets, total_equity, total_debt, data_source)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 'FSC')
+            ON CONFLICT (biz_no, fiscal_year, quarter, statement_type) DO UPDATE SET
+                revenue = EXCLUDED.revenue,
+                operating_income = EXCLUDED.operating_income,
+                net_income = EXCLUDED.net_income,
+                total_assets = EXCLUDED.total_assets,
+                total_equity = EXCLUDED.total_equity,
+                total_debt = EXCLUDED.total_debt,
+                data_source = EXCLUDED.data_source
+            "#,
+        )
+        .bind(&f.biz_no)
+        .bind(f.fiscal_year)
+        .bind(f.quarter)
+        .bind(f.statement_type.as_str())
+        .bind(f.revenue)
+        .bind(f.operating_income)
+        .bind(f.net_income)
+        .bind(f.total_assets)
+        .bind(f.total_equity)
+        .bind(f.total_debt)
+        .execute(pool))
+        .await?;
+        count += 1;
+    }
     Ok(count)
 }
 
-/// "202401" → "2024-01"
-fn format_year_month(raw: &str) -> String {
-    if raw.len() >= 6 {
-        format!("{}-{}", &raw[..4], &raw[4..6])
-    } else {
-        raw.to_string()
+/// PPS 계약을 procurement에 upsert ((입찰공고번호, 계약번호) 기준)
+/// 계약업체가 companies에 없어도 금액/기관 정보는 쓸모가 있어 저장하되 biz_no는 NULL,
+/// 원래 번호는 contractor_biz_no에 남긴다. 두 번호가 모두 없거나 사업자번호가 잘못된 행은 건너뜀
+/// 한 트랜잭션으로 적재하므로 실패하면 이 호출분은 아무것도 남지 않는다. 반환값: upsert한 행 수
+pub async fn upsert_procurements(pool: &PgPool, contracts: &[PpsContract]) -> anyhow::Result<u32> {
+    // procurement에는 load_batch_id가 없어 배치와 연결하지 않는다
+    let loader = PgLoader::begin(pool, Uuid::nil()).await?;
+    let written = loader.upsert_procurements(contracts).await?;
+    loader.commit().await?;
+    Ok(written)
+}
+
+impl PgLoader<'_> {
+    async fn upsert_procurements(&self, contracts: &[PpsContract]) -> anyhow::Result<u32> {
+        let mut count = 0u32;
+        for contract in contracts {
+            let row = pps_to_procurement(contract);
+            if row.bid_no.is_empty() && row.contract_no.is_empty() {
+                warn!("Skipping PPS contract without bid/contract number: {}", row.title);
+                continue;
+            }
+            if !row.has_valid_contractor() {
+                warn!(
+                    "Skipping PPS contract {}/{} with invalid contractor biz_no {:?}",
+                    row.bid_no, row.contract_no, contract.biz_no
+                );
+                continue;
+            }
+            self.execute(|| sqlx::query(
+                r#"
+                INSERT INTO procurement (bid_no, contract_no, title, biz_no, contractor_biz_no,
+                    contract_type, amount, contract_date, agency)
+                VALUES ($1, $2, $3, (SELECT biz_no FROM companies WHERE biz_no = $4), $4, $5, $6, $7, $8)
+                ON CONFLICT (bid_no, contract_no) DO UPDATE SET
+                    title = EXCLUDED.title,
+                    biz_no = EXCLUDED.biz_no,
+                    contractor_biz_no = EXCLUDED.contractor_biz_no,
+                    contract_type = EXCLUDED.contract_type,
+                    amount = EXCLUDED.amount,
+                    contract_date = EXCLUDED.contract_date,
+                    agency = EXCLUDED.agency,
+                    updated_at = NOW()
+                "#,
+            )
+            .bind(&row.bid_no)
+            .bind(&row.contract_no)
+            .bind(&row.title)
+            .bind(&row.contractor_biz_no)
+            .bind(&row.contract_type)
+            .bind(row.amount)
+            .bind(row.contract_date)
+            .bind(&row.agency))
+            .await?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// 사업자 상태 갱신, 실제로 바뀐 경우에만 company_history에 기록
+/// 반환값: 상태 변경 여부
+pub async fn update_biz_status(
+    pool: &PgPool,
+    biz_no: &str,
+    status: &BizStatus,
+    reason: Option<&str>,
+    source: &str,
+) -> anyhow::Result<bool> {
+    let result = DbRetry::shared().run(|| sqlx::query(
+        r#"
+        WITH prev AS (
+            SELECT biz_no, biz_status FROM companies WHERE biz_no = $1 FOR UPDATE
+        ),
+        upd AS (
+            UPDATE companies c SET biz_status = $2, updated_at = NOW()
+            FROM prev
+            WHERE c.biz_no = prev.biz_no AND prev.biz_status IS DISTINCT FROM $2
+            RETURNING c.biz_no, prev.biz_status as old_status
+        )
+        INSERT INTO company_history (biz_no, field, old_value, new_value, reason, source)
+        SELECT biz_no, 'biz_status', old_status, $2, $3, $4 FROM upd
+        "#,
+    )
+    .bind(biz_no)
+    .bind(status.as_str())
+    .bind(reason)
+    .bind(source)
+    .execute(pool))
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 산단 소속 갱신, 실제로 바뀐 경우에만 company_history에 기록 (field = 'complex_id')
+/// 반환값: 소속 변경 여부
+pub async fn update_complex_id(
+    pool: &PgPool,
+    biz_no: &str,
+    complex_id: Option<&str>,
+    source: &str,
+) -> anyhow::Result<bool> {
+    let result = DbRetry::shared()
+        .run(|| complex_id_update(biz_no, complex_id, source).execute(pool))
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+fn complex_id_update<'q>(
+    biz_no: &'q str,
+    complex_id: Option<&'q str>,
+    source: &'q str,
+) -> Query<'q, Postgres, PgArguments> {
+    sqlx::query(
+        r#"
+        WITH prev AS (
+            SELECT biz_no, complex_id FROM companies WHERE biz_no = $1 FOR UPDATE
+        ),
+        upd AS (
+            UPDATE companies c SET complex_id = $2, updated_at = NOW()
+            FROM prev
+            WHERE c.biz_no = prev.biz_no AND prev.complex_id IS DISTINCT FROM $2
+            RETURNING c.biz_no, prev.complex_id as old_complex
+        )
+        INSERT INTO company_history (biz_no, field, old_value, new_value, source)
+        SELECT biz_no, 'complex_id', old_complex, $2, $3 FROM upd
+        "#,
+    )
+    .bind(biz_no)
+    .bind(complex_id)
+    .bind(source)
+}
+
+/// NTS 상태 확인 시각 기록
+pub async fn mark_status_checked(pool: &PgPool, biz_nos: &[String]) -> anyhow::Result<u64> {
+    let result = DbRetry::shared().run(|| sqlx::query(
+        r#"
+        UPDATE companies SET status_checked_at = NOW()
+        WHERE biz_no = ANY($1)
+        "#,
+    )
+    .bind(biz_nos)
+    .execute(pool))
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// 좌표 보강 결과
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GeocodeSummary {
+    pub attempted: u32,
+    pub located: u32,
+    pub unresolved: u32,
+}
+
+/// 지오코딩 대기 행: 아직 시도하지 않은 행 먼저, 그다음 오래전에 시도한 순
+const GEOCODE_PENDING_SQL: &str = r#"
+    SELECT biz_no, address FROM companies
+    WHERE coordinates IS NULL AND address IS NOT NULL AND btrim(address) <> ''
+    ORDER BY geocode_checked_at NULLS FIRST, biz_no
+    LIMIT $1
+"#;
+
+/// 주소는 있고 좌표가 없는 기업을 최대 batch_size건 VWorld로 검색해 coordinates 채움
+/// 좌표가 빈 행만 고르므로 중단 후 다시 실행하면 이어서 진행하고, 찾지 못한 주소는 geocode_checked_at을
+/// 남겨 아직 시도하지 않은 행보다 뒤로 미룬다. 호출 간격은 vworld의 RateLimiter를 따른다
+pub async fn enrich_coordinates(
+    pool: &PgPool,
+    vworld: &VworldClient,
+    batch_size: i64,
+) -> anyhow::Result<GeocodeSummary> {
+    let retry = DbRetry::shared();
+    let pending: Vec<(String, String)> = retry.run(|| sqlx::query_as(GEOCODE_PENDING_SQL)
+    .bind(batch_size)
+    .fetch_all(pool))
+    .await?;
+    info!("Geocoding {} company addresses", pending.len());
+
+    let mut summary = GeocodeSummary::default();
+    for (biz_no, address) in &pending {
+        let point = match vworld.geocode(address).await {
+            Ok(point) => point,
+            Err(e) => {
+                warn!(
+                    "Geocoding stopped at {} ({} located, {} unresolved so far)",
+                    biz_no, summary.located, summary.unresolved
+                );
+                return Err(e);
+            }
+        };
+        summary.attempted += 1;
+        let (longitude, latitude) = point.unzip();
+        retry.run(|| sqlx::query(
+            r#"
+            UPDATE companies SET
+                coordinates = CASE WHEN $2::float8 IS NULL THEN NULL
+                                   ELSE ST_SetSRID(ST_MakePoint($2, $3), 4326) END,
+                geocode_checked_at = NOW(),
+                updated_at = CASE WHEN $2::float8 IS NULL THEN updated_at ELSE NOW() END
+            WHERE biz_no = $1 AND coordinates IS NULL
+            "#,
+        )
+        .bind(biz_no)
+        .bind(longitude)
+        .bind(latitude)
+        .execute(pool))
+        .await?;
+        match point {
+            Some(_) => summary.located += 1,
+            None => summary.unresolved += 1,
+        }
+    }
+
+    info!(
+        "Geocoded {} companies: {} located, {} unresolved",
+        summary.attempted, summary.located, summary.unresolved
+    );
+    Ok(summary)
+}
+
+/// NPS 기준월 "202401" → "2024-01"
+/// "20240115"처럼 일자가 붙은 8자리는 월까지만 사용하고, 그 외 형식은 Err
+pub(crate) fn format_year_month(raw: &str) -> kiep_core::Result<String> {
+    let compact = match raw.len() {
+        8 if raw.bytes().all(|b| b.is_ascii_digit()) => &raw[..6],
+        _ => raw,
+    };
+    // from_compact는 바이트 단위로 자르므로 비ASCII 입력은 먼저 거른다
+    if !compact.is_ascii() {
+        return Err(kiep_core::Error::Validation(format!(
+            "invalid compact year_month '{}': expected YYYYMM",
+            raw
+        )));
+    }
+    YearMonth::from_compact(compact).map(|ym| ym.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn company(biz_no: &str, name: &str, bjd_code: Option<&str>) -> CompanyUpsert {
+        CompanyUpsert {
+            biz_no: biz_no.into(),
+            name: name.into(),
+            industry_code: None,
+            bjd_code: bjd_code.map(String::from),
+            address: None,
+            complex_id: None,
+            data_source: "NPS".into(),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_company_upserts_matches_row_by_row_rules() {
+        let merged = dedupe_company_upserts(&[
+            company("0000123456", "청주정밀공업", Some("43111")),
+            company("0000654321", "오창", None),
+            company("0000123456", "청주정밀", None),
+            company("0000123456", "", Some("43112")),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].name, "청주정밀공업");
+        assert_eq!(merged[0].bjd_code.as_deref(), Some("43112"));
+        assert_eq!(merged[1].biz_no, "0000654321");
+    }
+
+    #[test]
+    fn test_dedupe_employment_keeps_last() {
+        let point = |ym: &str, count| EmploymentPoint {
+            biz_no: "0000123456".into(),
+            year_month: ym.into(),
+            employee_count: count,
+            new_hires: 0,
+            departures: 0,
+        };
+        let merged = dedupe_employment(&[point("2024-01", 10), point("2024-02", 11), point("2024-01", 12)]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].employee_count, 12);
+    }
+
+    /// 연결 1개짜리 풀 (TEMP 테이블이 테스트 안에서 계속 보이도록), 설정이 없으면 실패
+    async fn test_pool() -> PgPool {
+        let url = std::env::var("TEST_DATABASE_URL").expect("DB tests need TEST_DATABASE_URL");
+        sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&url)
+            .await
+            .expect("failed to connect to TEST_DATABASE_URL")
+    }
+
+    /// 적재 SQL이 쓰는 컬럼만 둔 TEMP 테이블, 각 테스트는 필요한 행만 넣는다
+    /// PostGIS 없는 테스트 DB라 coordinates는 TEXT로 대신한다
+    async fn create_test_tables(pool: &PgPool) {
+        for ddl in [
+            r#"
+            CREATE TEMP TABLE companies (
+                biz_no TEXT PRIMARY KEY, name TEXT, industry_code TEXT, bjd_code TEXT, address TEXT,
+                coordinates TEXT, geocode_checked_at TIMESTAMPTZ,
+                data_source TEXT, load_batch_id UUID, complex_id TEXT, updated_at TIMESTAMPTZ
+            )
+            "#,
+            r#"
+            CREATE TEMP TABLE company_history (
+                biz_no TEXT, field TEXT, old_value TEXT, new_value TEXT, source TEXT
+            )
+            "#,
+        ] {
+            sqlx::query(ddl).execute(pool).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_complex_tenants_record_membership_changes() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+        sqlx::query(
+            "INSERT INTO companies (biz_no, name, complex_id, data_source) VALUES ('2208162517', '오창정밀', 'A001', 'NPS')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let tenant = |complex_code: &str| KicoxTenant {
+            complex_code: complex_code.into(),
+            biz_no: "220-81-62517".into(),
+            name: "오창정밀".into(),
+        };
+        let history = || async {
+            sqlx::query_as::<_, (Option<String>, Option<String>, String)>(
+                "SELECT old_value, new_value, source FROM company_history WHERE field = 'complex_id'",
+            )
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+        };
+
+        upsert_complex_tenants(&pool, &[tenant("A002")], Uuid::nil()).await.unwrap();
+        assert_eq!(history().await, [(Some("A001".into()), Some("A002".into()), "KICOX".into())]);
+
+        // 같은 소속으로 다시 적재하면 이력을 남기지 않는다
+        upsert_complex_tenants(&pool, &[tenant("A002")], Uuid::nil()).await.unwrap();
+        assert_eq!(history().await.len(), 1);
+
+        let complex_id: Option<String> =
+            sqlx::query_scalar("SELECT complex_id FROM companies WHERE biz_no = '2208162517'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(complex_id.as_deref(), Some("A002"));
+    }
+
+    #[tokio::test]
+    #[ignore = "TEST_DATABASE_URL 필요 (cargo test -- --ignored)"]
+    async fn test_geocode_pending_order_and_resume() {
+        let pool = test_pool().await;
+        create_test_tables(&pool).await;
+
+        sqlx::query(
+            r#"
+            INSERT INTO companies (biz_no, name, address, coordinates, geocode_checked_at) VALUES
+                ('0000000001', '오래전 실패', '청주시 1', NULL, NOW() - INTERVAL '2 days'),
+                ('0000000002', '최근 실패', '청주시 2', NULL, NOW() - INTERVAL '1 hour'),
+                ('0000000003', '미시도 B', '청주시 3', NULL, NULL),
+                ('0000000004', '미시도 A', '청주시 4', NULL, NULL),
+                ('0000000005', '좌표 있음', '청주시 5', 'POINT(127 36)', NULL),
+                ('0000000006', '주소 공백', '  ', NULL, NULL),
+                ('0000000007', '주소 없음', NULL, NULL, NULL)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let pending = |limit: i64| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_as::<_, (String, String)>(GEOCODE_PENDING_SQL)
+                    .bind(limit)
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|(biz_no, _)| biz_no)
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(pending(10).await, ["0000000003", "0000000004", "0000000001", "0000000002"]);
+
+        // 첫 두 건을 찾지 못한 것으로 표시하면 다음 실행은 남은 행부터 이어간다
+        sqlx::query("UPDATE companies SET geocode_checked_at = NOW() WHERE biz_no = ANY($1)")
+            .bind(pending(2).await)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(pending(2).await, ["0000000001", "0000000002"]);
+        assert_eq!(pending(10).await, ["0000000001", "0000000002", "0000000003", "0000000004"]);
+
+        // NPS 적재로 주소가 바뀌면 좌표와 시도 기록을 지워 다시 맨 앞으로 온다
+        let mut moved = company("0000000005", "좌표 있음", None);
+        moved.address = Some("청주시 55".into());
+        let mut same = company("0000000002", "최근 실패", None);
+        same.address = Some("청주시 2".into());
+        let loader = PgLoader::begin(&pool, Uuid::nil()).await.unwrap();
+        loader.upsert_companies(&[moved, same]).await.unwrap();
+        loader.commit().await.unwrap();
+        assert_eq!(pending(1).await, ["0000000005"]);
+        assert_eq!(pending(10).await[1..], ["0000000001", "0000000002", "0000000003", "0000000004"]);
+    }
+
+    #[test]
+    fn test_format_year_month() {
+        assert_eq!(format_year_month("202401").unwrap(), "2024-01");
+        // 일자는 버린다 (월 단위 시계열)
+        assert_eq!(format_year_month("20240115").unwrap(), "2024-01");
+    }
+
+    #[test]
+    fn test_format_year_month_rejects_malformed() {
+        for raw in ["", "20241", "2024-01", "202413", "2024011", "2024가", "abcdefgh"] {
+            assert!(format_year_month(raw).is_err(), "{raw:?} should be rejected");
+        }
     }
 }
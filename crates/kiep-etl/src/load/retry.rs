@@ -0,0 +1,211 @@
+use std::env;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use tracing::warn;
+
+/// 일시적 DB 오류 재시도 설정
+/// `DB_RETRY_MAX`(재시도 횟수), `DB_RETRY_BACKOFF_MS`(첫 대기, 이후 2배씩),
+/// `DB_RETRY_MAX_BACKOFF_MS`(대기 상한)로 재정의
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DbRetry {
+    pub max_retries: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for DbRetry {
+    fn default() -> Self {
+        Self { max_retries: 3, base_backoff_ms: 200, max_backoff_ms: 5_000 }
+    }
+}
+
+impl DbRetry {
+    /// 처음 한 번 from_env로 읽은 설정 (쿼리마다 환경 변수를 다시 읽지 않도록)
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<DbRetry> = OnceLock::new();
+        *SHARED.get_or_init(Self::from_env)
+    }
+
+    pub fn from_env() -> Self {
+        let d = Self::default();
+        Self {
+            max_retries: env::var("DB_RETRY_MAX")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(d.max_retries),
+            base_backoff_ms: env::var("DB_RETRY_BACKOFF_MS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(d.base_backoff_ms),
+            max_backoff_ms: env::var("DB_RETRY_MAX_BACKOFF_MS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(d.max_backoff_ms),
+        }
+    }
+
+    /// attempt번째 재시도(1부터) 전 대기 시간, max_backoff_ms를 넘지 않는다
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1));
+        Duration::from_millis(self.base_backoff_ms.saturating_mul(factor).min(self.max_backoff_ms))
+    }
+
+    /// op를 실행하고 일시적 오류면 지수 백오프로 재시도, 그 외 오류는 즉시 반환
+    /// op는 재실행해도 안전해야 함 (upsert 등 멱등 쿼리)
+    pub async fn run<T, F, Fut>(&self, mut op: F) -> Result<T, sqlx::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.max_retries && is_transient(&e) => {
+                    attempt += 1;
+                    let delay = self.backoff(attempt);
+                    warn!(
+                        "Transient DB error, retry {}/{} after {}ms: {}",
+                        attempt,
+                        self.max_retries,
+                        delay.as_millis(),
+                        e
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// 재시도로 해결될 수 있는 오류인지 (연결 끊김, 직렬화 실패, 교착 등)
+/// 제약 조건 위반 등 데이터 문제는 false
+pub fn is_transient(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut | sqlx::Error::WorkerCrashed => true,
+        sqlx::Error::Database(db) => db.code().is_some_and(|code| {
+            // 08: connection exception, 40001: serialization_failure, 40P01: deadlock_detected,
+            // 53300: too_many_connections, 57P01~03: 서버 종료/재시작
+            code.starts_with("08")
+                || matches!(&*code, "40001" | "40P01" | "53300" | "57P01" | "57P02" | "57P03")
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::cell::Cell;
+    use std::error::Error as StdError;
+    use std::fmt;
+
+    use sqlx::error::{DatabaseError, ErrorKind};
+
+    #[derive(Debug)]
+    struct FakeDbError(&'static str);
+
+    impl fmt::Display for FakeDbError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "SQLSTATE {}", self.0)
+        }
+    }
+
+    impl StdError for FakeDbError {}
+
+    impl DatabaseError for FakeDbError {
+        fn message(&self) -> &str {
+            "fake"
+        }
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+        fn as_error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+            self
+        }
+        fn as_error_mut(&mut self) -> &mut (dyn StdError + Send + Sync + 'static) {
+            self
+        }
+        fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
+            self
+        }
+        fn kind(&self) -> ErrorKind {
+            if self.0 == "23505" { ErrorKind::UniqueViolation } else { ErrorKind::Other }
+        }
+    }
+
+    fn db_error(code: &'static str) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(FakeDbError(code)))
+    }
+
+    fn reset() -> sqlx::Error {
+        sqlx::Error::Io(std::io::Error::from(std::io::ErrorKind::ConnectionReset))
+    }
+
+    const FAST: DbRetry = DbRetry { max_retries: 3, base_backoff_ms: 1, max_backoff_ms: 10 };
+
+    #[test]
+    fn test_backoff_doubles_and_is_capped() {
+        let retry = DbRetry::default();
+        assert_eq!(retry.backoff(1), Duration::from_millis(200));
+        assert_eq!(retry.backoff(3), Duration::from_millis(800));
+        assert_eq!(retry.backoff(10), Duration::from_millis(5_000));
+        // 2^(attempt-1)이 넘쳐도 상한에서 멈춘다
+        assert_eq!(retry.backoff(200), Duration::from_millis(5_000));
+        let huge = DbRetry { base_backoff_ms: u64::MAX, max_backoff_ms: u64::MAX, ..retry };
+        assert_eq!(huge.backoff(64), Duration::from_millis(u64::MAX));
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&reset()));
+        assert!(is_transient(&sqlx::Error::PoolTimedOut));
+        assert!(is_transient(&db_error("40001")));
+        assert!(is_transient(&db_error("08006")));
+        assert!(!is_transient(&db_error("23505")));
+        assert!(!is_transient(&sqlx::Error::RowNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_then_succeeds() {
+        let calls = Cell::new(0);
+        let result = FAST
+            .run(|| {
+                calls.set(calls.get() + 1);
+                let n = calls.get();
+                async move { if n < 3 { Err(reset()) } else { Ok(n) } }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_constraint_violation_fails_fast() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = FAST
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err(db_error("23505")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = FAST
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err(db_error("40P01")) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 4);
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{FromRow, PgPool};
+
+use super::jobs::JobCounts;
+
+/// 데이터 소스별 마지막 수집 결과
+#[derive(Debug, Clone, FromRow)]
+pub struct SourceStatus {
+    pub source: String,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_counts: Option<serde_json::Value>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+}
+
+impl SourceStatus {
+    /// 마지막 실행 결과: 성공 이후 실패가 있으면 failed
+    pub fn last_outcome(&self) -> &'static str {
+        match (self.last_success_at, self.last_error_at) {
+            (_, Some(failed)) if self.last_success_at.is_none_or(|ok| failed > ok) => "failed",
+            (Some(_), _) => "succeeded",
+            _ => "never",
+        }
+    }
+
+    /// 성공 기록이 없거나 마지막 성공 후 max_age_hours가 지났으면 true
+    pub fn is_stale(&self, max_age_hours: i64) -> bool {
+        self.last_success_at
+            .is_none_or(|at| Utc::now() - at > TimeDelta::hours(max_age_hours))
+    }
+}
+
+/// 성공 기록, 이전 실패 메시지는 last_outcome 판단용으로 남겨 둔다
+pub async fn record_success(pool: &PgPool, source: &str, counts: JobCounts) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO source_status (source, last_success_at, last_counts, updated_at)
+        VALUES ($1, NOW(), $2, NOW())
+        ON CONFLICT (source) DO UPDATE SET
+            last_success_at = EXCLUDED.last_success_at,
+            last_counts = EXCLUDED.last_counts,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(source)
+    .bind(serde_json::json!({ "fetched": counts.fetched, "written": counts.written }))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn record_failure(pool: &PgPool, source: &str, error: &str) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO source_status (source, last_error, last_error_at, updated_at)
+        VALUES ($1, $2, NOW(), NOW())
+        ON CONFLICT (source) DO UPDATE SET
+            last_error = EXCLUDED.last_error,
+            last_error_at = EXCLUDED.last_error_at,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(source)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// 소스 이름 순
+pub async fn source_statuses(pool: &PgPool) -> anyhow::Result<Vec<SourceStatus>> {
+    let statuses = sqlx::query_as::<_, SourceStatus>(
+        r#"
+        SELECT source, last_success_at, last_counts, last_error, last_error_at
+        FROM source_status
+        ORDER BY source
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(success: Option<i64>, error: Option<i64>) -> SourceStatus {
+        let at = |secs| DateTime::from_timestamp(secs, 0).unwrap();
+        SourceStatus {
+            source: "NPS".into(),
+            last_success_at: success.map(at),
+            last_counts: None,
+            last_error: error.map(|_| "boom".into()),
+            last_error_at: error.map(at),
+        }
+    }
+
+    #[test]
+    fn test_last_outcome() {
+        assert_eq!(status(None, None).last_outcome(), "never");
+        assert_eq!(status(Some(10), None).last_outcome(), "succeeded");
+        assert_eq!(status(Some(10), Some(5)).last_outcome(), "succeeded");
+        assert_eq!(status(Some(10), Some(20)).last_outcome(), "failed");
+        assert_eq!(status(None, Some(20)).last_outcome(), "failed");
+    }
+}
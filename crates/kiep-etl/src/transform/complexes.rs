@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::clients::kicox::KicoxComplex;
+
+use super::normalize::percentage_in_range;
+
+/// 같은 단지코드(cmplxCd)가 한 번의 수집에 여러 번 나오는 경우(분할 필지 등) 1건으로 병합
+///
+/// 규칙:
+/// - 기준 분기가 다르면 최신 분기 행만 남긴다 (시점이 다른 보고는 합치지 않음)
+/// - 기준 분기가 같으면 면적·업체수·생산/수출/고용을 합산하고,
+///   분양률은 산업용지면적 가중 평균 (면적이 없으면 먼저 나온 값)
+/// - 이름/유형/지역은 먼저 나온 비어 있지 않은 값
+///
+/// 단지코드가 비어 있는 행은 병합하지 않는다. 반환값: (병합 결과, 접힌 중복 행 수)
+/// 가중 평균이 오류 값에 끌려가지 않도록 병합 전에 `sanitize_complex`를 적용한다
+pub fn dedupe_complexes(items: Vec<KicoxComplex>) -> (Vec<KicoxComplex>, usize) {
+    let mut out: Vec<KicoxComplex> = Vec::with_capacity(items.len());
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut collapsed = 0;
+
+    for mut item in items {
+        sanitize_complex(&mut item);
+        if item.complex_code.is_empty() {
+            out.push(item);
+            continue;
+        }
+        let Some(&i) = index.get(&item.complex_code) else {
+            index.insert(item.complex_code.clone(), out.len());
+            out.push(item);
+            continue;
+        };
+
+        collapsed += 1;
+        let existing = &mut out[i];
+        match item.year_quarter().cmp(&existing.year_quarter()) {
+            std::cmp::Ordering::Greater => *existing = item,
+            std::cmp::Ordering::Less => {}
+            std::cmp::Ordering::Equal => merge_parcel(existing, item),
+        }
+    }
+
+    if collapsed > 0 {
+        info!("Collapsed {} duplicate KICOX complex rows", collapsed);
+    }
+    (out, collapsed)
+}
+
+/// 범위를 벗어난 분양률(0~100 밖)은 행을 버리지 않고 값만 결측 처리 (면적·업체수는 유효)
+pub fn sanitize_complex(item: &mut KicoxComplex) {
+    item.occupancy_rate = percentage_in_range(item.occupancy_rate, "occupancy_rate", &item.complex_code);
+}
+
+fn merge_parcel(into: &mut KicoxComplex, other: KicoxComplex) {
+    let weighted = (into.occupancy_rate, into.industrial_area, other.occupancy_rate, other.industrial_area);
+    into.occupancy_rate = match weighted {
+        (Some(r1), Some(a1), Some(r2), Some(a2)) if a1 + a2 > 0.0 => {
+            Some((r1 * a1 + r2 * a2) / (a1 + a2))
+        }
+        (rate, ..) => rate.or(other.occupancy_rate),
+    };
+
+    into.designated_area = sum(into.designated_area, other.designated_area);
+    into.industrial_area = sum(into.industrial_area, other.industrial_area);
+    into.tenant_count = sum(into.tenant_count, other.tenant_count);
+    into.operating_count = sum(into.operating_count, other.operating_count);
+    into.production = sum(into.production, other.production);
+    into.export_amount = sum(into.export_amount, other.export_amount);
+    into.employment = sum(into.employment, other.employment);
+
+    for (field, value) in [
+        (&mut into.name, other.name),
+        (&mut into.complex_type, other.complex_type),
+        (&mut into.province, other.province),
+        (&mut into.sigungu, other.sigungu),
+    ] {
+        if field.is_empty() {
+            *field = value;
+        }
+    }
+}
+
+fn sum<T: std::ops::Add<Output = T>>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complex(code: &str, period: &str, area: f64, rate: f64, tenants: u32) -> KicoxComplex {
+        serde_json::from_value(serde_json::json!({
+            "cmplxCd": code,
+            "cmplxNm": "오창과학",
+            "stdrYm": period,
+            "idstAr": area,
+            "lttotRt": rate,
+            "mvnFrmCnt": tenants,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_code_same_period_is_summed() {
+        let items = vec![
+            complex("A001", "202406", 300.0, 90.0, 40),
+            complex("B002", "202406", 50.0, 100.0, 5),
+            complex("A001", "202406", 100.0, 50.0, 10),
+        ];
+
+        let (merged, collapsed) = dedupe_complexes(items);
+        assert_eq!(collapsed, 1);
+        assert_eq!(merged.len(), 2);
+
+        let a = &merged[0];
+        assert_eq!(a.complex_code, "A001");
+        assert_eq!(a.industrial_area, Some(400.0));
+        assert_eq!(a.tenant_count, Some(50));
+        assert!((a.occupancy_rate.unwrap() - 80.0).abs() < 1e-9);
+        assert_eq!(merged[1].complex_code, "B002");
+    }
+
+    #[test]
+    fn test_duplicate_code_keeps_latest_period() {
+        let items = vec![
+            complex("A001", "202403", 300.0, 90.0, 40),
+            complex("A001", "202406", 310.0, 95.0, 42),
+            complex("A001", "202312", 290.0, 85.0, 38),
+        ];
+
+        let (merged, collapsed) = dedupe_complexes(items);
+        assert_eq!(collapsed, 2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].base_period, "202406");
+        assert_eq!(merged[0].tenant_count, Some(42));
+    }
+
+    #[test]
+    fn test_out_of_range_occupancy_is_dropped_before_merge() {
+        let items = vec![
+            complex("A001", "202406", 300.0, 130.0, 40),
+            complex("A001", "202406", 100.0, 50.0, 10),
+            complex("B002", "202406", 50.0, -5.0, 5),
+        ];
+
+        let (merged, _) = dedupe_complexes(items);
+        // 오류 값은 가중 평균에서 빠지고 나머지 필지 값만 남는다
+        assert_eq!(merged[0].occupancy_rate, Some(50.0));
+        assert_eq!(merged[0].industrial_area, Some(400.0));
+        assert_eq!(merged[1].occupancy_rate, None);
+        assert_eq!(merged[1].tenant_count, Some(5));
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use kiep_core::models::{Financial, StatementType};
+
+use crate::clients::fsc::FscFinancial;
+
+/// FSC 재무제표구분명 → StatementType
+pub fn statement_type(name: &str) -> Option<StatementType> {
+    if name.contains("연결") {
+        Some(StatementType::Consolidated)
+    } else if name.contains("별도") || name.contains("개별") {
+        Some(StatementType::Separate)
+    } else {
+        None
+    }
+}
+
+/// 결산기준일 'YYYYMMDD' → (회계연도, 분기)
+fn fiscal_period(account_date: &str) -> Option<(i32, i16)> {
+    let digits: String = account_date.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.len() < 6 {
+        return None;
+    }
+    let year = digits[..4].parse().ok()?;
+    let month: i16 = digits[4..6].parse().ok()?;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    Some((year, (month + 2) / 3))
+}
+
+/// "1,234,000" / "-500" → i64
+pub(crate) fn parse_amount(raw: Option<&str>) -> Option<i64> {
+    let cleaned: String = raw?.chars().filter(|c| *c != ',' && !c.is_whitespace()).collect();
+    cleaned.parse().ok()
+}
+
+/// 계정과목 단위 FSC 항목을 (구분, 회계연도, 분기)별 Financial로 묶음
+/// 구분을 알 수 없는 항목은 버린다 (연결/별도 혼합 방지)
+pub fn fsc_to_financials(biz_no: &str, items: &[FscFinancial]) -> Vec<Financial> {
+    let mut grouped: BTreeMap<(StatementType, i32, i16), Financial> = BTreeMap::new();
+
+    for item in items {
+        let Some(stmt) = statement_type(&item.statement_name) else {
+            continue;
+        };
+        let Some((fiscal_year, quarter)) = fiscal_period(&item.account_date) else {
+            continue;
+        };
+
+        let entry = grouped
+            .entry((stmt, fiscal_year, quarter))
+            .or_insert_with(|| Financial {
+                biz_no: biz_no.to_string(),
+                fiscal_year,
+                quarter,
+                revenue: None,
+                operating_income: None,
+                net_income: None,
+                total_assets: None,
+                total_equity: None,
+                total_debt: None,
+                statement_type: stmt,
+            });
+
+        let amount = parse_amount(item.current_amount.as_deref());
+        let slot = match item.account_name.trim() {
+            "매출액" | "영업수익" => &mut entry.revenue,
+            "영업이익" | "영업이익(손실)" => &mut entry.operating_income,
+            "당기순이익" | "당기순이익(손실)" => &mut entry.net_income,
+            "자산총계" => &mut entry.total_assets,
+            "자본총계" => &mut entry.total_equity,
+            "부채총계" => &mut entry.total_debt,
+            _ => continue,
+        };
+        if amount.is_some() {
+            *slot = amount;
+        }
+    }
+
+    grouped.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(statement: &str, account: &str, amount: &str) -> FscFinancial {
+        FscFinancial {
+            corp_no: "1101110000000".into(),
+            corp_name: "테스트".into(),
+            account_date: "20231231".into(),
+            statement_name: statement.into(),
+            account_name: account.into(),
+            current_amount: Some(amount.into()),
+            previous_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_statement_type() {
+        assert_eq!(statement_type("연결요약재무제표"), Some(StatementType::Consolidated));
+        assert_eq!(statement_type("별도요약재무제표"), Some(StatementType::Separate));
+        assert_eq!(statement_type(""), None);
+    }
+
+    #[test]
+    fn test_company_reporting_both_statements() {
+        let items = [
+            item("연결요약재무제표", "매출액", "1,000,000"),
+            item("연결요약재무제표", "당기순이익", "50,000"),
+            item("별도요약재무제표", "매출액", "700,000"),
+            item("별도요약재무제표", "당기순이익", "-20,000"),
+            item("", "매출액", "1"),
+        ];
+
+        let financials = fsc_to_financials("1234567890", &items);
+        assert_eq!(financials.len(), 2);
+
+        let consolidated = &financials[0];
+        assert_eq!(consolidated.statement_type, StatementType::Consolidated);
+        assert_eq!((consolidated.fiscal_year, consolidated.quarter), (2023, 4));
+        assert_eq!(consolidated.revenue, Some(1_000_000));
+        assert_eq!(consolidated.net_income, Some(50_000));
+
+        let separate = &financials[1];
+        assert_eq!(separate.statement_type, StatementType::Separate);
+        assert_eq!(separate.revenue, Some(700_000));
+        assert_eq!(separate.net_income, Some(-20_000));
+    }
+}
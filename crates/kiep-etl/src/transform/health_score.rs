@@ -1,4 +1,4 @@
-use kiep_core::models::RegionHealth;
+use kiep_core::models::{HealthWeights, RegionHealth};
 
 /// 지역 건강도 스코어 계산기
 pub struct HealthScoreCalculator;
@@ -21,6 +21,25 @@ impl HealthScoreCalculator {
         )
     }
 
+    /// 설정한 가중치로 건강도 산출 (가중치 검증은 설정 로드 시)
+    pub fn calculate_with(
+        employment_growth: f64,
+        new_biz_rate: f64,
+        closure_rate: f64,
+        avg_revenue_growth: f64,
+        complex_utilization: f64,
+        weights: &HealthWeights,
+    ) -> f64 {
+        RegionHealth::calculate_score_with(
+            employment_growth,
+            new_biz_rate,
+            closure_rate,
+            avg_revenue_growth,
+            complex_utilization,
+            weights,
+        )
+    }
+
     /// 여러 지역의 건강도를 일괄 계산
     pub fn calculate_batch(
         regions: &[(String, f64, f64, f64, f64, f64)],
@@ -52,6 +71,14 @@ mod tests {
         assert!(score < 30.0, "Struggling region should score below 30, got {}", score);
     }
 
+    #[test]
+    fn test_components_reproduce_score() {
+        let components = RegionHealth::score_components(5.0, 10.0, 2.0, 15.0, 95.0);
+        assert_eq!(components.score(), HealthScoreCalculator::calculate(5.0, 10.0, 2.0, 15.0, 95.0));
+        assert_eq!(components.employment_growth, 0.75);
+        assert_eq!(components.survival_rate, 0.9);
+    }
+
     #[test]
     fn test_score_bounds() {
         let max = HealthScoreCalculator::calculate(20.0, 30.0, 0.0, 50.0, 100.0);
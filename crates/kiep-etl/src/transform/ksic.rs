@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// 한국표준산업분류(10차) 대분류(영문 1자)와 중분류(숫자 2자리) 이름표
+const KSIC_CSV: &str = include_str!("ksic.csv");
+
+struct KsicTable {
+    /// 중분류 → (대분류, 이름)
+    divisions: HashMap<String, (String, String)>,
+    /// 대분류 → 이름
+    sections: HashMap<String, String>,
+}
+
+static TABLE: LazyLock<KsicTable> = LazyLock::new(|| {
+    let mut table = KsicTable { divisions: HashMap::new(), sections: HashMap::new() };
+    let mut reader = csv::Reader::from_reader(KSIC_CSV.as_bytes());
+    for record in reader.records() {
+        let record = record.expect("embedded ksic.csv is valid");
+        let (code, section, name) = (&record[0], &record[1], &record[2]);
+        if code == section {
+            table.sections.insert(section.to_string(), name.to_string());
+        } else {
+            table.divisions.insert(code.to_string(), (section.to_string(), name.to_string()));
+        }
+    }
+    table
+});
+
+/// 업종코드를 (대분류 문자, 중분류 2자리)로 분해
+/// "C26110", "26110", "26"처럼 대분류 문자는 있어도 없어도 되고, "C"처럼 대분류만도 허용
+fn split(code: &str) -> Option<(Option<String>, Option<&str>)> {
+    let code = code.trim();
+    let (letter, digits) = match code.chars().next()? {
+        c if c.is_ascii_alphabetic() => (Some(c.to_ascii_uppercase().to_string()), &code[1..]),
+        _ => (None, code),
+    };
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match digits.len() {
+        0 => letter.map(|l| (Some(l), None)),
+        1 => None,
+        _ => Some((letter, Some(&digits[..2]))),
+    }
+}
+
+/// 중분류 2자리 (예: "C26110" → "26"), 표에 없거나 대분류 문자가 맞지 않으면 None
+pub fn division_of(code: &str) -> Option<&'static str> {
+    let (letter, division) = split(code)?;
+    let (key, (section, _)) = TABLE.divisions.get_key_value(division?)?;
+    if letter.is_some_and(|l| l != *section) {
+        return None;
+    }
+    Some(key.as_str())
+}
+
+/// 대분류 문자 (예: "26110" → "C")
+pub fn section_of(code: &str) -> Option<&'static str> {
+    match split(code)? {
+        (Some(letter), None) => TABLE.sections.get_key_value(&letter).map(|(k, _)| k.as_str()),
+        _ => division_of(code).map(|d| TABLE.divisions[d].0.as_str()),
+    }
+}
+
+/// 가장 구체적인 수준의 한글 이름: 중분류가 있으면 중분류, 대분류만 주면 대분류 이름
+pub fn name_of(code: &str) -> Option<&'static str> {
+    match division_of(code) {
+        Some(division) => Some(TABLE.divisions[division].1.as_str()),
+        None => section_of(code).map(|s| TABLE.sections[s].as_str()),
+    }
+}
+
+/// 대분류 한글 이름 (예: "C" → "제조업")
+pub fn section_name(section: &str) -> Option<&'static str> {
+    TABLE.sections.get(section).map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manufacturing_code() {
+        // 반도체 제조업 (C26110)
+        for code in ["C26110", "26110", "c26", " 26 "] {
+            assert_eq!(division_of(code), Some("26"), "{}", code);
+            assert_eq!(section_of(code), Some("C"), "{}", code);
+        }
+        assert_eq!(name_of("26110"), Some("전자 부품, 컴퓨터, 영상, 음향 및 통신장비 제조업"));
+        assert_eq!(section_name("C"), Some("제조업"));
+    }
+
+    #[test]
+    fn test_services_code() {
+        // 응용 소프트웨어 개발 및 공급업 (J58221)
+        assert_eq!(division_of("J58221"), Some("58"));
+        assert_eq!(section_of("58221"), Some("J"));
+        assert_eq!(name_of("58221"), Some("출판업"));
+        assert_eq!(name_of("M"), Some("전문, 과학 및 기술 서비스업"));
+        assert_eq!(section_of("M"), Some("M"));
+        assert_eq!(division_of("M"), None);
+    }
+
+    #[test]
+    fn test_unknown_or_inconsistent_codes() {
+        assert_eq!(division_of("A26110"), None);
+        assert_eq!(section_of("04"), None);
+        for code in ["", "2", "C2", "unknown", "26-110", "Z"] {
+            assert_eq!(name_of(code), None, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_every_division_has_a_section() {
+        assert_eq!(TABLE.sections.len(), 21);
+        assert!(TABLE.divisions.values().all(|(s, _)| TABLE.sections.contains_key(s)));
+    }
+}
@@ -1,2 +1,6 @@
+pub mod complexes;
+pub mod financials;
+pub mod ksic;
 pub mod normalize;
+pub mod procurement;
 pub mod health_score;
@@ -1,25 +1,95 @@
+use kiep_core::bjd;
+use kiep_core::models::BizStatus;
+use tracing::warn;
+
 /// 사업자등록번호 정규화: 하이픈 제거, 10자리 패딩
 pub fn normalize_biz_no(raw: &str) -> String {
     let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
     format!("{:0>10}", digits)
 }
 
+/// 사업자등록번호 입력 형태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BizNoForm {
+    /// 10자리 전체 (검증번호 확인)
+    Full,
+    /// 앞 6자리만 (NPS, 검증번호가 없어 형식만 확인)
+    Prefix,
+}
+
+fn digits(raw: &str) -> Vec<u32> {
+    raw.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+/// 사업자등록번호 검증번호 확인 (하이픈 허용, 정확히 10자리)
+/// 가중치 1,3,7,1,3,7,1,3,5를 곱해 더하고 9번째 자리×5의 십의 자리를 한 번 더 더한다
+pub fn validate_biz_no(raw: &str) -> bool {
+    if raw.chars().any(|c| !c.is_ascii_digit() && c != '-') {
+        return false;
+    }
+    let d = digits(raw);
+    if d.len() != 10 {
+        return false;
+    }
+    const WEIGHTS: [u32; 9] = [1, 3, 7, 1, 3, 7, 1, 3, 5];
+    let sum: u32 = d.iter().zip(WEIGHTS).map(|(d, w)| d * w).sum::<u32>() + d[8] * 5 / 10;
+    (10 - sum % 10) % 10 == d[9]
+}
+
+/// NPS 앞 6자리 형식 확인 (세무서코드 3자리 + 구분코드 2자리 + 일련번호 첫 자리)
+pub fn validate_biz_no_prefix(raw: &str) -> bool {
+    let d = digits(raw);
+    d.len() == 6 && raw.chars().all(|c| c.is_ascii_digit() || c == '-') && d.iter().any(|&d| d != 0)
+}
+
+/// 형태에 맞게 검증 후 normalize_biz_no, 잘못된 번호는 Validation 오류
+pub fn normalize_and_validate(raw: &str, form: BizNoForm) -> kiep_core::Result<String> {
+    let valid = match form {
+        BizNoForm::Full => validate_biz_no(raw),
+        BizNoForm::Prefix => validate_biz_no_prefix(raw),
+    };
+    if !valid {
+        return Err(kiep_core::Error::Validation(format!(
+            "invalid business registration number '{}' ({:?})",
+            raw, form
+        )));
+    }
+    Ok(normalize_biz_no(raw))
+}
+
+/// 법인등록번호 정규화: 하이픈 제거, 13자리 패딩
+pub fn normalize_corp_no(raw: &str) -> String {
+    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+    format!("{:0>13}", digits)
+}
+
+/// 법인등록번호 검증번호 확인 (하이픈 허용, 정확히 13자리)
+/// 앞 12자리에 1,2를 번갈아 곱해 더한 값의 10의 보수가 마지막 자리
+pub fn validate_corp_no(raw: &str) -> bool {
+    if raw.chars().any(|c| !c.is_ascii_digit() && c != '-') {
+        return false;
+    }
+    let d = digits(raw);
+    if d.len() != 13 {
+        return false;
+    }
+    let sum: u32 = d[..12].iter().zip([1, 2].into_iter().cycle()).map(|(d, w)| d * w).sum();
+    (10 - sum % 10) % 10 == d[12]
+}
+
 /// 법정동코드 정규화: 8자리 → 10자리 (뒤 2자리 00 패딩)
 pub fn normalize_bjd_code(raw: &str) -> String {
-    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
-    format!("{:0<10}", digits)
+    bjd::normalize(raw)
 }
 
 /// 법정동코드에서 시군구 코드(5자리) 추출
 pub fn extract_sigungu_code(bjd_code: &str) -> String {
-    let normalized = normalize_bjd_code(bjd_code);
-    normalized[..5].to_string()
+    bjd::sigungu_code(bjd_code)
 }
 
 /// 법정동코드에서 시도 코드(2자리) 추출
 pub fn extract_sido_code(bjd_code: &str) -> String {
-    let normalized = normalize_bjd_code(bjd_code);
-    normalized[..2].to_string()
+    bjd::sido_code(bjd_code)
 }
 
 /// NPS 시도코드 → 법정동 시도코드 매핑
@@ -46,6 +116,28 @@ pub fn nps_sido_to_bjd(nps_sido: &str) -> Option<&'static str> {
     }
 }
 
+/// NTS 사업자상태(bstt) → BizStatus 매핑
+pub fn nts_status_to_biz_status(bstt: &str) -> Option<BizStatus> {
+    match bstt.trim() {
+        "계속사업자" | "계속" => Some(BizStatus::Active),
+        "휴업자" | "휴업" => Some(BizStatus::Suspended),
+        "폐업자" | "폐업" => Some(BizStatus::Closed),
+        _ => None,
+    }
+}
+
+/// 백분율(0~100) 검증, 범위를 벗어나면 경고 후 None
+/// 상류 오류 값이라 100/0으로 잘라 쓰지 않고 결측으로 본다 (잘라 쓰면 없는 "만실"/"공실"을 만든다)
+pub fn percentage_in_range(value: Option<f64>, field: &str, key: &str) -> Option<f64> {
+    match value {
+        Some(v) if !(0.0..=100.0).contains(&v) => {
+            warn!("{} out of range for {}: {} (treated as missing)", field, key, v);
+            None
+        }
+        v => v,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +149,44 @@ mod tests {
         assert_eq!(normalize_biz_no("12345"), "0000012345");
     }
 
+    #[test]
+    fn test_validate_biz_no() {
+        assert!(validate_biz_no("220-81-62517"));
+        assert!(validate_biz_no("1248100998"));
+        assert!(validate_biz_no("120-81-47521"));
+        // 검증번호만 바꾼 번호, 자릿수 오류, 문자 포함
+        assert!(!validate_biz_no("220-81-62518"));
+        assert!(!validate_biz_no("1234567890"));
+        assert!(!validate_biz_no("124810099"));
+        assert!(!validate_biz_no("12481009981"));
+        assert!(!validate_biz_no("12A8100998"));
+    }
+
+    #[test]
+    fn test_normalize_and_validate() {
+        assert_eq!(normalize_and_validate("220-81-62517", BizNoForm::Full).unwrap(), "2208162517");
+        assert!(normalize_and_validate("220-81-62518", BizNoForm::Full).is_err());
+
+        assert_eq!(normalize_and_validate("123456", BizNoForm::Prefix).unwrap(), "0000123456");
+        assert!(normalize_and_validate("000000", BizNoForm::Prefix).is_err());
+        assert!(normalize_and_validate("12345", BizNoForm::Prefix).is_err());
+        assert!(normalize_and_validate("2208162517", BizNoForm::Prefix).is_err());
+    }
+
+    #[test]
+    fn test_corp_no() {
+        assert_eq!(normalize_corp_no("130111-0006246"), "1301110006246");
+        assert_eq!(normalize_corp_no(" 110111-0005078 "), "1101110005078");
+        assert_eq!(normalize_corp_no("1110005078"), "0001110005078");
+
+        assert!(validate_corp_no("130111-0006246"));
+        assert!(validate_corp_no("1101110005078"));
+        // 검증번호 손상, 자릿수 오류
+        assert!(!validate_corp_no("130111-0006247"));
+        assert!(!validate_corp_no("130111000624"));
+        assert!(!validate_corp_no("130111 0006246"));
+    }
+
     #[test]
     fn test_normalize_bjd_code() {
         assert_eq!(normalize_bjd_code("11010"), "1101000000");
@@ -64,6 +194,14 @@ mod tests {
         assert_eq!(normalize_bjd_code("4311000"), "4311000000");
     }
 
+    #[test]
+    fn test_nts_status_to_biz_status() {
+        assert_eq!(nts_status_to_biz_status("계속사업자"), Some(BizStatus::Active));
+        assert_eq!(nts_status_to_biz_status("휴업자"), Some(BizStatus::Suspended));
+        assert_eq!(nts_status_to_biz_status(" 폐업자 "), Some(BizStatus::Closed));
+        assert_eq!(nts_status_to_biz_status(""), None);
+    }
+
     #[test]
     fn test_extract_sigungu() {
         assert_eq!(extract_sigungu_code("1101010100"), "11010");
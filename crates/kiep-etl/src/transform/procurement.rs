@@ -0,0 +1,90 @@
+use chrono::NaiveDate;
+
+use crate::clients::pps::PpsContract;
+
+use super::financials::parse_amount;
+use super::normalize;
+
+/// procurement 테이블 1행 (biz_no는 적재 시 companies에 있을 때만 연결)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcurementRow {
+    pub bid_no: String,
+    pub contract_no: String,
+    pub title: String,
+    /// 정규화한 계약업체 사업자번호, 번호가 없으면 None
+    pub contractor_biz_no: Option<String>,
+    pub contract_type: Option<String>,
+    pub amount: Option<i64>,
+    pub contract_date: Option<NaiveDate>,
+    pub agency: Option<String>,
+}
+
+impl ProcurementRow {
+    /// 계약업체 번호가 없거나 검증을 통과 (잘못된 번호는 contractor_biz_no VARCHAR(10)에 넣을 수 없다)
+    pub fn has_valid_contractor(&self) -> bool {
+        self.contractor_biz_no.as_deref().is_none_or(normalize::validate_biz_no)
+    }
+}
+
+/// 계약일자 'YYYYMMDD' (하이픈 허용) → 날짜, 비었거나 잘못되면 None
+fn parse_contract_date(raw: &str) -> Option<NaiveDate> {
+    let digits: String = raw.chars().filter(|c| c.is_ascii_digit()).collect();
+    NaiveDate::parse_from_str(&digits, "%Y%m%d").ok()
+}
+
+fn non_empty(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+pub fn pps_to_procurement(contract: &PpsContract) -> ProcurementRow {
+    let has_biz_no = contract.biz_no.chars().any(|c| c.is_ascii_digit());
+    ProcurementRow {
+        bid_no: contract.bid_no.trim().to_string(),
+        contract_no: contract.contract_no.trim().to_string(),
+        title: contract.title.trim().to_string(),
+        contractor_biz_no: has_biz_no.then(|| normalize::normalize_biz_no(&contract.biz_no)),
+        contract_type: non_empty(&contract.contract_type),
+        amount: parse_amount(contract.amount.as_deref()),
+        contract_date: parse_contract_date(&contract.contract_date),
+        agency: non_empty(&contract.agency),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_with_commas_and_blank_date() {
+        let contract: PpsContract = serde_json::from_value(serde_json::json!({
+            "bidNtceNo": "20240112345",
+            "cntrctNo": "C-001",
+            "bidNtceNm": "청사 보수공사",
+            "bizno": "220-81-62517",
+            "cntrctAmt": "1,234,500,000",
+            "cntrctDate": "",
+            "dmndInsttNm": "충청북도",
+            "cntrctMthdNm": "공사",
+        }))
+        .unwrap();
+
+        let row = pps_to_procurement(&contract);
+        assert_eq!(row.amount, Some(1_234_500_000));
+        assert_eq!(row.contract_date, None);
+        assert_eq!(row.contractor_biz_no.as_deref(), Some("2208162517"));
+        assert_eq!(row.agency.as_deref(), Some("충청북도"));
+        assert!(row.has_valid_contractor());
+
+        let blank = ProcurementRow { contractor_biz_no: None, ..row.clone() };
+        assert!(blank.has_valid_contractor());
+        let bad_checksum = ProcurementRow { contractor_biz_no: Some("2208162518".into()), ..row.clone() };
+        assert!(!bad_checksum.has_valid_contractor());
+        let too_long = ProcurementRow { contractor_biz_no: Some("22081625170".into()), ..row };
+        assert!(!too_long.has_valid_contractor());
+
+        assert_eq!(parse_contract_date("20240315"), NaiveDate::from_ymd_opt(2024, 3, 15));
+        assert_eq!(parse_contract_date("2024-03-15"), NaiveDate::from_ymd_opt(2024, 3, 15));
+        assert_eq!(parse_contract_date("20241340"), None);
+    }
+}
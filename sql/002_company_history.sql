@@ -0,0 +1,19 @@
+-- KIEP Database Schema
+-- 002: 기업 변경 이력
+
+-- ============================================================
+-- 11. 기업 변경 이력 (사업자 상태, 산단 소속 등)
+-- ============================================================
+CREATE TABLE IF NOT EXISTS company_history (
+    id              BIGSERIAL PRIMARY KEY,
+    biz_no          VARCHAR(10) NOT NULL REFERENCES companies(biz_no),
+    field           VARCHAR(30) NOT NULL,           -- biz_status/complex_id
+    old_value       TEXT,                           -- 변경 전 값 (최초 기록 시 NULL)
+    new_value       TEXT,                           -- 변경 후 값
+    reason          TEXT,                           -- 변경 사유 (폐업 사유 등, 제공되는 경우)
+    source          VARCHAR(20),                    -- NTS/NPS/KICOX
+    changed_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_chist_biz ON company_history(biz_no);
+CREATE INDEX IF NOT EXISTS idx_chist_field_time ON company_history(field, changed_at);
@@ -0,0 +1,6 @@
+-- KIEP Database Schema
+-- 003: 사업자 상태 확인 시각
+
+ALTER TABLE companies ADD COLUMN IF NOT EXISTS status_checked_at TIMESTAMPTZ;  -- 마지막 NTS 상태 확인
+
+CREATE INDEX IF NOT EXISTS idx_companies_status_checked ON companies(status_checked_at NULLS FIRST);
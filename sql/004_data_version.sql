@@ -0,0 +1,24 @@
+-- KIEP Database Schema
+-- 004: 데이터 버전 (HTTP Last-Modified 용)
+
+-- ============================================================
+-- 12. 데이터 버전
+-- ============================================================
+CREATE TABLE IF NOT EXISTS data_version (
+    name            VARCHAR(30) PRIMARY KEY,        -- 데이터셋 이름 (region_health)
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- region_health가 쓰일 때마다 버전 갱신 (재계산/백필 등 경로와 무관)
+CREATE OR REPLACE FUNCTION bump_region_health_version() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO data_version (name, updated_at) VALUES ('region_health', NOW())
+    ON CONFLICT (name) DO UPDATE SET updated_at = EXCLUDED.updated_at;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_region_health_version ON region_health;
+CREATE TRIGGER trg_region_health_version
+    AFTER INSERT OR UPDATE OR DELETE ON region_health
+    FOR EACH STATEMENT EXECUTE FUNCTION bump_region_health_version();
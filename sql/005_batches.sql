@@ -0,0 +1,23 @@
+-- KIEP Database Schema
+-- 005: 적재 배치 기록 (수집 실행 단위 추적/롤백)
+
+CREATE TABLE IF NOT EXISTS batches (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    source          VARCHAR(20) NOT NULL,           -- NPS/NTS/KICOX/...
+    params          JSONB NOT NULL DEFAULT '{}',    -- 실행 인자
+    status          VARCHAR(20) NOT NULL DEFAULT 'running',  -- running/completed/failed/rolled_back
+    fetched_count   INTEGER,                        -- API 수신 건수
+    written_count   INTEGER,                        -- 적재 건수
+    started_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at     TIMESTAMPTZ,
+    rolled_back_at  TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_batches_source ON batches(source, started_at);
+
+-- 마지막으로 행을 기록한 배치
+ALTER TABLE companies ADD COLUMN IF NOT EXISTS load_batch_id UUID REFERENCES batches(id);
+ALTER TABLE employment_series ADD COLUMN IF NOT EXISTS load_batch_id UUID REFERENCES batches(id);
+
+CREATE INDEX IF NOT EXISTS idx_companies_batch ON companies(load_batch_id);
+CREATE INDEX IF NOT EXISTS idx_emp_batch ON employment_series(load_batch_id);
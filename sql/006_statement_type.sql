@@ -0,0 +1,8 @@
+-- KIEP Database Schema
+-- 006: 재무제표 구분 (연결/별도)
+
+-- 기존 행은 FSC 요약재무제표(연결) 기준으로 적재됨
+ALTER TABLE financials ADD COLUMN IF NOT EXISTS statement_type VARCHAR(12) NOT NULL DEFAULT 'consolidated';  -- consolidated/separate
+
+DROP INDEX IF EXISTS idx_fin_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_fin_unique_stmt ON financials(biz_no, fiscal_year, quarter, statement_type);
@@ -0,0 +1,6 @@
+-- KIEP Database Schema
+-- 007: 건강도 스코어 모델 버전
+
+-- 기존 행은 최초 모델(1)로 계산됨, 이후 적재는 항상 버전을 명시
+ALTER TABLE region_health ADD COLUMN IF NOT EXISTS score_version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE region_health ALTER COLUMN score_version DROP DEFAULT;
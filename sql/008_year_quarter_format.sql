@@ -0,0 +1,23 @@
+-- KIEP Database Schema
+-- 008: complex_series.year_quarter 표기 통일 ('YYYY-Qn')
+
+-- 같은 분기가 두 표기로 모두 있으면 'YYYY-Qn' 행을 남김
+DELETE FROM complex_series c
+WHERE c.year_quarter ~ '^\d{4}[1-4]$'
+  AND EXISTS (
+      SELECT 1 FROM complex_series o
+      WHERE o.complex_id = c.complex_id
+        AND o.year_quarter = LEFT(c.year_quarter, 4) || '-Q' || RIGHT(c.year_quarter, 1)
+  );
+
+UPDATE complex_series
+SET year_quarter = LEFT(year_quarter, 4) || '-Q' || RIGHT(year_quarter, 1)
+WHERE year_quarter ~ '^\d{4}[1-4]$';
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'chk_cseries_year_quarter') THEN
+        ALTER TABLE complex_series
+            ADD CONSTRAINT chk_cseries_year_quarter CHECK (year_quarter ~ '^\d{4}-Q[1-4]$');
+    END IF;
+END $$;
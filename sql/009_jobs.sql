@@ -0,0 +1,17 @@
+-- KIEP Database Schema
+-- 009: CLI 실행 기록 (수집/재계산 명령 단위, 모니터링/알림용)
+
+CREATE TABLE IF NOT EXISTS jobs (
+    id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    command         VARCHAR(40) NOT NULL,           -- fetch-nps/backfill-nps/...
+    args            JSONB NOT NULL DEFAULT '[]',    -- 실행 인자 (명령행 그대로)
+    status          VARCHAR(20) NOT NULL DEFAULT 'running',  -- running/completed/failed
+    fetched_count   INTEGER,                        -- 수신/처리 대상 건수
+    written_count   INTEGER,                        -- 기록 건수
+    error           TEXT,                           -- 실패 시 오류 메시지
+    started_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at     TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_started ON jobs(started_at DESC);
+CREATE INDEX IF NOT EXISTS idx_jobs_command ON jobs(command, started_at DESC);
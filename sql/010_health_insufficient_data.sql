@@ -0,0 +1,4 @@
+-- KIEP Database Schema
+-- 010: 기업 수가 적은 지역의 건강도 표시 (점수는 저장하되 API에서 공개하지 않음)
+
+ALTER TABLE region_health ADD COLUMN IF NOT EXISTS insufficient_data BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,11 @@
+-- KIEP Database Schema
+-- 011: 데이터 소스별 마지막 수집 결과 (갱신 주기 모니터링용)
+
+CREATE TABLE IF NOT EXISTS source_status (
+    source          VARCHAR(20) PRIMARY KEY,        -- NPS/NTS/...
+    last_success_at TIMESTAMPTZ,                    -- 마지막 성공 수집 시각
+    last_counts     JSONB,                          -- 마지막 성공 시 {"fetched", "written"}
+    last_error      TEXT,                           -- 마지막 실패 메시지
+    last_error_at   TIMESTAMPTZ,
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
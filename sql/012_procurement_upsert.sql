@@ -0,0 +1,15 @@
+-- KIEP Database Schema
+-- 012: 조달 계약 upsert 키 (입찰공고번호, 계약번호)
+
+-- 계약업체 사업자번호 원본 (companies에 없으면 biz_no는 NULL로 두고 여기에만 남김)
+ALTER TABLE procurement ADD COLUMN IF NOT EXISTS contractor_biz_no VARCHAR(10);
+ALTER TABLE procurement ADD COLUMN IF NOT EXISTS updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+-- 같은 키가 여러 번 적재된 경우 마지막 행만 남김
+DELETE FROM procurement p
+USING procurement o
+WHERE p.bid_no IS NOT DISTINCT FROM o.bid_no
+  AND p.contract_no IS NOT DISTINCT FROM o.contract_no
+  AND p.id < o.id;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_proc_unique ON procurement(bid_no, contract_no);
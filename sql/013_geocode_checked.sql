@@ -0,0 +1,8 @@
+-- KIEP Database Schema
+-- 013: 주소 지오코딩 시도 시각 (찾지 못한 주소는 다음 실행에서 뒤로 미룸)
+
+ALTER TABLE companies ADD COLUMN IF NOT EXISTS geocode_checked_at TIMESTAMPTZ;  -- 마지막 VWorld 주소 검색
+
+CREATE INDEX IF NOT EXISTS idx_companies_geocode_pending
+    ON companies(geocode_checked_at NULLS FIRST, biz_no)
+    WHERE coordinates IS NULL AND address IS NOT NULL;
@@ -0,0 +1,5 @@
+-- KIEP Database Schema
+-- 014: 기본 가중치가 아닌 HEALTH_WEIGHTS로 재계산한 건강도 표시
+
+-- NULL이면 score_version 모델의 기본 가중치, 아니면 HEALTH_WEIGHTS 형식 ('0.3,0.25,0.2,0.15,0.1')
+ALTER TABLE region_health ADD COLUMN IF NOT EXISTS score_weights TEXT;
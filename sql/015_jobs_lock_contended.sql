@@ -0,0 +1,6 @@
+-- KIEP Database Schema
+-- 015: 실행 기록에 run lock 경합 여부 (같은 시도를 수집 중인 다른 실행과 겹쳤는지)
+
+ALTER TABLE jobs ADD COLUMN IF NOT EXISTS lock_contended BOOLEAN;  -- run lock을 쓰지 않는 명령은 NULL
+
+-- status: running/completed/failed/skipped (skipped = 다른 실행이 락을 점유 중이라 대기 없이 종료)